@@ -17,7 +17,7 @@ async fn main() -> anyhow::Result<()> {
     }
 
     // Create an OpenAI client
-    let client = OpenAIClient::new()?;
+    let client = OpenAIClient::from_env()?;
 
     // Build the request body
     let request_body = ChatRequestBody::builder(
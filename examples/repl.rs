@@ -8,7 +8,7 @@ async fn main() -> Result<()> {
     let mut editor = rustyline::DefaultEditor::new().unwrap();
 
     // Create an OpenAI client
-    let client = OpenAIClient::new()?;
+    let client = OpenAIClient::from_env()?;
 
     loop {
         let line = editor.readline(">> ");
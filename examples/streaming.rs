@@ -5,7 +5,10 @@ use rustyopenai::prelude::*;
 #[tokio::main]
 async fn main() -> Result<()> {
     // Create a client
-    let client = OpenAIClient::builder().timeout(Duration::from_millis(5000)).build()?;
+    let client = OpenAIClient::builder()
+        .load_env()
+        .timeout(Duration::from_millis(5000))
+        .build()?;
 
     // Build the request body
     let request_body = ChatRequestBody::builder(
@@ -0,0 +1,175 @@
+use crate::{ Result, OpenAIClient, ListResponse, DeletedObject, utils::encode_path_segment };
+use super::{
+    super::{ ASSISTANTS_API_PATH, AssistantRequestBody, AssistantsListQuery, Assistant },
+    send::send_request,
+};
+
+/// Creates an assistant with a model and instructions.
+pub async fn create_assistant(
+    client: &OpenAIClient,
+    request_body: &AssistantRequestBody
+) -> Result<Assistant> {
+    send_request(
+        client,
+        |client| client.post(client.endpoint(ASSISTANTS_API_PATH)).json(request_body),
+        None,
+        "assistant"
+    ).await
+}
+
+/// Retrieves an assistant.
+pub async fn retrieve_assistant<S: AsRef<str>>(
+    client: &OpenAIClient,
+    assistant_id: S
+) -> Result<Assistant> {
+    let assistant_id = assistant_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client.get(
+                client.endpoint(
+                    format!("{}/{}", ASSISTANTS_API_PATH, encode_path_segment(assistant_id))
+                )
+            )
+        },
+        Some(assistant_id),
+        "assistant"
+    ).await
+}
+
+/// Modifies an assistant with the fields set in the request body.
+pub async fn modify_assistant<S: AsRef<str>>(
+    client: &OpenAIClient,
+    assistant_id: S,
+    request_body: &AssistantRequestBody
+) -> Result<Assistant> {
+    let assistant_id = assistant_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client
+                .post(
+                    client.endpoint(
+                        format!("{}/{}", ASSISTANTS_API_PATH, encode_path_segment(assistant_id))
+                    )
+                )
+                .json(request_body)
+        },
+        Some(assistant_id),
+        "assistant"
+    ).await
+}
+
+/// Lists a page of assistants.
+pub async fn list_assistants(
+    client: &OpenAIClient,
+    query: &AssistantsListQuery
+) -> Result<ListResponse<Assistant>> {
+    send_request(
+        client,
+        |client| client.get(client.endpoint(ASSISTANTS_API_PATH)).query(query),
+        None,
+        "list of assistants"
+    ).await
+}
+
+/// Deletes an assistant.
+pub async fn delete_assistant<S: AsRef<str>>(
+    client: &OpenAIClient,
+    assistant_id: S
+) -> Result<DeletedObject> {
+    let assistant_id = assistant_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client.delete(
+                client.endpoint(
+                    format!("{}/{}", ASSISTANTS_API_PATH, encode_path_segment(assistant_id))
+                )
+            )
+        },
+        Some(assistant_id),
+        "deleted assistant"
+    ).await
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use serde_json::json;
+    use crate::{ Error, AssistantsApiError, assistants::* };
+    use super::*;
+
+    #[tokio::test]
+    async fn test_create_assistant() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/assistants")
+            .match_header("authorization", "Bearer sk-test")
+            .match_header("openai-beta", "assistants=v2")
+            .match_body(
+                Matcher::Json(
+                    json!({
+                        "model": "gpt-4o",
+                        "name": "Math Tutor",
+                        "instructions": "You are a personal math tutor.",
+                        "tools": [{ "type": "code_interpreter" }],
+                        "tool_resources": { "code_interpreter": { "file_ids": ["file-abc123"] } }
+                    })
+                )
+            )
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "id": "asst_abc123",
+                    "object": "assistant",
+                    "created_at": 1698984975,
+                    "name": "Math Tutor",
+                    "description": null,
+                    "model": "gpt-4o",
+                    "instructions": "You are a personal math tutor.",
+                    "tools": [{ "type": "code_interpreter" }],
+                    "tool_resources": { "code_interpreter": { "file_ids": ["file-abc123"] } },
+                    "metadata": {},
+                    "top_p": 1.0,
+                    "temperature": 1.0
+                }).to_string()
+            )
+            .create_async().await;
+        server
+            .mock("GET", "/assistants/missing")
+            .match_header("openai-beta", "assistants=v2")
+            .with_status(404)
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // Create the assistant
+        let request_body = AssistantRequestBody::builder("gpt-4o")
+            .name("Math Tutor")
+            .instructions("You are a personal math tutor.")
+            .tools(vec![AssistantTool::CodeInterpreter])
+            .tool_resources(ToolResources::code_interpreter(&["file-abc123"]))
+            .build();
+        let assistant = create_assistant(&client, &request_body).await?;
+
+        mock.assert_async().await;
+        assert_eq!(assistant.tools, vec![AssistantTool::CodeInterpreter]);
+
+        // Retrieve a missing assistant
+        let result = retrieve_assistant(&client, "missing").await;
+        assert!(
+            matches!(
+                result,
+                Err(Error::AssistantsApi(AssistantsApiError::ObjectNotFound(id))) if id == "missing"
+            )
+        );
+
+        Ok(())
+    }
+}
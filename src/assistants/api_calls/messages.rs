@@ -0,0 +1,107 @@
+use crate::{ Result, OpenAIClient, ListResponse, DeletedObject, utils::encode_path_segment };
+use super::{
+    super::{ THREADS_API_PATH, MessageRequestBody, AssistantsListQuery, ThreadMessage },
+    send::send_request,
+};
+
+/// Creates a message in a thread.
+pub async fn create_message<S: AsRef<str>>(
+    client: &OpenAIClient,
+    thread_id: S,
+    request_body: &MessageRequestBody
+) -> Result<ThreadMessage> {
+    let thread_id = thread_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client
+                .post(
+                    client.endpoint(
+                        format!("{}/{}/messages", THREADS_API_PATH, encode_path_segment(thread_id))
+                    )
+                )
+                .json(request_body)
+        },
+        Some(thread_id),
+        "message"
+    ).await
+}
+
+/// Retrieves a message in a thread.
+pub async fn retrieve_message<S: AsRef<str>, T: AsRef<str>>(
+    client: &OpenAIClient,
+    thread_id: S,
+    message_id: T
+) -> Result<ThreadMessage> {
+    let message_id = message_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client.get(
+                client.endpoint(
+                    format!(
+                        "{}/{}/messages/{}",
+                        THREADS_API_PATH,
+                        encode_path_segment(thread_id.as_ref()),
+                        encode_path_segment(message_id)
+                    )
+                )
+            )
+        },
+        Some(message_id),
+        "message"
+    ).await
+}
+
+/// Lists a page of the messages in a thread.
+pub async fn list_messages<S: AsRef<str>>(
+    client: &OpenAIClient,
+    thread_id: S,
+    query: &AssistantsListQuery
+) -> Result<ListResponse<ThreadMessage>> {
+    let thread_id = thread_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client
+                .get(
+                    client.endpoint(
+                        format!("{}/{}/messages", THREADS_API_PATH, encode_path_segment(thread_id))
+                    )
+                )
+                .query(query)
+        },
+        Some(thread_id),
+        "list of messages"
+    ).await
+}
+
+/// Deletes a message in a thread.
+pub async fn delete_message<S: AsRef<str>, T: AsRef<str>>(
+    client: &OpenAIClient,
+    thread_id: S,
+    message_id: T
+) -> Result<DeletedObject> {
+    let message_id = message_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client.delete(
+                client.endpoint(
+                    format!(
+                        "{}/{}/messages/{}",
+                        THREADS_API_PATH,
+                        encode_path_segment(thread_id.as_ref()),
+                        encode_path_segment(message_id)
+                    )
+                )
+            )
+        },
+        Some(message_id),
+        "deleted message"
+    ).await
+}
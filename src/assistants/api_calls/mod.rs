@@ -0,0 +1,28 @@
+mod send;
+
+mod assistants;
+pub use assistants::{
+    create_assistant,
+    retrieve_assistant,
+    modify_assistant,
+    list_assistants,
+    delete_assistant,
+};
+
+mod threads;
+pub use threads::{ create_thread, retrieve_thread, modify_thread, delete_thread };
+
+mod messages;
+pub use messages::{ create_message, retrieve_message, list_messages, delete_message };
+
+mod runs;
+pub use runs::{ create_run, retrieve_run, list_runs, cancel_run, submit_tool_outputs };
+
+mod run_steps;
+pub use run_steps::{ retrieve_run_step, list_run_steps };
+
+mod poll;
+pub use poll::{ poll_run, poll_run_with_tools };
+
+mod streaming;
+pub use streaming::{ create_run_stream, submit_tool_outputs_stream };
@@ -0,0 +1,157 @@
+use std::{ future::Future, time::Duration };
+use crate::{ Result, OpenAIClient, chat::ChatCompletionToolCall };
+use super::{ super::{ ToolOutput, Run, RunStatus }, retrieve_run, submit_tool_outputs };
+
+/// Polls a run until it reaches a terminal status or requires action,
+/// sleeping for the interval between retrievals.
+pub async fn poll_run<S: AsRef<str>, T: AsRef<str>>(
+    client: &OpenAIClient,
+    thread_id: S,
+    run_id: T,
+    interval: Duration
+) -> Result<Run> {
+    loop {
+        let run = retrieve_run(client, thread_id.as_ref(), run_id.as_ref()).await?;
+
+        if run.status.is_terminal() || run.status == RunStatus::RequiresAction {
+            return Ok(run);
+        }
+
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// Polls a run until it reaches a terminal status.
+///
+/// Whenever the run requires action, the handler is called with each tool call
+/// to produce its output, and the outputs are submitted to continue the run.
+pub async fn poll_run_with_tools<S, T, F, Fut>(
+    client: &OpenAIClient,
+    thread_id: S,
+    run_id: T,
+    interval: Duration,
+    mut handler: F
+)
+    -> Result<Run>
+    where
+        S: AsRef<str>,
+        T: AsRef<str>,
+        F: FnMut(ChatCompletionToolCall) -> Fut,
+        Fut: Future<Output = Result<String>>
+{
+    loop {
+        let run = poll_run(client, thread_id.as_ref(), run_id.as_ref(), interval).await?;
+
+        if run.status != RunStatus::RequiresAction {
+            return Ok(run);
+        }
+
+        // Run the tool calls
+        let mut tool_outputs = vec![];
+        for tool_call in run.required_tool_calls() {
+            let output = handler(tool_call.clone()).await?;
+            tool_outputs.push(ToolOutput::new(&tool_call.id, output));
+        }
+
+        submit_tool_outputs(client, thread_id.as_ref(), run_id.as_ref(), &tool_outputs).await?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use serde_json::json;
+    use super::*;
+
+    fn run_json(status: &str) -> String {
+        let mut run =
+            json!({
+            "id": "run_abc123",
+            "object": "thread.run",
+            "created_at": 1699063290,
+            "thread_id": "thread_abc123",
+            "assistant_id": "asst_abc123",
+            "status": status,
+            "model": "gpt-4o",
+            "tools": [
+                {
+                    "type": "function",
+                    "function": { "name": "get_weather", "parameters": { "type": "object" } }
+                }
+            ]
+        });
+
+        if status == "requires_action" {
+            run["required_action"] = json!({
+                "type": "submit_tool_outputs",
+                "submit_tool_outputs": {
+                    "tool_calls": [
+                        {
+                            "id": "call_abc123",
+                            "type": "function",
+                            "function": {
+                                "name": "get_weather",
+                                "arguments": "{\"location\":\"Tokyo\"}"
+                            }
+                        }
+                    ]
+                }
+            });
+        }
+
+        run.to_string()
+    }
+
+    #[tokio::test]
+    async fn test_poll_run_with_tools() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let requires_action = server
+            .mock("GET", "/threads/thread_abc123/runs/run_abc123")
+            .match_header("openai-beta", "assistants=v2")
+            .with_header("content-type", "application/json")
+            .with_body(run_json("requires_action"))
+            .expect(1)
+            .create_async().await;
+        let submit = server
+            .mock("POST", "/threads/thread_abc123/runs/run_abc123/submit_tool_outputs")
+            .match_header("openai-beta", "assistants=v2")
+            .match_body(
+                Matcher::Json(
+                    json!({
+                        "tool_outputs": [{ "tool_call_id": "call_abc123", "output": "22C" }]
+                    })
+                )
+            )
+            .with_header("content-type", "application/json")
+            .with_body(run_json("queued"))
+            .create_async().await;
+        let completed = server
+            .mock("GET", "/threads/thread_abc123/runs/run_abc123")
+            .with_header("content-type", "application/json")
+            .with_body(run_json("completed"))
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // Poll the run, answering the tool call
+        let run = poll_run_with_tools(
+            &client,
+            "thread_abc123",
+            "run_abc123",
+            Duration::from_millis(10),
+            |tool_call| async move {
+                assert_eq!(tool_call.function.name, "get_weather");
+                Ok("22C".to_string())
+            }
+        ).await?;
+
+        requires_action.assert_async().await;
+        submit.assert_async().await;
+        completed.assert_async().await;
+        assert_eq!(run.status, RunStatus::Completed);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{ Result, OpenAIClient, ListResponse, utils::encode_path_segment };
+use super::{ super::{ THREADS_API_PATH, AssistantsListQuery, RunStep }, send::send_request };
+
+/// Retrieves a step of a run.
+pub async fn retrieve_run_step<S: AsRef<str>, T: AsRef<str>, U: AsRef<str>>(
+    client: &OpenAIClient,
+    thread_id: S,
+    run_id: T,
+    step_id: U
+) -> Result<RunStep> {
+    let step_id = step_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client.get(
+                client.endpoint(
+                    format!(
+                        "{}/{}/runs/{}/steps/{}",
+                        THREADS_API_PATH,
+                        encode_path_segment(thread_id.as_ref()),
+                        encode_path_segment(run_id.as_ref()),
+                        encode_path_segment(step_id)
+                    )
+                )
+            )
+        },
+        Some(step_id),
+        "run step"
+    ).await
+}
+
+/// Lists a page of the steps of a run.
+pub async fn list_run_steps<S: AsRef<str>, T: AsRef<str>>(
+    client: &OpenAIClient,
+    thread_id: S,
+    run_id: T,
+    query: &AssistantsListQuery
+) -> Result<ListResponse<RunStep>> {
+    let run_id = run_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client
+                .get(
+                    client.endpoint(
+                        format!(
+                            "{}/{}/runs/{}/steps",
+                            THREADS_API_PATH,
+                            encode_path_segment(thread_id.as_ref()),
+                            encode_path_segment(run_id)
+                        )
+                    )
+                )
+                .query(query)
+        },
+        Some(run_id),
+        "list of run steps"
+    ).await
+}
@@ -0,0 +1,145 @@
+use crate::{ Result, OpenAIClient, ListResponse, utils::encode_path_segment };
+use super::{
+    super::{
+        THREADS_API_PATH,
+        RunRequestBody,
+        AssistantsListQuery,
+        ToolOutput,
+        SubmitToolOutputsRequestBody,
+        Run,
+    },
+    send::send_request,
+};
+
+/// Creates a run of an assistant on a thread.
+pub async fn create_run<S: AsRef<str>>(
+    client: &OpenAIClient,
+    thread_id: S,
+    request_body: &RunRequestBody
+) -> Result<Run> {
+    let thread_id = thread_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client
+                .post(
+                    client.endpoint(
+                        format!("{}/{}/runs", THREADS_API_PATH, encode_path_segment(thread_id))
+                    )
+                )
+                .json(request_body)
+        },
+        Some(thread_id),
+        "run"
+    ).await
+}
+
+/// Retrieves a run.
+pub async fn retrieve_run<S: AsRef<str>, T: AsRef<str>>(
+    client: &OpenAIClient,
+    thread_id: S,
+    run_id: T
+) -> Result<Run> {
+    let run_id = run_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client.get(
+                client.endpoint(
+                    format!(
+                        "{}/{}/runs/{}",
+                        THREADS_API_PATH,
+                        encode_path_segment(thread_id.as_ref()),
+                        encode_path_segment(run_id)
+                    )
+                )
+            )
+        },
+        Some(run_id),
+        "run"
+    ).await
+}
+
+/// Lists a page of the runs on a thread.
+pub async fn list_runs<S: AsRef<str>>(
+    client: &OpenAIClient,
+    thread_id: S,
+    query: &AssistantsListQuery
+) -> Result<ListResponse<Run>> {
+    let thread_id = thread_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client
+                .get(
+                    client.endpoint(
+                        format!("{}/{}/runs", THREADS_API_PATH, encode_path_segment(thread_id))
+                    )
+                )
+                .query(query)
+        },
+        Some(thread_id),
+        "list of runs"
+    ).await
+}
+
+/// Cancels a run that is in progress.
+pub async fn cancel_run<S: AsRef<str>, T: AsRef<str>>(
+    client: &OpenAIClient,
+    thread_id: S,
+    run_id: T
+) -> Result<Run> {
+    let run_id = run_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client.post(
+                client.endpoint(
+                    format!(
+                        "{}/{}/runs/{}/cancel",
+                        THREADS_API_PATH,
+                        encode_path_segment(thread_id.as_ref()),
+                        encode_path_segment(run_id)
+                    )
+                )
+            )
+        },
+        Some(run_id),
+        "run"
+    ).await
+}
+
+/// Submits the outputs of the tool calls requested by a run
+/// whose status is `RequiresAction`, so that it continues.
+pub async fn submit_tool_outputs<S: AsRef<str>, T: AsRef<str>>(
+    client: &OpenAIClient,
+    thread_id: S,
+    run_id: T,
+    tool_outputs: &[ToolOutput]
+) -> Result<Run> {
+    let run_id = run_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client
+                .post(
+                    client.endpoint(
+                        format!(
+                            "{}/{}/runs/{}/submit_tool_outputs",
+                            THREADS_API_PATH,
+                            encode_path_segment(thread_id.as_ref()),
+                            encode_path_segment(run_id)
+                        )
+                    )
+                )
+                .json(&(SubmitToolOutputsRequestBody { tool_outputs }))
+        },
+        Some(run_id),
+        "run"
+    ).await
+}
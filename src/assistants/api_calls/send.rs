@@ -0,0 +1,48 @@
+use reqwest::{ RequestBuilder, Response };
+use serde::de::DeserializeOwned;
+use crate::{ Result, Error, AssistantsApiError, OpenAIClient };
+
+/// Adds the header required by the beta assistants API.
+fn with_beta_header(request: RequestBuilder) -> RequestBuilder {
+    request.header("OpenAI-Beta", "assistants=v2")
+}
+
+/// Sends the request built by the closure to the assistants API.
+///
+/// A 404 status means the object with the given ID is not found.
+pub(super) async fn send<F>(client: &OpenAIClient, build: F, id: Option<&str>) -> Result<Response>
+    where F: FnOnce(&OpenAIClient) -> RequestBuilder
+{
+    let response = client.send(|client| with_beta_header(build(client))).await;
+
+    let not_found = reqwest::StatusCode::NOT_FOUND;
+    if let (Err(Error::UnknownStatusCode { status_code, .. }), Some(id)) = (&response, id) {
+        if *status_code == not_found {
+            return Err(Error::AssistantsApi(AssistantsApiError::ObjectNotFound(id.to_string())));
+        }
+    }
+
+    response
+}
+
+/// Sends the request built by the closure to the assistants API,
+/// and parses the response to the named object.
+pub(super) async fn send_request<T: DeserializeOwned, F>(
+    client: &OpenAIClient,
+    build: F,
+    id: Option<&str>,
+    object: &'static str
+) -> Result<T>
+    where F: FnOnce(&OpenAIClient) -> RequestBuilder
+{
+    // Send the request
+    let response = send(client, build, id).await?;
+
+    // Parse the response
+    match response.json::<T>().await {
+        Ok(response) => Ok(response),
+        Err(error) => {
+            Err(Error::AssistantsApi(AssistantsApiError::ParseResponse { object, source: error }))
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{ Result, OpenAIClient, utils::{ StreamingRequestBody, encode_path_segment } };
+use super::{
+    super::{
+        THREADS_API_PATH,
+        RunRequestBody,
+        ToolOutput,
+        SubmitToolOutputsRequestBody,
+        AssistantEventStream,
+    },
+    send::send,
+};
+
+/// Creates a run of an assistant on a thread, streaming its events.
+pub async fn create_run_stream<S: AsRef<str>>(
+    client: &OpenAIClient,
+    thread_id: S,
+    request_body: &RunRequestBody
+) -> Result<AssistantEventStream> {
+    let thread_id = thread_id.as_ref();
+
+    // Send the request
+    let response = send(
+        client,
+        |client| {
+            client
+                .post(
+                    client.endpoint(
+                        format!("{}/{}/runs", THREADS_API_PATH, encode_path_segment(thread_id))
+                    )
+                )
+                .json(&StreamingRequestBody::new(request_body))
+        },
+        Some(thread_id)
+    ).await?;
+
+    // Wrap the bytes stream in an AssistantEventStream
+    Ok(AssistantEventStream::new(response.bytes_stream()))
+}
+
+/// Submits the outputs of the tool calls requested by a run
+/// whose status is `RequiresAction`, streaming the events of the continued run.
+pub async fn submit_tool_outputs_stream<S: AsRef<str>, T: AsRef<str>>(
+    client: &OpenAIClient,
+    thread_id: S,
+    run_id: T,
+    tool_outputs: &[ToolOutput]
+) -> Result<AssistantEventStream> {
+    let run_id = run_id.as_ref();
+
+    // Send the request
+    let response = send(
+        client,
+        |client| {
+            client
+                .post(
+                    client.endpoint(
+                        format!(
+                            "{}/{}/runs/{}/submit_tool_outputs",
+                            THREADS_API_PATH,
+                            encode_path_segment(thread_id.as_ref()),
+                            encode_path_segment(run_id)
+                        )
+                    )
+                )
+                .json(&StreamingRequestBody::new(&(SubmitToolOutputsRequestBody { tool_outputs })))
+        },
+        Some(run_id)
+    ).await?;
+
+    // Wrap the bytes stream in an AssistantEventStream
+    Ok(AssistantEventStream::new(response.bytes_stream()))
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use mockito::Matcher;
+    use serde_json::json;
+    use crate::assistants::AssistantStreamEvent;
+    use super::*;
+
+    fn run_event(event: &str, status: &str) -> String {
+        let mut run =
+            json!({
+            "id": "run_abc123",
+            "object": "thread.run",
+            "created_at": 1699063290,
+            "thread_id": "thread_abc123",
+            "assistant_id": "asst_abc123",
+            "status": status,
+            "model": "gpt-4o",
+            "tools": []
+        });
+
+        if status == "requires_action" {
+            run["required_action"] = json!({
+                "type": "submit_tool_outputs",
+                "submit_tool_outputs": {
+                    "tool_calls": [
+                        {
+                            "id": "call_abc123",
+                            "type": "function",
+                            "function": { "name": "get_weather", "arguments": "{}" }
+                        }
+                    ]
+                }
+            });
+        }
+
+        format!("event: {event}\ndata: {run}\n\n")
+    }
+
+    #[tokio::test]
+    async fn test_stream_run_with_tool_outputs() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let create = server
+            .mock("POST", "/threads/thread_abc123/runs")
+            .match_header("openai-beta", "assistants=v2")
+            .match_body(Matcher::Json(json!({ "assistant_id": "asst_abc123", "stream": true })))
+            .with_header("content-type", "text/event-stream")
+            .with_body(
+                [
+                    run_event("thread.run.created", "queued"),
+                    run_event("thread.run.requires_action", "requires_action"),
+                    "event: done\ndata: [DONE]\n\n".to_string(),
+                ].concat()
+            )
+            .create_async().await;
+        let submit = server
+            .mock("POST", "/threads/thread_abc123/runs/run_abc123/submit_tool_outputs")
+            .match_header("openai-beta", "assistants=v2")
+            .match_body(
+                Matcher::Json(
+                    json!({
+                        "tool_outputs": [{ "tool_call_id": "call_abc123", "output": "22C" }],
+                        "stream": true
+                    })
+                )
+            )
+            .with_header("content-type", "text/event-stream")
+            .with_body(
+                [
+                    run_event("thread.run.completed", "completed"),
+                    "event: done\ndata: [DONE]\n\n".to_string(),
+                ].concat()
+            )
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // Stream the run until it requires action
+        let request_body = RunRequestBody::builder("asst_abc123").build();
+        let mut stream = create_run_stream(&client, "thread_abc123", &request_body).await?;
+        let mut tool_outputs = vec![];
+        while let Some(event) = stream.next().await {
+            if let AssistantStreamEvent::RunRequiresAction(run) = event? {
+                for tool_call in run.required_tool_calls() {
+                    tool_outputs.push(ToolOutput::new(&tool_call.id, "22C"));
+                }
+            }
+        }
+
+        create.assert_async().await;
+        assert_eq!(tool_outputs.len(), 1);
+
+        // Continue the run with the tool outputs
+        let mut stream = submit_tool_outputs_stream(
+            &client,
+            "thread_abc123",
+            "run_abc123",
+            &tool_outputs
+        ).await?;
+        let mut events = vec![];
+        while let Some(event) = stream.next().await {
+            events.push(event?);
+        }
+
+        submit.assert_async().await;
+        assert!(matches!(events[0], AssistantStreamEvent::RunCompleted(_)));
+        assert!(matches!(events[1], AssistantStreamEvent::Done));
+
+        Ok(())
+    }
+}
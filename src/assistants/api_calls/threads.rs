@@ -0,0 +1,74 @@
+use crate::{ Result, OpenAIClient, DeletedObject, utils::encode_path_segment };
+use super::{ super::{ THREADS_API_PATH, ThreadRequestBody, Thread }, send::send_request };
+
+/// Creates a thread, optionally with initial messages.
+pub async fn create_thread(
+    client: &OpenAIClient,
+    request_body: &ThreadRequestBody
+) -> Result<Thread> {
+    send_request(
+        client,
+        |client| client.post(client.endpoint(THREADS_API_PATH)).json(request_body),
+        None,
+        "thread"
+    ).await
+}
+
+/// Retrieves a thread.
+pub async fn retrieve_thread<S: AsRef<str>>(client: &OpenAIClient, thread_id: S) -> Result<Thread> {
+    let thread_id = thread_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client.get(
+                client.endpoint(format!("{}/{}", THREADS_API_PATH, encode_path_segment(thread_id)))
+            )
+        },
+        Some(thread_id),
+        "thread"
+    ).await
+}
+
+/// Modifies the tool resources and metadata of a thread.
+pub async fn modify_thread<S: AsRef<str>>(
+    client: &OpenAIClient,
+    thread_id: S,
+    request_body: &ThreadRequestBody
+) -> Result<Thread> {
+    let thread_id = thread_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client
+                .post(
+                    client.endpoint(
+                        format!("{}/{}", THREADS_API_PATH, encode_path_segment(thread_id))
+                    )
+                )
+                .json(request_body)
+        },
+        Some(thread_id),
+        "thread"
+    ).await
+}
+
+/// Deletes a thread.
+pub async fn delete_thread<S: AsRef<str>>(
+    client: &OpenAIClient,
+    thread_id: S
+) -> Result<DeletedObject> {
+    let thread_id = thread_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client.delete(
+                client.endpoint(format!("{}/{}", THREADS_API_PATH, encode_path_segment(thread_id)))
+            )
+        },
+        Some(thread_id),
+        "deleted thread"
+    ).await
+}
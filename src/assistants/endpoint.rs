@@ -0,0 +1,6 @@
+/// Path of the assistants API under the base URL of the client.
+pub const ASSISTANTS_API_PATH: &str = "assistants";
+
+/// Path of the threads API under the base URL of the client,
+/// under which are the messages, runs and run steps.
+pub const THREADS_API_PATH: &str = "threads";
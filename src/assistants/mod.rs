@@ -0,0 +1,11 @@
+mod endpoint;
+use endpoint::{ ASSISTANTS_API_PATH, THREADS_API_PATH };
+
+mod request;
+pub use request::*;
+
+mod response;
+pub use response::*;
+
+mod api_calls;
+pub use api_calls::*;
@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+use serde::Serialize;
+use log::warn;
+use super::{ AssistantTool, ToolResources };
+
+const MIN_TEMPERATURE: f32 = 0.0;
+const MAX_TEMPERATURE: f32 = 2.0;
+const MIN_TOP_P: f32 = 0.0;
+const MAX_TOP_P: f32 = 1.0;
+
+/// Request body of creating or modifying an assistant.
+#[derive(Debug, Serialize)]
+pub struct AssistantRequestBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instructions: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<AssistantTool>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_resources: Option<ToolResources>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<HashMap<String, String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f32>,
+}
+
+impl AssistantRequestBody {
+    /// Creates a builder of the request body of creating an assistant with the model.
+    pub fn builder<S: AsRef<str>>(model: S) -> AssistantRequestBodyBuilder {
+        AssistantRequestBodyBuilder::new().model(model)
+    }
+
+    /// Creates a builder of the request body of modifying an assistant,
+    /// in which only the fields to change are set.
+    pub fn modify_builder() -> AssistantRequestBodyBuilder {
+        AssistantRequestBodyBuilder::new()
+    }
+}
+
+#[derive(Default)]
+pub struct AssistantRequestBodyBuilder {
+    model: Option<String>,
+    name: Option<String>,
+    description: Option<String>,
+    instructions: Option<String>,
+    tools: Option<Vec<AssistantTool>>,
+    tool_resources: Option<ToolResources>,
+    metadata: Option<HashMap<String, String>>,
+    temperature: Option<f32>,
+    top_p: Option<f32>,
+}
+
+impl AssistantRequestBodyBuilder {
+    /// Creates a new builder with `None` values for all fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds the request body.
+    pub fn build(self) -> AssistantRequestBody {
+        AssistantRequestBody {
+            model: self.model,
+            name: self.name,
+            description: self.description,
+            instructions: self.instructions,
+            tools: self.tools,
+            tool_resources: self.tool_resources,
+            metadata: self.metadata,
+            temperature: self.temperature,
+            top_p: self.top_p,
+        }
+    }
+
+    /// Sets the model used by the assistant.
+    pub fn model<S: AsRef<str>>(mut self, model: S) -> Self {
+        self.model = Some(model.as_ref().to_string());
+        self
+    }
+
+    pub fn name<S: AsRef<str>>(mut self, name: S) -> Self {
+        self.name = Some(name.as_ref().to_string());
+        self
+    }
+
+    pub fn description<S: AsRef<str>>(mut self, description: S) -> Self {
+        self.description = Some(description.as_ref().to_string());
+        self
+    }
+
+    /// Sets the system instructions that the assistant uses.
+    pub fn instructions<S: AsRef<str>>(mut self, instructions: S) -> Self {
+        self.instructions = Some(instructions.as_ref().to_string());
+        self
+    }
+
+    /// Sets the tools enabled on the assistant, at most 128.
+    pub fn tools(mut self, tools: Vec<AssistantTool>) -> Self {
+        self.tools = Some(tools);
+        self
+    }
+
+    /// Sets the resources used by the tools.
+    pub fn tool_resources(mut self, tool_resources: ToolResources) -> Self {
+        self.tool_resources = Some(tool_resources);
+        self
+    }
+
+    /// Adds a key-value pair to the metadata of the assistant.
+    pub fn metadata<K: AsRef<str>, V: AsRef<str>>(mut self, key: K, value: V) -> Self {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+
+    /// Sets the sampling temperature.
+    ///
+    /// The input value will be clamped in between 0.0 and 2.0.
+    pub fn temperature(mut self, temperature: f32) -> Self {
+        // Clamp the value to the valid range
+        let temperature = if !(MIN_TEMPERATURE..=MAX_TEMPERATURE).contains(&temperature) {
+            // Clamp the value
+            let t = temperature.clamp(MIN_TEMPERATURE, MAX_TEMPERATURE);
+
+            // Warn the user
+            warn!(
+                "input value of temperature is {temperature} which is out of range, it is now clamped to {t}"
+            );
+
+            t
+        } else {
+            temperature
+        };
+
+        self.temperature = Some(temperature);
+        self
+    }
+
+    /// Sets the nucleus sampling probability mass.
+    ///
+    /// The input value will be clamped in between 0.0 and 1.0.
+    pub fn top_p(mut self, top_p: f32) -> Self {
+        // Clamp the value to the valid range
+        let top_p = if !(MIN_TOP_P..=MAX_TOP_P).contains(&top_p) {
+            // Clamp the value
+            let p = top_p.clamp(MIN_TOP_P, MAX_TOP_P);
+
+            // Warn the user
+            warn!("input value of top_p is {top_p} which is out of range, it is now clamped to {p}");
+
+            p
+        } else {
+            top_p
+        };
+
+        self.top_p = Some(top_p);
+        self
+    }
+}
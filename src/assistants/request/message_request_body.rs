@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+use serde::{ Serialize, Deserialize };
+
+/// Request body of creating a message in a thread.
+#[derive(Debug, Serialize)]
+pub struct MessageRequestBody {
+    role: MessageRole,
+    content: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attachments: Option<Vec<MessageAttachment>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<HashMap<String, String>>,
+}
+
+impl MessageRequestBody {
+    pub fn builder<S: AsRef<str>>(role: MessageRole, content: S) -> MessageRequestBodyBuilder {
+        MessageRequestBodyBuilder::new(role, content)
+    }
+}
+
+/// The role of the entity creating a message.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageRole {
+    User,
+    Assistant,
+}
+
+/// A file attached to a message, and the tools to add it to.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct MessageAttachment {
+    pub file_id: String,
+    pub tools: Vec<AttachmentTool>,
+}
+
+impl MessageAttachment {
+    pub fn new<S: AsRef<str>>(file_id: S, tools: Vec<AttachmentTool>) -> Self {
+        Self { file_id: file_id.as_ref().to_string(), tools }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AttachmentTool {
+    CodeInterpreter,
+    FileSearch,
+}
+
+pub struct MessageRequestBodyBuilder {
+    role: MessageRole,
+    content: String,
+    attachments: Option<Vec<MessageAttachment>>,
+    metadata: Option<HashMap<String, String>>,
+}
+
+impl MessageRequestBodyBuilder {
+    /// Creates a new builder with `None` values for all optional fields.
+    pub fn new<S: AsRef<str>>(role: MessageRole, content: S) -> Self {
+        Self {
+            role,
+            content: content.as_ref().to_string(),
+            attachments: None,
+            metadata: None,
+        }
+    }
+
+    /// Builds the request body.
+    pub fn build(self) -> MessageRequestBody {
+        MessageRequestBody {
+            role: self.role,
+            content: self.content,
+            attachments: self.attachments,
+            metadata: self.metadata,
+        }
+    }
+
+    /// Attaches a file to the message.
+    pub fn attachment(mut self, attachment: MessageAttachment) -> Self {
+        self.attachments.get_or_insert_with(Vec::new).push(attachment);
+        self
+    }
+
+    /// Adds a key-value pair to the metadata of the message.
+    pub fn metadata<K: AsRef<str>, V: AsRef<str>>(mut self, key: K, value: V) -> Self {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_message_request_body() {
+        let body = MessageRequestBody::builder(MessageRole::User, "Summarize the report.")
+            .attachment(
+                MessageAttachment::new(
+                    "file-abc123",
+                    vec![AttachmentTool::FileSearch, AttachmentTool::CodeInterpreter]
+                )
+            )
+            .build();
+        let json = serde_json::to_string(&body).unwrap();
+        assert_eq!(
+            json,
+            r#"{"role":"user","content":"Summarize the report.","attachments":[{"file_id":"file-abc123","tools":[{"type":"file_search"},{"type":"code_interpreter"}]}]}"#
+        );
+    }
+}
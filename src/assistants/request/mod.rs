@@ -0,0 +1,30 @@
+mod tool;
+pub use tool::{ AssistantTool, FunctionDefinition, FileSearchSettings };
+
+mod tool_resources;
+pub use tool_resources::{ ToolResources, CodeInterpreterResources, FileSearchResources };
+
+mod assistant_request_body;
+pub use assistant_request_body::{ AssistantRequestBody, AssistantRequestBodyBuilder };
+
+mod message_request_body;
+pub use message_request_body::{
+    MessageRequestBody,
+    MessageRequestBodyBuilder,
+    MessageRole,
+    MessageAttachment,
+    AttachmentTool,
+};
+
+mod thread_request_body;
+pub use thread_request_body::{ ThreadRequestBody, ThreadRequestBodyBuilder };
+
+mod run_request_body;
+pub use run_request_body::{ RunRequestBody, RunRequestBodyBuilder };
+
+mod tool_output;
+pub use tool_output::ToolOutput;
+pub(crate) use tool_output::SubmitToolOutputsRequestBody;
+
+mod list;
+pub use list::{ AssistantsListQuery, AssistantsListQueryBuilder };
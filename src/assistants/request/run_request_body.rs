@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+use serde::Serialize;
+use log::warn;
+use super::{ AssistantTool, MessageRequestBody };
+
+const MIN_TEMPERATURE: f32 = 0.0;
+const MAX_TEMPERATURE: f32 = 2.0;
+
+/// Request body of creating a run of an assistant on a thread.
+///
+/// The fields other than the assistant ID override the settings of the assistant for this run.
+#[derive(Debug, Serialize)]
+pub struct RunRequestBody {
+    assistant_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instructions: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    additional_instructions: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    additional_messages: Option<Vec<MessageRequestBody>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<AssistantTool>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<HashMap<String, String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_prompt_tokens: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_completion_tokens: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parallel_tool_calls: Option<bool>,
+}
+
+impl RunRequestBody {
+    pub fn builder<S: AsRef<str>>(assistant_id: S) -> RunRequestBodyBuilder {
+        RunRequestBodyBuilder::new(assistant_id)
+    }
+}
+
+pub struct RunRequestBodyBuilder {
+    assistant_id: String,
+    model: Option<String>,
+    instructions: Option<String>,
+    additional_instructions: Option<String>,
+    additional_messages: Option<Vec<MessageRequestBody>>,
+    tools: Option<Vec<AssistantTool>>,
+    metadata: Option<HashMap<String, String>>,
+    temperature: Option<f32>,
+    max_prompt_tokens: Option<u32>,
+    max_completion_tokens: Option<u32>,
+    parallel_tool_calls: Option<bool>,
+}
+
+impl RunRequestBodyBuilder {
+    /// Creates a new builder with `None` values for all optional fields.
+    pub fn new<S: AsRef<str>>(assistant_id: S) -> Self {
+        Self {
+            assistant_id: assistant_id.as_ref().to_string(),
+            model: None,
+            instructions: None,
+            additional_instructions: None,
+            additional_messages: None,
+            tools: None,
+            metadata: None,
+            temperature: None,
+            max_prompt_tokens: None,
+            max_completion_tokens: None,
+            parallel_tool_calls: None,
+        }
+    }
+
+    /// Builds the request body.
+    pub fn build(self) -> RunRequestBody {
+        RunRequestBody {
+            assistant_id: self.assistant_id,
+            model: self.model,
+            instructions: self.instructions,
+            additional_instructions: self.additional_instructions,
+            additional_messages: self.additional_messages,
+            tools: self.tools,
+            metadata: self.metadata,
+            temperature: self.temperature,
+            max_prompt_tokens: self.max_prompt_tokens,
+            max_completion_tokens: self.max_completion_tokens,
+            parallel_tool_calls: self.parallel_tool_calls,
+        }
+    }
+
+    /// Overrides the model of the assistant.
+    pub fn model<S: AsRef<str>>(mut self, model: S) -> Self {
+        self.model = Some(model.as_ref().to_string());
+        self
+    }
+
+    /// Overrides the instructions of the assistant.
+    pub fn instructions<S: AsRef<str>>(mut self, instructions: S) -> Self {
+        self.instructions = Some(instructions.as_ref().to_string());
+        self
+    }
+
+    /// Appends instructions to those of the assistant.
+    pub fn additional_instructions<S: AsRef<str>>(mut self, additional_instructions: S) -> Self {
+        self.additional_instructions = Some(additional_instructions.as_ref().to_string());
+        self
+    }
+
+    /// Adds a message to the thread before creating the run.
+    pub fn additional_message(mut self, message: MessageRequestBody) -> Self {
+        self.additional_messages.get_or_insert_with(Vec::new).push(message);
+        self
+    }
+
+    /// Overrides the tools of the assistant.
+    pub fn tools(mut self, tools: Vec<AssistantTool>) -> Self {
+        self.tools = Some(tools);
+        self
+    }
+
+    /// Adds a key-value pair to the metadata of the run.
+    pub fn metadata<K: AsRef<str>, V: AsRef<str>>(mut self, key: K, value: V) -> Self {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+
+    /// Sets the sampling temperature.
+    ///
+    /// The input value will be clamped in between 0.0 and 2.0.
+    pub fn temperature(mut self, temperature: f32) -> Self {
+        // Clamp the value to the valid range
+        let temperature = if !(MIN_TEMPERATURE..=MAX_TEMPERATURE).contains(&temperature) {
+            // Clamp the value
+            let t = temperature.clamp(MIN_TEMPERATURE, MAX_TEMPERATURE);
+
+            // Warn the user
+            warn!(
+                "input value of temperature is {temperature} which is out of range, it is now clamped to {t}"
+            );
+
+            t
+        } else {
+            temperature
+        };
+
+        self.temperature = Some(temperature);
+        self
+    }
+
+    /// Sets the maximum number of prompt tokens used over the course of the run.
+    pub fn max_prompt_tokens(mut self, max_prompt_tokens: u32) -> Self {
+        self.max_prompt_tokens = Some(max_prompt_tokens);
+        self
+    }
+
+    /// Sets the maximum number of completion tokens used over the course of the run.
+    pub fn max_completion_tokens(mut self, max_completion_tokens: u32) -> Self {
+        self.max_completion_tokens = Some(max_completion_tokens);
+        self
+    }
+
+    /// Sets whether to enable parallel function calling during tool use.
+    pub fn parallel_tool_calls(mut self, parallel_tool_calls: bool) -> Self {
+        self.parallel_tool_calls = Some(parallel_tool_calls);
+        self
+    }
+}
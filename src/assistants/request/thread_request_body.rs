@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+use serde::Serialize;
+use super::{ MessageRequestBody, ToolResources };
+
+/// Request body of creating or modifying a thread.
+#[derive(Debug, Serialize, Default)]
+pub struct ThreadRequestBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    messages: Option<Vec<MessageRequestBody>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_resources: Option<ToolResources>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<HashMap<String, String>>,
+}
+
+impl ThreadRequestBody {
+    pub fn builder() -> ThreadRequestBodyBuilder {
+        ThreadRequestBodyBuilder::new()
+    }
+}
+
+#[derive(Default)]
+pub struct ThreadRequestBodyBuilder {
+    messages: Option<Vec<MessageRequestBody>>,
+    tool_resources: Option<ToolResources>,
+    metadata: Option<HashMap<String, String>>,
+}
+
+impl ThreadRequestBodyBuilder {
+    /// Creates a new builder with `None` values for all fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds the request body.
+    pub fn build(self) -> ThreadRequestBody {
+        ThreadRequestBody {
+            messages: self.messages,
+            tool_resources: self.tool_resources,
+            metadata: self.metadata,
+        }
+    }
+
+    /// Adds a message to start the thread with.
+    /// Messages are ignored when modifying a thread.
+    pub fn message(mut self, message: MessageRequestBody) -> Self {
+        self.messages.get_or_insert_with(Vec::new).push(message);
+        self
+    }
+
+    /// Sets the resources used by the tools of the assistants in the thread.
+    pub fn tool_resources(mut self, tool_resources: ToolResources) -> Self {
+        self.tool_resources = Some(tool_resources);
+        self
+    }
+
+    /// Adds a key-value pair to the metadata of the thread.
+    pub fn metadata<K: AsRef<str>, V: AsRef<str>>(mut self, key: K, value: V) -> Self {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+}
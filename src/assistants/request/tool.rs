@@ -0,0 +1,116 @@
+use serde::{ Serialize, Deserialize };
+use serde_json::Value;
+use crate::chat::Function;
+
+/// A tool enabled on an assistant or a run.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AssistantTool {
+    CodeInterpreter,
+
+    FileSearch {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        file_search: Option<FileSearchSettings>,
+    },
+
+    Function {
+        function: FunctionDefinition,
+    },
+}
+
+impl AssistantTool {
+    /// Creates a file search tool with the default settings.
+    pub fn file_search() -> Self {
+        Self::FileSearch { file_search: None }
+    }
+
+    /// Creates a function tool.
+    pub fn function<F: Into<FunctionDefinition>>(function: F) -> Self {
+        Self::Function { function: function.into() }
+    }
+}
+
+/// A function that the model may call, with its parameters in a JSON schema.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FunctionDefinition {
+    pub name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Value>,
+
+    /// Whether to follow the schema exactly.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
+}
+
+impl From<Function> for FunctionDefinition {
+    fn from(function: Function) -> Self {
+        // The function serializes to the same shape
+        let function = serde_json::to_value(function).unwrap_or_default();
+
+        Self {
+            name: function["name"].as_str().unwrap_or_default().to_string(),
+            description: function["description"]
+                .as_str()
+                .map(|description| description.to_string()),
+            parameters: function.get("parameters").cloned(),
+            strict: None,
+        }
+    }
+}
+
+/// Settings of the file search tool.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct FileSearchSettings {
+    /// The maximum number of results, in between 1 and 50.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_num_results: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use crate::chat::FunctionParameter;
+    use super::*;
+
+    #[test]
+    fn serialize_assistant_tools() {
+        let tools = vec![
+            AssistantTool::CodeInterpreter,
+            AssistantTool::file_search(),
+            AssistantTool::function(
+                Function::builder("get_weather")
+                    .parameters(
+                        vec![FunctionParameter::new("city", true, json!({"type": "string"}))]
+                    )
+                    .build()
+            )
+        ];
+        assert_eq!(
+            serde_json::to_value(&tools).unwrap(),
+            json!([
+                { "type": "code_interpreter" },
+                { "type": "file_search" },
+                {
+                    "type": "function",
+                    "function": {
+                        "name": "get_weather",
+                        "parameters": {
+                            "type": "object",
+                            "properties": { "city": { "type": "string" } },
+                            "required": ["city"]
+                        }
+                    }
+                }
+            ])
+        );
+
+        let deserialized: Vec<AssistantTool> = serde_json
+            ::from_value(serde_json::to_value(&tools).unwrap())
+            .unwrap();
+        assert_eq!(deserialized, tools);
+    }
+}
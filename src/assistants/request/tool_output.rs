@@ -0,0 +1,23 @@
+use serde::Serialize;
+
+/// The output of a tool call requested by a run.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct ToolOutput {
+    pub tool_call_id: String,
+    pub output: String,
+}
+
+impl ToolOutput {
+    pub fn new<S: AsRef<str>, T: AsRef<str>>(tool_call_id: S, output: T) -> Self {
+        Self {
+            tool_call_id: tool_call_id.as_ref().to_string(),
+            output: output.as_ref().to_string(),
+        }
+    }
+}
+
+/// Request body of submitting the outputs of the tool calls requested by a run.
+#[derive(Debug, Serialize)]
+pub(crate) struct SubmitToolOutputsRequestBody<'a> {
+    pub tool_outputs: &'a [ToolOutput],
+}
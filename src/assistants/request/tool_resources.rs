@@ -0,0 +1,53 @@
+use serde::{ Serialize, Deserialize };
+
+/// Resources used by the tools of an assistant or a thread.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct ToolResources {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code_interpreter: Option<CodeInterpreterResources>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_search: Option<FileSearchResources>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct CodeInterpreterResources {
+    /// IDs of the files made available to the code interpreter, at most 20.
+    #[serde(default)]
+    pub file_ids: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct FileSearchResources {
+    /// IDs of the vector stores to search, at most 1.
+    #[serde(default)]
+    pub vector_store_ids: Vec<String>,
+}
+
+impl ToolResources {
+    /// Creates the resources of the code interpreter with the given files.
+    pub fn code_interpreter<S: AsRef<str>>(file_ids: &[S]) -> Self {
+        Self {
+            code_interpreter: Some(CodeInterpreterResources {
+                file_ids: file_ids
+                    .iter()
+                    .map(|file_id| file_id.as_ref().to_string())
+                    .collect(),
+            }),
+            file_search: None,
+        }
+    }
+
+    /// Creates the resources of the file search with the given vector stores.
+    pub fn file_search<S: AsRef<str>>(vector_store_ids: &[S]) -> Self {
+        Self {
+            code_interpreter: None,
+            file_search: Some(FileSearchResources {
+                vector_store_ids: vector_store_ids
+                    .iter()
+                    .map(|vector_store_id| vector_store_id.as_ref().to_string())
+                    .collect(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+use super::super::{ AssistantTool, ToolResources };
+
+/// An assistant that can call the model and use tools.
+#[derive(Debug, Deserialize, Clone)]
+pub struct Assistant {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub model: String,
+    pub instructions: Option<String>,
+
+    #[serde(default)]
+    pub tools: Vec<AssistantTool>,
+
+    pub tool_resources: Option<ToolResources>,
+    pub metadata: Option<HashMap<String, String>>,
+    pub temperature: Option<f32>,
+    pub top_p: Option<f32>,
+}
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+use super::super::{ MessageRole, MessageAttachment };
+
+/// A message in a thread.
+#[derive(Debug, Deserialize, Clone)]
+pub struct ThreadMessage {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+    pub thread_id: String,
+    pub status: Option<MessageStatus>,
+    pub role: MessageRole,
+    pub content: Vec<MessageContent>,
+
+    /// ID of the assistant that authored the message, if any.
+    pub assistant_id: Option<String>,
+
+    /// ID of the run in which the message was created, if any.
+    pub run_id: Option<String>,
+
+    pub attachments: Option<Vec<MessageAttachment>>,
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl ThreadMessage {
+    /// Gets the text of the message by joining the text parts of the content.
+    pub fn text(&self) -> String {
+        self.content
+            .iter()
+            .filter_map(|content| {
+                match content {
+                    MessageContent::Text { text } => Some(text.value.as_str()),
+                    _ => None,
+                }
+            })
+            .collect::<Vec<&str>>()
+            .join("\n")
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageStatus {
+    InProgress,
+    Incomplete,
+    Completed,
+}
+
+/// A part of the content of a message.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MessageContent {
+    Text {
+        text: MessageText,
+    },
+
+    ImageFile {
+        image_file: MessageImageFile,
+    },
+
+    ImageUrl {
+        image_url: MessageImageUrl,
+    },
+
+    Refusal {
+        refusal: String,
+    },
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct MessageText {
+    pub value: String,
+
+    /// Citations of files and paths to generated files in the text.
+    #[serde(default)]
+    pub annotations: Vec<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct MessageImageFile {
+    pub file_id: String,
+    pub detail: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct MessageImageUrl {
+    pub url: String,
+    pub detail: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_thread_message() {
+        let json =
+            r#"{
+            "id": "msg_abc123",
+            "object": "thread.message",
+            "created_at": 1699017614,
+            "assistant_id": "asst_abc123",
+            "thread_id": "thread_abc123",
+            "run_id": "run_abc123",
+            "status": "completed",
+            "role": "assistant",
+            "content": [
+                { "type": "text", "text": { "value": "Here is the chart.", "annotations": [] } },
+                { "type": "image_file", "image_file": { "file_id": "file-abc123" } }
+            ],
+            "attachments": [{ "file_id": "file-def456", "tools": [{ "type": "code_interpreter" }] }],
+            "metadata": {}
+        }"#;
+
+        let message: ThreadMessage = serde_json::from_str(json).unwrap();
+        assert_eq!(message.role, MessageRole::Assistant);
+        assert_eq!(message.text(), "Here is the chart.");
+        assert_eq!(
+            message.content[1],
+            MessageContent::ImageFile {
+                image_file: MessageImageFile { file_id: "file-abc123".to_string(), detail: None },
+            }
+        );
+    }
+}
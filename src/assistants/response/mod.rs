@@ -0,0 +1,36 @@
+mod assistant;
+pub use assistant::Assistant;
+
+mod thread;
+pub use thread::Thread;
+
+mod message;
+pub use message::{
+    ThreadMessage,
+    MessageStatus,
+    MessageContent,
+    MessageText,
+    MessageImageFile,
+    MessageImageUrl,
+};
+
+mod run;
+pub use run::{ Run, RunStatus, RequiredAction, SubmitToolOutputs, RunError, RunUsage };
+
+mod run_step;
+pub use run_step::{ RunStep, RunStepStatus, RunStepDetails, MessageCreation };
+
+mod stream_event;
+pub use stream_event::{
+    AssistantStreamEvent,
+    AssistantStreamError,
+    MessageDelta,
+    MessageDeltaFields,
+    MessageDeltaContent,
+    MessageDeltaText,
+    RunStepDelta,
+    RunStepDeltaFields,
+};
+
+mod stream;
+pub use stream::AssistantEventStream;
@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+use crate::chat::ChatCompletionToolCall;
+use super::super::AssistantTool;
+
+/// An invocation of an assistant on a thread.
+///
+/// The timestamps are Unix timestamps in seconds.
+#[derive(Debug, Deserialize, Clone)]
+pub struct Run {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+    pub thread_id: String,
+    pub assistant_id: String,
+    pub status: RunStatus,
+
+    /// The action required to continue the run, if its status is `RequiresAction`.
+    pub required_action: Option<RequiredAction>,
+
+    pub last_error: Option<RunError>,
+    pub expires_at: Option<u64>,
+    pub started_at: Option<u64>,
+    pub cancelled_at: Option<u64>,
+    pub failed_at: Option<u64>,
+    pub completed_at: Option<u64>,
+    pub model: String,
+    pub instructions: Option<String>,
+
+    #[serde(default)]
+    pub tools: Vec<AssistantTool>,
+
+    /// Token usage of the run, which is only present once the run is in a terminal state.
+    pub usage: Option<RunUsage>,
+
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl Run {
+    /// Gets the tool calls that must be answered with their outputs to continue the run.
+    pub fn required_tool_calls(&self) -> &[ChatCompletionToolCall] {
+        match &self.required_action {
+            Some(RequiredAction::SubmitToolOutputs { submit_tool_outputs }) => {
+                &submit_tool_outputs.tool_calls
+            }
+            None => &[],
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RunStatus {
+    Queued,
+    InProgress,
+    RequiresAction,
+    Cancelling,
+    Cancelled,
+    Failed,
+    Completed,
+    Incomplete,
+    Expired,
+}
+
+impl RunStatus {
+    /// Whether the run will no longer change.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::Cancelled | Self::Failed | Self::Completed | Self::Incomplete | Self::Expired
+        )
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RequiredAction {
+    SubmitToolOutputs {
+        submit_tool_outputs: SubmitToolOutputs,
+    },
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct SubmitToolOutputs {
+    pub tool_calls: Vec<ChatCompletionToolCall>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct RunError {
+    pub code: String,
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+pub struct RunUsage {
+    pub prompt_tokens: u32,
+    pub completion_tokens: u32,
+    pub total_tokens: u32,
+}
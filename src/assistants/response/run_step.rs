@@ -0,0 +1,50 @@
+use serde::Deserialize;
+use super::{ RunError, RunUsage };
+
+/// A step in the execution of a run, either creating a message or calling tools.
+#[derive(Debug, Deserialize, Clone)]
+pub struct RunStep {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+    pub assistant_id: String,
+    pub thread_id: String,
+    pub run_id: String,
+    pub status: RunStepStatus,
+    pub step_details: RunStepDetails,
+    pub last_error: Option<RunError>,
+    pub expired_at: Option<u64>,
+    pub cancelled_at: Option<u64>,
+    pub failed_at: Option<u64>,
+    pub completed_at: Option<u64>,
+    pub usage: Option<RunUsage>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RunStepStatus {
+    InProgress,
+    Cancelled,
+    Failed,
+    Completed,
+    Expired,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RunStepDetails {
+    MessageCreation {
+        message_creation: MessageCreation,
+    },
+
+    /// Calls of the code interpreter, file search or functions,
+    /// whose shapes differ by the type of the tool.
+    ToolCalls {
+        tool_calls: Vec<serde_json::Value>,
+    },
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct MessageCreation {
+    pub message_id: String,
+}
@@ -0,0 +1,105 @@
+use std::{ pin::Pin, task::{ Context, Poll } };
+use bytes::Bytes;
+use futures::{ Stream, StreamExt };
+use crate::{ Result, Error, AssistantsApiError, sse::EventStream };
+use super::AssistantStreamEvent;
+
+/// A stream of the server-sent events of a run, ending after the `done` event.
+pub struct AssistantEventStream {
+    stream: EventStream,
+    done: bool,
+}
+
+impl AssistantEventStream {
+    pub fn new<S: 'static + Stream<Item = reqwest::Result<Bytes>>>(stream: S) -> Self {
+        Self { stream: EventStream::new(stream), done: false }
+    }
+}
+
+impl Stream for AssistantEventStream {
+    type Item = Result<AssistantStreamEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            match self.stream.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(frame))) => {
+                    // Skip unnamed and unknown events
+                    let Some(event) = frame.event.as_deref() else {
+                        continue;
+                    };
+                    let Some(event) = AssistantStreamEvent::parse(event, &frame.data) else {
+                        continue;
+                    };
+
+                    if let Ok(AssistantStreamEvent::Done) = event {
+                        self.done = true;
+                    }
+
+                    return Poll::Ready(Some(event));
+                }
+                Poll::Ready(Some(Err(error))) => {
+                    return Poll::Ready(
+                        Some(
+                            Err(
+                                Error::AssistantsApi(AssistantsApiError::ReceiveStreamedBytes {
+                                    source: error,
+                                })
+                            )
+                        )
+                    );
+                }
+                Poll::Ready(None) => {
+                    // The bytes stream ends without a `done` event
+                    self.done = true;
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => {
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream;
+    use crate::assistants::RunStatus;
+    use super::*;
+
+    #[tokio::test]
+    async fn decode_named_events() {
+        let events = concat!(
+            "event: thread.run.created\n",
+            r#"data: {"id":"run_abc123","object":"thread.run","created_at":1699063290,"thread_id":"thread_abc123","assistant_id":"asst_abc123","status":"queued","model":"gpt-4o","tools":[]}"#,
+            "\n\nevent: thread.message.delta\n",
+            r#"data: {"id":"msg_abc123","object":"thread.message.delta","delta":{"content":[{"index":0,"type":"text","text":{"value":"Hello"}}]}}"#,
+            "\n\nevent: thread.unknown\ndata: {}\n\n",
+            "event: done\ndata: [DONE]\n\n"
+        );
+
+        // Split the bytes so that events span several chunks
+        let (first, second) = events.split_at(100);
+        let chunks = vec![Ok(Bytes::from(first)), Ok(Bytes::from(second))];
+        let mut stream = AssistantEventStream::new(stream::iter(chunks));
+
+        match stream.next().await {
+            Some(Ok(AssistantStreamEvent::RunCreated(run))) => {
+                assert_eq!(run.status, RunStatus::Queued);
+            }
+            other => panic!("expected a run created event, got {other:?}"),
+        }
+        match stream.next().await {
+            Some(Ok(AssistantStreamEvent::MessageDelta(delta))) => {
+                assert_eq!(delta.text(), "Hello");
+            }
+            other => panic!("expected a message delta event, got {other:?}"),
+        }
+        assert!(matches!(stream.next().await, Some(Ok(AssistantStreamEvent::Done))));
+        assert!(stream.next().await.is_none());
+    }
+}
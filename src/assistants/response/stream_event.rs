@@ -0,0 +1,247 @@
+use serde::Deserialize;
+use crate::{ Result, Error, AssistantsApiError };
+use super::{
+    super::MessageRole,
+    Thread,
+    ThreadMessage,
+    Run,
+    RunStep,
+    MessageImageFile,
+    MessageImageUrl,
+};
+
+/// An event streamed while a run executes, named by the `event` field of the server-sent event.
+#[derive(Debug, Clone)]
+pub enum AssistantStreamEvent {
+    /// `thread.created`
+    ThreadCreated(Thread),
+
+    /// `thread.run.created`
+    RunCreated(Run),
+
+    /// `thread.run.queued`
+    RunQueued(Run),
+
+    /// `thread.run.in_progress`
+    RunInProgress(Run),
+
+    /// `thread.run.requires_action`, the run waits for the outputs of its tool calls.
+    RunRequiresAction(Run),
+
+    /// `thread.run.completed`
+    RunCompleted(Run),
+
+    /// `thread.run.incomplete`
+    RunIncomplete(Run),
+
+    /// `thread.run.failed`
+    RunFailed(Run),
+
+    /// `thread.run.cancelling`
+    RunCancelling(Run),
+
+    /// `thread.run.cancelled`
+    RunCancelled(Run),
+
+    /// `thread.run.expired`
+    RunExpired(Run),
+
+    /// `thread.run.step.created`
+    RunStepCreated(RunStep),
+
+    /// `thread.run.step.in_progress`
+    RunStepInProgress(RunStep),
+
+    /// `thread.run.step.delta`
+    RunStepDelta(RunStepDelta),
+
+    /// `thread.run.step.completed`
+    RunStepCompleted(RunStep),
+
+    /// `thread.run.step.failed`
+    RunStepFailed(RunStep),
+
+    /// `thread.run.step.cancelled`
+    RunStepCancelled(RunStep),
+
+    /// `thread.run.step.expired`
+    RunStepExpired(RunStep),
+
+    /// `thread.message.created`
+    MessageCreated(ThreadMessage),
+
+    /// `thread.message.in_progress`
+    MessageInProgress(ThreadMessage),
+
+    /// `thread.message.delta`
+    MessageDelta(MessageDelta),
+
+    /// `thread.message.completed`
+    MessageCompleted(ThreadMessage),
+
+    /// `thread.message.incomplete`
+    MessageIncomplete(ThreadMessage),
+
+    /// `error`
+    Error(AssistantStreamError),
+
+    /// `done`, the last event of the stream.
+    Done,
+}
+
+impl AssistantStreamEvent {
+    /// Parses the data of a server-sent event by its name.
+    ///
+    /// Returns `None` for events unknown to this crate, so that they can be skipped.
+    pub(crate) fn parse(event: &str, data: &str) -> Option<Result<Self>> {
+        let event = match event {
+            "thread.created" => parse_data(event, data).map(Self::ThreadCreated),
+            "thread.run.created" => parse_data(event, data).map(Self::RunCreated),
+            "thread.run.queued" => parse_data(event, data).map(Self::RunQueued),
+            "thread.run.in_progress" => parse_data(event, data).map(Self::RunInProgress),
+            "thread.run.requires_action" => parse_data(event, data).map(Self::RunRequiresAction),
+            "thread.run.completed" => parse_data(event, data).map(Self::RunCompleted),
+            "thread.run.incomplete" => parse_data(event, data).map(Self::RunIncomplete),
+            "thread.run.failed" => parse_data(event, data).map(Self::RunFailed),
+            "thread.run.cancelling" => parse_data(event, data).map(Self::RunCancelling),
+            "thread.run.cancelled" => parse_data(event, data).map(Self::RunCancelled),
+            "thread.run.expired" => parse_data(event, data).map(Self::RunExpired),
+            "thread.run.step.created" => parse_data(event, data).map(Self::RunStepCreated),
+            "thread.run.step.in_progress" => parse_data(event, data).map(Self::RunStepInProgress),
+            "thread.run.step.delta" => parse_data(event, data).map(Self::RunStepDelta),
+            "thread.run.step.completed" => parse_data(event, data).map(Self::RunStepCompleted),
+            "thread.run.step.failed" => parse_data(event, data).map(Self::RunStepFailed),
+            "thread.run.step.cancelled" => parse_data(event, data).map(Self::RunStepCancelled),
+            "thread.run.step.expired" => parse_data(event, data).map(Self::RunStepExpired),
+            "thread.message.created" => parse_data(event, data).map(Self::MessageCreated),
+            "thread.message.in_progress" => parse_data(event, data).map(Self::MessageInProgress),
+            "thread.message.delta" => parse_data(event, data).map(Self::MessageDelta),
+            "thread.message.completed" => parse_data(event, data).map(Self::MessageCompleted),
+            "thread.message.incomplete" => parse_data(event, data).map(Self::MessageIncomplete),
+            "error" => parse_data(event, data).map(Self::Error),
+            "done" => Ok(Self::Done),
+            _ => {
+                return None;
+            }
+        };
+
+        Some(event)
+    }
+
+    /// Gets the run of a `thread.run.*` event.
+    pub fn run(&self) -> Option<&Run> {
+        match self {
+            | Self::RunCreated(run)
+            | Self::RunQueued(run)
+            | Self::RunInProgress(run)
+            | Self::RunRequiresAction(run)
+            | Self::RunCompleted(run)
+            | Self::RunIncomplete(run)
+            | Self::RunFailed(run)
+            | Self::RunCancelling(run)
+            | Self::RunCancelled(run)
+            | Self::RunExpired(run) => Some(run),
+            _ => None,
+        }
+    }
+}
+
+fn parse_data<T: for<'de> Deserialize<'de>>(event: &str, data: &str) -> Result<T> {
+    serde_json::from_str(data).map_err(|error| {
+        Error::AssistantsApi(AssistantsApiError::ParseStreamEvent {
+            event: event.to_string(),
+            source: error,
+        })
+    })
+}
+
+/// The error of an `error` event.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct AssistantStreamError {
+    pub message: String,
+
+    #[serde(rename = "type")]
+    pub error_type: Option<String>,
+
+    pub code: Option<String>,
+    pub param: Option<String>,
+}
+
+/// The changed fields of a message while it is being generated.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct MessageDelta {
+    pub id: String,
+    pub object: String,
+    pub delta: MessageDeltaFields,
+}
+
+impl MessageDelta {
+    /// Gets the text fragment of the delta by joining the text parts of the content.
+    pub fn text(&self) -> String {
+        self.delta.content
+            .iter()
+            .filter_map(|content| {
+                match content {
+                    MessageDeltaContent::Text { text, .. } => text.value.as_deref(),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct MessageDeltaFields {
+    pub role: Option<MessageRole>,
+
+    #[serde(default)]
+    pub content: Vec<MessageDeltaContent>,
+}
+
+/// A fragment of a part of the content of a message,
+/// where the index is the position of the part in the content.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MessageDeltaContent {
+    Text {
+        index: usize,
+        text: MessageDeltaText,
+    },
+
+    ImageFile {
+        index: usize,
+        image_file: MessageImageFile,
+    },
+
+    ImageUrl {
+        index: usize,
+        image_url: MessageImageUrl,
+    },
+
+    Refusal {
+        index: usize,
+        refusal: Option<String>,
+    },
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct MessageDeltaText {
+    pub value: Option<String>,
+
+    #[serde(default)]
+    pub annotations: Vec<serde_json::Value>,
+}
+
+/// The changed fields of a run step while it is in progress.
+#[derive(Debug, Deserialize, Clone)]
+pub struct RunStepDelta {
+    pub id: String,
+    pub object: String,
+    pub delta: RunStepDeltaFields,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct RunStepDeltaFields {
+    /// Fragments of the details of the step, whose shapes differ by the type of the step.
+    pub step_details: Option<serde_json::Value>,
+}
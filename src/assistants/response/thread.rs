@@ -0,0 +1,13 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+use super::super::ToolResources;
+
+/// A conversation session between an assistant and a user.
+#[derive(Debug, Deserialize, Clone)]
+pub struct Thread {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+    pub tool_resources: Option<ToolResources>,
+    pub metadata: Option<HashMap<String, String>>,
+}
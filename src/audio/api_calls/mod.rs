@@ -0,0 +1,8 @@
+mod transcription;
+pub use transcription::create_transcription;
+
+mod translation;
+pub use translation::create_translation;
+
+mod speech;
+pub use speech::{ create_speech, create_speech_stream };
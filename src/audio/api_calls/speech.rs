@@ -0,0 +1,100 @@
+use bytes::Bytes;
+use crate::{ Result, Error, AudioApiError, OpenAIClient };
+use super::super::{ SPEECH_API_PATH, SpeechRequestBody, SpeechStream };
+
+/// Generates audio from the input text, and
+/// returns the audio bytes once the whole clip is received.
+pub async fn create_speech(
+    client: &OpenAIClient,
+    request_body: &SpeechRequestBody
+) -> Result<Bytes> {
+    // Send the request
+    let response = send_speech_request(client, request_body).await?;
+
+    // Receive all the bytes
+    match response.bytes().await {
+        Ok(bytes) => Ok(bytes),
+        Err(error) => Err(Error::AudioApi(AudioApiError::ReceiveSpeechBytes { source: error })),
+    }
+}
+
+/// Generates audio from the input text, and
+/// returns a stream of the audio bytes as they are generated.
+pub async fn create_speech_stream(
+    client: &OpenAIClient,
+    request_body: &SpeechRequestBody
+) -> Result<SpeechStream> {
+    // Send the request
+    let response = send_speech_request(client, request_body).await?;
+
+    // Wrap the bytes stream in a SpeechStream
+    Ok(SpeechStream::new(response.bytes_stream()))
+}
+
+async fn send_speech_request(
+    client: &OpenAIClient,
+    request_body: &SpeechRequestBody
+) -> Result<reqwest::Response> {
+    client.send(|client| {
+        client
+            .post(client.endpoint(SPEECH_API_PATH))
+            .json(request_body)
+    }).await
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use serde_json::json;
+    use crate::audio::*;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_create_speech() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/audio/speech")
+            .match_header("authorization", "Bearer sk-test")
+            .match_body(
+                Matcher::Json(
+                    json!({
+                        "model": "tts-1",
+                        "input": "Hello, world!",
+                        "voice": "onyx",
+                        "response_format": "wav"
+                    })
+                )
+            )
+            .with_header("content-type", "audio/wav")
+            .with_body("RIFF....WAVE")
+            .expect(2)
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // Build the request body
+        let request_body = SpeechRequestBody::builder(
+            TextToSpeechModel::Tts1,
+            "Hello, world!",
+            SpeechVoice::Onyx
+        )
+            .response_format(SpeechResponseFormat::Wav)
+            .build();
+
+        // Get the whole clip
+        let audio = create_speech(&client, &request_body).await?;
+        assert_eq!(audio, "RIFF....WAVE");
+
+        // Stream the clip to a file
+        let path = std::env::temp_dir().join("rustyopenai-speech.wav");
+        let num_bytes = create_speech_stream(&client, &request_body).await?.save(&path).await?;
+        assert_eq!(num_bytes, 12);
+        assert_eq!(tokio::fs::read(&path).await.unwrap(), b"RIFF....WAVE");
+
+        mock.assert_async().await;
+
+        Ok(())
+    }
+}
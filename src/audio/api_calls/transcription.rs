@@ -0,0 +1,89 @@
+use crate::{ Result, Error, AudioApiError, OpenAIClient };
+use super::super::{ TRANSCRIPTIONS_API_PATH, TranscriptionRequestBody, TranscriptionResponse };
+
+/// Transcribes audio into the input language.
+///
+/// The audio file is validated before uploading.
+/// The output is parsed according to the requested response format.
+pub async fn create_transcription(
+    client: &OpenAIClient,
+    request_body: TranscriptionRequestBody
+) -> Result<TranscriptionResponse> {
+    // Get the response format before the request body is consumed
+    let response_format = request_body.response_format();
+
+    // Convert the request body to a multipart form
+    let form = request_body.into_form().await?;
+
+    // Send the request
+    let response = client.send(|client| {
+        client
+            .post(client.endpoint(TRANSCRIPTIONS_API_PATH))
+            .multipart(form)
+    }).await?;
+
+    // Receive the response body
+    let body = match response.text().await {
+        Ok(body) => body,
+        Err(error) => {
+            return Err(Error::AudioApi(AudioApiError::ReceiveResponseBody { source: error }));
+        }
+    };
+
+    // Parse the response
+    TranscriptionResponse::parse(response_format, body)
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use crate::{ UploadFile, audio::* };
+    use super::*;
+
+    #[tokio::test]
+    async fn test_create_transcription() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/audio/transcriptions")
+            .match_header("authorization", "Bearer sk-test")
+            .match_body(
+                Matcher::AllOf(
+                    vec![
+                        Matcher::Regex(r#"name="file"; filename="speech.mp3""#.to_string()),
+                        Matcher::Regex(r#"name="model"\r\n\r\nwhisper-1"#.to_string()),
+                        Matcher::Regex(r#"name="language"\r\n\r\nen"#.to_string()),
+                        Matcher::Regex(r#"name="response_format"\r\n\r\nsrt"#.to_string())
+                    ]
+                )
+            )
+            .with_header("content-type", "text/plain")
+            .with_body("1\n00:00:00,000 --> 00:00:01,500\nHello.\n\n")
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // Build the request body
+        let request_body = TranscriptionRequestBody::builder(
+            UploadFile::from_bytes("speech.mp3", vec![0; 16]),
+            "whisper-1"
+        )
+            .language("en")
+            .response_format(AudioResponseFormat::Srt)
+            .build();
+
+        // Send the request
+        let response = create_transcription(&client, request_body).await?;
+        mock.assert_async().await;
+
+        let subtitles = match response {
+            TranscriptionResponse::Subtitles(subtitles) => subtitles,
+            _ => panic!("expected subtitles"),
+        };
+        assert_eq!(subtitles.cues.len(), 1);
+        assert_eq!(subtitles.text(), "Hello.");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{ Result, Error, AudioApiError, OpenAIClient };
+use super::super::{ TRANSLATIONS_API_PATH, TranslationRequestBody, TranscriptionResponse };
+
+/// Translates audio into English.
+///
+/// The audio file is validated before uploading.
+/// The output is parsed according to the requested response format.
+pub async fn create_translation(
+    client: &OpenAIClient,
+    request_body: TranslationRequestBody
+) -> Result<TranscriptionResponse> {
+    // Get the response format before the request body is consumed
+    let response_format = request_body.response_format();
+
+    // Convert the request body to a multipart form
+    let form = request_body.into_form().await?;
+
+    // Send the request
+    let response = client.send(|client| {
+        client
+            .post(client.endpoint(TRANSLATIONS_API_PATH))
+            .multipart(form)
+    }).await?;
+
+    // Receive the response body
+    let body = match response.text().await {
+        Ok(body) => body,
+        Err(error) => {
+            return Err(Error::AudioApi(AudioApiError::ReceiveResponseBody { source: error }));
+        }
+    };
+
+    // Parse the response
+    TranscriptionResponse::parse(response_format, body)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use crate::{ UploadFile, audio::* };
+    use super::*;
+
+    #[tokio::test]
+    async fn test_create_translation() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/audio/translations")
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "text": "Hello, my name is Wolfgang." }).to_string())
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // Build the request body
+        let request_body = TranslationRequestBody::builder(
+            UploadFile::from_bytes("german.m4a", vec![0; 16]),
+            "whisper-1"
+        ).build();
+
+        // Send the request
+        let response = create_translation(&client, request_body).await?;
+        mock.assert_async().await;
+
+        assert_eq!(response.text(), "Hello, my name is Wolfgang.");
+
+        Ok(())
+    }
+}
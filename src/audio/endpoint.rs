@@ -0,0 +1,8 @@
+/// Path of the speech API under the base URL of the client.
+pub const SPEECH_API_PATH: &str = "audio/speech";
+
+/// Path of the transcription API under the base URL of the client.
+pub const TRANSCRIPTIONS_API_PATH: &str = "audio/transcriptions";
+
+/// Path of the translation API under the base URL of the client.
+pub const TRANSLATIONS_API_PATH: &str = "audio/translations";
@@ -0,0 +1,25 @@
+mod transcription;
+pub use transcription::{ TranscriptionRequestBody, TranscriptionRequestBodyBuilder };
+
+mod translation;
+pub use translation::{ TranslationRequestBody, TranslationRequestBodyBuilder };
+
+mod response_format;
+pub use response_format::AudioResponseFormat;
+
+mod timestamp_granularity;
+pub use timestamp_granularity::TimestampGranularity;
+
+mod speech;
+pub use speech::{ SpeechRequestBody, SpeechRequestBodyBuilder };
+
+mod speech_model;
+pub use speech_model::TextToSpeechModel;
+
+mod voice;
+pub use voice::SpeechVoice;
+
+mod speech_response_format;
+pub use speech_response_format::SpeechResponseFormat;
+
+mod validation;
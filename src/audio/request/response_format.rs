@@ -0,0 +1,23 @@
+use serde::Serialize;
+
+/// The format of the transcript or translation output.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AudioResponseFormat {
+    /// A JSON object containing only the text.
+    #[default]
+    Json,
+
+    /// Plain text.
+    Text,
+
+    /// SubRip subtitles.
+    Srt,
+
+    /// A JSON object containing the text, language, duration,
+    /// and optionally segments and words with timestamps.
+    VerboseJson,
+
+    /// WebVTT subtitles.
+    Vtt,
+}
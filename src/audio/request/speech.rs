@@ -0,0 +1,136 @@
+use serde::Serialize;
+use log::warn;
+use super::{ TextToSpeechModel, SpeechVoice, SpeechResponseFormat };
+
+const MAX_INPUT_LENGTH: usize = 4096;
+const MIN_SPEED: f32 = 0.25;
+const MAX_SPEED: f32 = 4.0;
+
+/// Request body of generating audio from the input text.
+#[derive(Debug, Serialize)]
+pub struct SpeechRequestBody {
+    model: TextToSpeechModel,
+    input: String,
+    voice: SpeechVoice,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<SpeechResponseFormat>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    speed: Option<f32>,
+}
+
+impl SpeechRequestBody {
+    pub fn builder<S: AsRef<str>>(
+        model: TextToSpeechModel,
+        input: S,
+        voice: SpeechVoice
+    ) -> SpeechRequestBodyBuilder {
+        SpeechRequestBodyBuilder::new(model, input, voice)
+    }
+}
+
+pub struct SpeechRequestBodyBuilder {
+    model: TextToSpeechModel,
+    input: String,
+    voice: SpeechVoice,
+    response_format: Option<SpeechResponseFormat>,
+    speed: Option<f32>,
+}
+
+impl SpeechRequestBodyBuilder {
+    /// Creates a new builder with `None` values for all optional fields.
+    ///
+    /// If the input text is longer than 4096 characters,
+    /// then only the first 4096 characters will be kept.
+    pub fn new<S: AsRef<str>>(model: TextToSpeechModel, input: S, voice: SpeechVoice) -> Self {
+        // Only keep the first 4096 characters if the input is too long
+        let mut input = input.as_ref().to_string();
+        if let Some((index, _)) = input.char_indices().nth(MAX_INPUT_LENGTH) {
+            input.truncate(index);
+
+            // Warn the user
+            warn!("input text is too long, only the first {MAX_INPUT_LENGTH} characters are kept");
+        }
+
+        Self {
+            model,
+            input,
+            voice,
+            response_format: None,
+            speed: None,
+        }
+    }
+
+    /// Builds the request body.
+    pub fn build(self) -> SpeechRequestBody {
+        SpeechRequestBody {
+            model: self.model,
+            input: self.input,
+            voice: self.voice,
+            response_format: self.response_format,
+            speed: self.speed,
+        }
+    }
+
+    /// Sets the format of the generated audio.
+    ///
+    /// It defaults to MP3 on the server side.
+    pub fn response_format(mut self, response_format: SpeechResponseFormat) -> Self {
+        self.response_format = Some(response_format);
+        self
+    }
+
+    /// Sets the speed of the generated audio.
+    ///
+    /// The input value will be clamped in between 0.25 and 4.0.
+    pub fn speed(mut self, speed: f32) -> Self {
+        // Clamp the value to the valid range
+        let speed = if !(MIN_SPEED..=MAX_SPEED).contains(&speed) {
+            // Clamp the value
+            let s = speed.clamp(MIN_SPEED, MAX_SPEED);
+
+            // Warn the user
+            warn!("input value of speed is {speed} which is out of range, it is now clamped to {s}");
+
+            s
+        } else {
+            speed
+        };
+
+        self.speed = Some(speed);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_speech_request_body() {
+        let body = SpeechRequestBody::builder(
+            TextToSpeechModel::Tts1Hd,
+            "Hello, world!",
+            SpeechVoice::Alloy
+        )
+            .response_format(SpeechResponseFormat::Opus)
+            .speed(8.0)
+            .build();
+        let json = serde_json::to_string(&body).unwrap();
+        assert_eq!(
+            json,
+            r#"{"model":"tts-1-hd","input":"Hello, world!","voice":"alloy","response_format":"opus","speed":4.0}"#
+        );
+    }
+
+    #[test]
+    fn truncate_input() {
+        let body = SpeechRequestBody::builder(
+            TextToSpeechModel::Tts1,
+            "é".repeat(5000),
+            SpeechVoice::Nova
+        ).build();
+        assert_eq!(body.input.chars().count(), 4096);
+    }
+}
@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+pub enum TextToSpeechModel {
+    /// Optimized for real-time use cases.
+    #[serde(rename = "tts-1")]
+    Tts1,
+
+    /// Optimized for quality.
+    #[serde(rename = "tts-1-hd")]
+    Tts1Hd,
+}
@@ -0,0 +1,38 @@
+use serde::Serialize;
+
+/// The format of the generated audio.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SpeechResponseFormat {
+    /// The default format.
+    Mp3,
+
+    /// For internet streaming and communication, low latency.
+    Opus,
+
+    /// For digital audio compression, preferred by YouTube, Android, iOS.
+    Aac,
+
+    /// For lossless audio compression.
+    Flac,
+
+    /// Uncompressed WAV audio, suitable for low-latency applications to avoid decoding overhead.
+    Wav,
+
+    /// Raw samples in 24kHz (16-bit signed, low-endian), without the header.
+    Pcm,
+}
+
+impl SpeechResponseFormat {
+    /// Gets the file extension without the leading dot.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Mp3 => "mp3",
+            Self::Opus => "opus",
+            Self::Aac => "aac",
+            Self::Flac => "flac",
+            Self::Wav => "wav",
+            Self::Pcm => "pcm",
+        }
+    }
+}
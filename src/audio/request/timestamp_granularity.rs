@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+/// The granularity of the timestamps to populate for a transcription.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampGranularity {
+    /// Generating word timestamps incurs additional latency.
+    Word,
+
+    /// Segment timestamps incur no additional latency.
+    Segment,
+}
@@ -0,0 +1,199 @@
+use reqwest::multipart::Form;
+use log::warn;
+use crate::{ Result, UploadFile, utils::to_form_text };
+use super::{ AudioResponseFormat, TimestampGranularity, validation::validate_audio_file };
+
+const MIN_TEMPERATURE: f32 = 0.0;
+const MAX_TEMPERATURE: f32 = 1.0;
+
+/// Request body of transcribing audio into the input language.
+///
+/// It is sent as a multipart form rather than JSON.
+pub struct TranscriptionRequestBody {
+    file: UploadFile,
+    model: String,
+    language: Option<String>,
+    prompt: Option<String>,
+    response_format: Option<AudioResponseFormat>,
+    temperature: Option<f32>,
+    timestamp_granularities: Option<Vec<TimestampGranularity>>,
+}
+
+impl TranscriptionRequestBody {
+    pub fn builder<S: AsRef<str>>(file: UploadFile, model: S) -> TranscriptionRequestBodyBuilder {
+        TranscriptionRequestBodyBuilder::new(file, model)
+    }
+
+    /// Gets the response format, which defaults to JSON.
+    pub(crate) fn response_format(&self) -> AudioResponseFormat {
+        self.response_format.unwrap_or_default()
+    }
+
+    /// Validates the audio file, and converts to a multipart form.
+    pub(crate) async fn into_form(self) -> Result<Form> {
+        // Validate the audio file
+        let file = self.file.prepare(0).await?;
+        validate_audio_file(&file)?;
+
+        let mut form = Form::new().part("file", file.into_part()).text("model", self.model);
+
+        // Add the optional fields
+        if let Some(language) = self.language {
+            form = form.text("language", language);
+        }
+        if let Some(prompt) = self.prompt {
+            form = form.text("prompt", prompt);
+        }
+        if let Some(response_format) = self.response_format {
+            form = form.text("response_format", to_form_text(&response_format));
+        }
+        if let Some(temperature) = self.temperature {
+            form = form.text("temperature", temperature.to_string());
+        }
+        if let Some(timestamp_granularities) = self.timestamp_granularities {
+            for timestamp_granularity in timestamp_granularities {
+                form = form.text("timestamp_granularities[]", to_form_text(&timestamp_granularity));
+            }
+        }
+
+        Ok(form)
+    }
+}
+
+pub struct TranscriptionRequestBodyBuilder {
+    file: UploadFile,
+    model: String,
+    language: Option<String>,
+    prompt: Option<String>,
+    response_format: Option<AudioResponseFormat>,
+    temperature: Option<f32>,
+    timestamp_granularities: Option<Vec<TimestampGranularity>>,
+}
+
+impl TranscriptionRequestBodyBuilder {
+    /// Creates a new builder with `None` values for all optional fields.
+    ///
+    /// The audio file must be in one of these formats:
+    /// flac, mp3, mp4, mpeg, mpga, m4a, ogg, wav, or webm,
+    /// and less than 25 MB.
+    pub fn new<S: AsRef<str>>(file: UploadFile, model: S) -> Self {
+        Self {
+            file,
+            model: model.as_ref().to_string(),
+            language: None,
+            prompt: None,
+            response_format: None,
+            temperature: None,
+            timestamp_granularities: None,
+        }
+    }
+
+    /// Builds the request body.
+    ///
+    /// If timestamp granularities are set, the response format will be revised to `VerboseJson`
+    /// since it is the only format supporting timestamps.
+    pub fn build(self) -> TranscriptionRequestBody {
+        let response_format = match (self.response_format, &self.timestamp_granularities) {
+            (Some(AudioResponseFormat::VerboseJson), _) | (_, None) => self.response_format,
+            (response_format, Some(_)) => {
+                // Warn the user
+                warn!(
+                    "response_format is {:?}, it is now revised to VerboseJson to use timestamp_granularities",
+                    response_format.unwrap_or_default()
+                );
+
+                Some(AudioResponseFormat::VerboseJson)
+            }
+        };
+
+        TranscriptionRequestBody {
+            file: self.file,
+            model: self.model,
+            language: self.language,
+            prompt: self.prompt,
+            response_format,
+            temperature: self.temperature,
+            timestamp_granularities: self.timestamp_granularities,
+        }
+    }
+
+    /// Sets the language of the input audio in ISO-639-1 format, e.g., `en`.
+    ///
+    /// Supplying the input language will improve accuracy and latency.
+    pub fn language<S: AsRef<str>>(mut self, language: S) -> Self {
+        self.language = Some(language.as_ref().to_string());
+        self
+    }
+
+    /// Sets an optional text to guide the model's style or continue a previous audio segment.
+    ///
+    /// The prompt should match the audio language.
+    pub fn prompt<S: AsRef<str>>(mut self, prompt: S) -> Self {
+        self.prompt = Some(prompt.as_ref().to_string());
+        self
+    }
+
+    /// Sets the format of the transcript output.
+    pub fn response_format(mut self, response_format: AudioResponseFormat) -> Self {
+        self.response_format = Some(response_format);
+        self
+    }
+
+    /// Sets the sampling temperature.
+    ///
+    /// The input value will be clamped in between 0 and 1.
+    pub fn temperature(mut self, temperature: f32) -> Self {
+        // Clamp the value to the valid range
+        let temperature = if !(MIN_TEMPERATURE..=MAX_TEMPERATURE).contains(&temperature) {
+            // Clamp the value
+            let t = temperature.clamp(MIN_TEMPERATURE, MAX_TEMPERATURE);
+
+            // Warn the user
+            warn!(
+                "input value of temperature is {temperature} which is out of range, it is now clamped to {t}"
+            );
+
+            t
+        } else {
+            temperature
+        };
+
+        self.temperature = Some(temperature);
+        self
+    }
+
+    /// Sets the timestamp granularities to populate for this transcription.
+    ///
+    /// The response format must be `VerboseJson` to use timestamp granularities,
+    /// which will be set when building the request body.
+    pub fn timestamp_granularities(
+        mut self,
+        timestamp_granularities: Vec<TimestampGranularity>
+    ) -> Self {
+        self.timestamp_granularities = Some(timestamp_granularities);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn revise_response_format() {
+        let request_body = TranscriptionRequestBody::builder(
+            UploadFile::from_bytes("speech.mp3", vec![]),
+            "whisper-1"
+        )
+            .response_format(AudioResponseFormat::Srt)
+            .timestamp_granularities(vec![TimestampGranularity::Word])
+            .build();
+        assert_eq!(request_body.response_format(), AudioResponseFormat::VerboseJson);
+
+        let request_body = TranscriptionRequestBody::builder(
+            UploadFile::from_bytes("speech.mp3", vec![]),
+            "whisper-1"
+        ).build();
+        assert_eq!(request_body.response_format(), AudioResponseFormat::Json);
+    }
+}
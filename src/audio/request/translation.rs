@@ -0,0 +1,124 @@
+use reqwest::multipart::Form;
+use log::warn;
+use crate::{ Result, UploadFile, utils::to_form_text };
+use super::{ AudioResponseFormat, validation::validate_audio_file };
+
+const MIN_TEMPERATURE: f32 = 0.0;
+const MAX_TEMPERATURE: f32 = 1.0;
+
+/// Request body of translating audio into English.
+///
+/// It is sent as a multipart form rather than JSON.
+pub struct TranslationRequestBody {
+    file: UploadFile,
+    model: String,
+    prompt: Option<String>,
+    response_format: Option<AudioResponseFormat>,
+    temperature: Option<f32>,
+}
+
+impl TranslationRequestBody {
+    pub fn builder<S: AsRef<str>>(file: UploadFile, model: S) -> TranslationRequestBodyBuilder {
+        TranslationRequestBodyBuilder::new(file, model)
+    }
+
+    /// Gets the response format, which defaults to JSON.
+    pub(crate) fn response_format(&self) -> AudioResponseFormat {
+        self.response_format.unwrap_or_default()
+    }
+
+    /// Validates the audio file, and converts to a multipart form.
+    pub(crate) async fn into_form(self) -> Result<Form> {
+        // Validate the audio file
+        let file = self.file.prepare(0).await?;
+        validate_audio_file(&file)?;
+
+        let mut form = Form::new().part("file", file.into_part()).text("model", self.model);
+
+        // Add the optional fields
+        if let Some(prompt) = self.prompt {
+            form = form.text("prompt", prompt);
+        }
+        if let Some(response_format) = self.response_format {
+            form = form.text("response_format", to_form_text(&response_format));
+        }
+        if let Some(temperature) = self.temperature {
+            form = form.text("temperature", temperature.to_string());
+        }
+
+        Ok(form)
+    }
+}
+
+pub struct TranslationRequestBodyBuilder {
+    file: UploadFile,
+    model: String,
+    prompt: Option<String>,
+    response_format: Option<AudioResponseFormat>,
+    temperature: Option<f32>,
+}
+
+impl TranslationRequestBodyBuilder {
+    /// Creates a new builder with `None` values for all optional fields.
+    ///
+    /// The audio file must be in one of these formats:
+    /// flac, mp3, mp4, mpeg, mpga, m4a, ogg, wav, or webm,
+    /// and less than 25 MB.
+    pub fn new<S: AsRef<str>>(file: UploadFile, model: S) -> Self {
+        Self {
+            file,
+            model: model.as_ref().to_string(),
+            prompt: None,
+            response_format: None,
+            temperature: None,
+        }
+    }
+
+    /// Builds the request body.
+    pub fn build(self) -> TranslationRequestBody {
+        TranslationRequestBody {
+            file: self.file,
+            model: self.model,
+            prompt: self.prompt,
+            response_format: self.response_format,
+            temperature: self.temperature,
+        }
+    }
+
+    /// Sets an optional text to guide the model's style or continue a previous audio segment.
+    ///
+    /// The prompt should be in English.
+    pub fn prompt<S: AsRef<str>>(mut self, prompt: S) -> Self {
+        self.prompt = Some(prompt.as_ref().to_string());
+        self
+    }
+
+    /// Sets the format of the translation output.
+    pub fn response_format(mut self, response_format: AudioResponseFormat) -> Self {
+        self.response_format = Some(response_format);
+        self
+    }
+
+    /// Sets the sampling temperature.
+    ///
+    /// The input value will be clamped in between 0 and 1.
+    pub fn temperature(mut self, temperature: f32) -> Self {
+        // Clamp the value to the valid range
+        let temperature = if !(MIN_TEMPERATURE..=MAX_TEMPERATURE).contains(&temperature) {
+            // Clamp the value
+            let t = temperature.clamp(MIN_TEMPERATURE, MAX_TEMPERATURE);
+
+            // Warn the user
+            warn!(
+                "input value of temperature is {temperature} which is out of range, it is now clamped to {t}"
+            );
+
+            t
+        } else {
+            temperature
+        };
+
+        self.temperature = Some(temperature);
+        self
+    }
+}
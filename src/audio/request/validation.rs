@@ -0,0 +1,74 @@
+use crate::{ Result, Error, AudioApiError, upload::PreparedUpload };
+
+/// Maximum size of an audio file to upload, which is 25 MB.
+const MAX_AUDIO_FILE_SIZE: u64 = 25 * 1024 * 1024;
+
+/// Extensions of the supported audio file formats.
+const SUPPORTED_AUDIO_FILE_EXTENSIONS: [&str; 9] = [
+    "flac",
+    "mp3",
+    "mp4",
+    "mpeg",
+    "mpga",
+    "m4a",
+    "ogg",
+    "wav",
+    "webm",
+];
+
+/// Validates that the audio file has a supported format and is less than 25 MB.
+/// The format is inferred from the file name in the same way as the API does.
+pub(crate) fn validate_audio_file(upload: &PreparedUpload) -> Result<()> {
+    // Check the file extension
+    let extension = upload.file_name
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_lowercase())
+        .unwrap_or_default();
+    if !SUPPORTED_AUDIO_FILE_EXTENSIONS.contains(&extension.as_str()) {
+        return Err(Error::AudioApi(AudioApiError::UnsupportedFileFormat(upload.file_name.clone())));
+    }
+
+    // Check the file size
+    if upload.length > MAX_AUDIO_FILE_SIZE {
+        return Err(
+            Error::AudioApi(AudioApiError::FileTooLarge {
+                file_name: upload.file_name.clone(),
+                size: upload.length,
+                max_size: MAX_AUDIO_FILE_SIZE,
+            })
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UploadFile;
+    use super::*;
+
+    #[tokio::test]
+    async fn validate_audio() -> Result<()> {
+        let upload = UploadFile::from_bytes("speech.MP3", vec![0; 16]).prepare(0).await?;
+        assert!(validate_audio_file(&upload).is_ok());
+
+        let upload = UploadFile::from_bytes("speech.txt", vec![0; 16]).prepare(0).await?;
+        assert!(
+            matches!(
+                validate_audio_file(&upload),
+                Err(Error::AudioApi(AudioApiError::UnsupportedFileFormat(_)))
+            )
+        );
+
+        let upload = UploadFile::from_bytes("speech.wav", vec![0; 26 * 1024 * 1024])
+            .prepare(0).await?;
+        assert!(
+            matches!(
+                validate_audio_file(&upload),
+                Err(Error::AudioApi(AudioApiError::FileTooLarge { .. }))
+            )
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+mod transcription;
+pub use transcription::{
+    Transcription,
+    VerboseTranscription,
+    TranscriptionSegment,
+    TranscriptionWord,
+    TranscriptionResponse,
+};
+
+mod subtitles;
+pub use subtitles::{ Subtitles, SubtitleCue };
+
+mod speech_stream;
+pub use speech_stream::SpeechStream;
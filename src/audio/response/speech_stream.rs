@@ -0,0 +1,93 @@
+use std::{ path::Path, pin::Pin, task::{ Context, Poll } };
+use bytes::Bytes;
+use futures::{ Stream, StreamExt };
+use tokio::io::{ AsyncWrite, AsyncWriteExt };
+use crate::{ Result, Error, AudioApiError };
+
+/// A stream of the generated audio bytes.
+///
+/// The audio can be played or written out while it is still being generated,
+/// without buffering the whole clip in memory.
+pub struct SpeechStream {
+    stream: Pin<Box<dyn Stream<Item = reqwest::Result<Bytes>> + Send>>,
+}
+
+impl SpeechStream {
+    pub fn new<S: 'static + Stream<Item = reqwest::Result<Bytes>> + Send>(stream: S) -> Self {
+        Self { stream: Box::pin(stream) }
+    }
+
+    /// Writes all the audio bytes to the writer as they arrive,
+    /// and returns the number of bytes written.
+    pub async fn write_to<W: AsyncWrite + Unpin>(mut self, writer: &mut W) -> Result<u64> {
+        let mut num_bytes = 0;
+
+        while let Some(bytes) = self.next().await {
+            let bytes = bytes?;
+
+            // Write the chunk
+            if let Err(error) = writer.write_all(&bytes).await {
+                return Err(Error::AudioApi(AudioApiError::WriteSpeech { source: error }));
+            }
+
+            num_bytes += bytes.len() as u64;
+        }
+
+        // Flush the writer
+        if let Err(error) = writer.flush().await {
+            return Err(Error::AudioApi(AudioApiError::WriteSpeech { source: error }));
+        }
+
+        Ok(num_bytes)
+    }
+
+    /// Saves the audio to the given file path,
+    /// and returns the number of bytes written.
+    pub async fn save<P: AsRef<Path>>(self, path: P) -> Result<u64> {
+        let mut file = match tokio::fs::File::create(path).await {
+            Ok(file) => file,
+            Err(error) => {
+                return Err(Error::AudioApi(AudioApiError::WriteSpeech { source: error }));
+            }
+        };
+
+        self.write_to(&mut file).await
+    }
+}
+
+impl Stream for SpeechStream {
+    type Item = Result<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.stream.poll_next_unpin(cx) {
+            Poll::Ready(Some(Ok(bytes))) => Poll::Ready(Some(Ok(bytes))),
+            Poll::Ready(Some(Err(error))) => {
+                Poll::Ready(
+                    Some(Err(Error::AudioApi(AudioApiError::ReceiveSpeechBytes { source: error })))
+                )
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn write_speech_stream() -> Result<()> {
+        let stream = SpeechStream::new(
+            futures::stream::iter(vec![Ok(Bytes::from("ID3")), Ok(Bytes::from("audio"))])
+        );
+
+        let mut buffer: Vec<u8> = vec![];
+        let num_bytes = stream.write_to(&mut buffer).await?;
+
+        assert_eq!(num_bytes, 8);
+        assert_eq!(buffer, b"ID3audio");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+use std::time::Duration;
+use crate::{ Result, Error, AudioApiError };
+
+/// Subtitles parsed from the SRT or WebVTT output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Subtitles {
+    pub cues: Vec<SubtitleCue>,
+}
+
+/// A piece of text displayed during a period of time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubtitleCue {
+    /// The sequence number in SRT, or the optional cue identifier in WebVTT.
+    pub identifier: Option<String>,
+
+    pub start: Duration,
+    pub end: Duration,
+    pub text: String,
+}
+
+impl Subtitles {
+    /// Parses SRT or WebVTT subtitles.
+    ///
+    /// Blocks without a timing line, e.g., the `WEBVTT` header and `NOTE` blocks, are skipped.
+    pub fn parse<S: AsRef<str>>(subtitles: S) -> Result<Self> {
+        let subtitles = subtitles.as_ref().replace("\r\n", "\n");
+
+        let mut cues = vec![];
+
+        for block in subtitles.split("\n\n") {
+            let lines: Vec<&str> = block
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .collect();
+
+            // Find the timing line
+            let timing_line_index = match lines.iter().position(|line| line.contains("-->")) {
+                Some(timing_line_index) => timing_line_index,
+                None => {
+                    continue;
+                }
+            };
+
+            // The line before the timing line is the identifier
+            let identifier = match timing_line_index {
+                0 => None,
+                _ => Some(lines[timing_line_index - 1].trim().to_string()),
+            };
+
+            // Parse the start and end times
+            // * In WebVTT, there may be cue settings after the end time
+            let timing_line = lines[timing_line_index];
+            let (start, end) = match timing_line.split_once("-->") {
+                Some((start, end)) => (start.trim(), end.split_whitespace().next().unwrap_or("")),
+                None => {
+                    continue;
+                }
+            };
+            let start = parse_timestamp(start)?;
+            let end = parse_timestamp(end)?;
+
+            // The remaining lines are the text
+            let text = lines[timing_line_index + 1..].join("\n");
+
+            cues.push(SubtitleCue { identifier, start, end, text });
+        }
+
+        Ok(Self { cues })
+    }
+
+    /// Gets the text of all cues joined by new lines.
+    pub fn text(&self) -> String {
+        self.cues
+            .iter()
+            .map(|cue| cue.text.as_str())
+            .collect::<Vec<&str>>()
+            .join("\n")
+    }
+}
+
+/// Parses a timestamp like `01:02:03,456` (SRT), `01:02:03.456` or `02:03.456` (WebVTT).
+fn parse_timestamp(timestamp: &str) -> Result<Duration> {
+    let invalid_timestamp = || {
+        Error::AudioApi(AudioApiError::ParseSubtitleTimestamp(timestamp.to_string()))
+    };
+
+    // Split the seconds and the milliseconds
+    let (hms, milliseconds) = timestamp
+        .rsplit_once([',', '.'])
+        .ok_or_else(invalid_timestamp)?;
+    let milliseconds: u64 = milliseconds.parse().map_err(|_| invalid_timestamp())?;
+
+    // The hours are optional
+    let parts = hms
+        .split(':')
+        .map(|part| part.parse::<u64>())
+        .collect::<std::result::Result<Vec<u64>, _>>()
+        .map_err(|_| invalid_timestamp())?;
+    let seconds = match parts.as_slice() {
+        [hours, minutes, seconds] => hours * 3600 + minutes * 60 + seconds,
+        [minutes, seconds] => minutes * 60 + seconds,
+        _ => {
+            return Err(invalid_timestamp());
+        }
+    };
+
+    Ok(Duration::from_secs(seconds) + Duration::from_millis(milliseconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_srt() {
+        let srt = "1\n00:00:00,000 --> 00:00:02,500\nHello there.\n\n2\n00:00:02,500 --> 00:01:04,000\nGeneral\nKenobi!\n";
+        let subtitles = Subtitles::parse(srt).unwrap();
+
+        assert_eq!(
+            subtitles.cues,
+            vec![
+                SubtitleCue {
+                    identifier: Some("1".to_string()),
+                    start: Duration::ZERO,
+                    end: Duration::from_millis(2500),
+                    text: "Hello there.".to_string(),
+                },
+                SubtitleCue {
+                    identifier: Some("2".to_string()),
+                    start: Duration::from_millis(2500),
+                    end: Duration::from_secs(64),
+                    text: "General\nKenobi!".to_string(),
+                }
+            ]
+        );
+        assert_eq!(subtitles.text(), "Hello there.\nGeneral\nKenobi!");
+    }
+
+    #[test]
+    fn parse_vtt() {
+        let vtt = "WEBVTT\n\nNOTE a comment\n\n00:00.000 --> 00:02.500 align:start\nHello there.\n\nintro\n01:00:02.500 --> 01:00:04.000\nGeneral Kenobi!\n";
+        let subtitles = Subtitles::parse(vtt).unwrap();
+
+        assert_eq!(
+            subtitles.cues,
+            vec![
+                SubtitleCue {
+                    identifier: None,
+                    start: Duration::ZERO,
+                    end: Duration::from_millis(2500),
+                    text: "Hello there.".to_string(),
+                },
+                SubtitleCue {
+                    identifier: Some("intro".to_string()),
+                    start: Duration::from_millis(3_602_500),
+                    end: Duration::from_secs(3604),
+                    text: "General Kenobi!".to_string(),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_invalid_timestamp() {
+        let result = Subtitles::parse("1\n00:00:xx,000 --> 00:00:02,500\nHello there.");
+        assert!(matches!(result, Err(Error::AudioApi(AudioApiError::ParseSubtitleTimestamp(_)))));
+    }
+}
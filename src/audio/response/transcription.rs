@@ -0,0 +1,192 @@
+use serde::Deserialize;
+use crate::{ Result, Error, AudioApiError };
+use super::{ super::AudioResponseFormat, Subtitles };
+
+/// The transcribed or translated text.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct Transcription {
+    pub text: String,
+}
+
+/// The transcribed or translated text with the detected language, duration,
+/// and optionally segments and words with timestamps.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct VerboseTranscription {
+    /// The language of the input audio.
+    pub language: String,
+
+    /// The duration of the input audio in seconds.
+    pub duration: f64,
+
+    pub text: String,
+
+    /// Segments of the text and their details.
+    #[serde(default)]
+    pub segments: Vec<TranscriptionSegment>,
+
+    /// Extracted words and their timestamps.
+    /// They are only present if the word timestamp granularity is requested.
+    #[serde(default)]
+    pub words: Vec<TranscriptionWord>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct TranscriptionSegment {
+    pub id: u32,
+
+    /// Seek offset of the segment.
+    pub seek: u32,
+
+    /// Start time of the segment in seconds.
+    pub start: f64,
+
+    /// End time of the segment in seconds.
+    pub end: f64,
+
+    pub text: String,
+
+    /// Token IDs of the text.
+    pub tokens: Vec<u32>,
+
+    pub temperature: f64,
+
+    /// Average log probability of the segment.
+    /// If the value is lower than -1, consider the log probabilities failed.
+    pub avg_logprob: f64,
+
+    /// Compression ratio of the segment.
+    /// If the value is greater than 2.4, consider the compression failed.
+    pub compression_ratio: f64,
+
+    /// Probability of no speech in the segment.
+    /// If the value is higher than 1.0 and the average log probability is below -1,
+    /// consider this segment silent.
+    pub no_speech_prob: f64,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct TranscriptionWord {
+    pub word: String,
+
+    /// Start time of the word in seconds.
+    pub start: f64,
+
+    /// End time of the word in seconds.
+    pub end: f64,
+}
+
+/// The output of the transcription or translation API,
+/// depending on the requested response format.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TranscriptionResponse {
+    /// Output in the `Json` or `Text` format.
+    Text(Transcription),
+
+    /// Output in the `VerboseJson` format.
+    Verbose(VerboseTranscription),
+
+    /// Output in the `Srt` or `Vtt` format.
+    Subtitles(Subtitles),
+}
+
+impl TranscriptionResponse {
+    /// Parses the raw response body in the given format.
+    pub(crate) fn parse(response_format: AudioResponseFormat, body: String) -> Result<Self> {
+        match response_format {
+            AudioResponseFormat::Json =>
+                match serde_json::from_str::<Transcription>(&body) {
+                    Ok(transcription) => Ok(Self::Text(transcription)),
+                    Err(error) => {
+                        Err(Error::AudioApi(AudioApiError::ParseToTranscription { source: error }))
+                    }
+                }
+            AudioResponseFormat::VerboseJson =>
+                match serde_json::from_str::<VerboseTranscription>(&body) {
+                    Ok(transcription) => Ok(Self::Verbose(transcription)),
+                    Err(error) => {
+                        Err(Error::AudioApi(AudioApiError::ParseToTranscription { source: error }))
+                    }
+                }
+            AudioResponseFormat::Text => {
+                // The plain text ends with a new line
+                Ok(Self::Text(Transcription { text: body.trim_end().to_string() }))
+            }
+            AudioResponseFormat::Srt | AudioResponseFormat::Vtt => {
+                Ok(Self::Subtitles(Subtitles::parse(body)?))
+            }
+        }
+    }
+
+    /// Gets the transcribed or translated text.
+    pub fn text(&self) -> String {
+        match self {
+            Self::Text(transcription) => transcription.text.clone(),
+            Self::Verbose(transcription) => transcription.text.clone(),
+            Self::Subtitles(subtitles) => subtitles.text(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_verbose_json() {
+        let json =
+            r#"{
+            "task": "transcribe",
+            "language": "english",
+            "duration": 8.47,
+            "text": "The beach was a popular spot.",
+            "segments": [
+                {
+                    "id": 0,
+                    "seek": 0,
+                    "start": 0.0,
+                    "end": 3.32,
+                    "text": " The beach was a popular spot.",
+                    "tokens": [50364, 440, 7534],
+                    "temperature": 0.0,
+                    "avg_logprob": -0.28,
+                    "compression_ratio": 1.23,
+                    "no_speech_prob": 0.009
+                }
+            ],
+            "words": [
+                { "word": "The", "start": 0.0, "end": 0.24 }
+            ]
+        }"#;
+
+        let response = TranscriptionResponse::parse(
+            AudioResponseFormat::VerboseJson,
+            json.to_string()
+        ).unwrap();
+
+        let transcription = match response {
+            TranscriptionResponse::Verbose(transcription) => transcription,
+            _ => panic!("expected a verbose transcription"),
+        };
+        assert_eq!(transcription.language, "english");
+        assert_eq!(transcription.segments[0].tokens, vec![50364, 440, 7534]);
+        assert_eq!(
+            transcription.words,
+            vec![TranscriptionWord { word: "The".to_string(), start: 0.0, end: 0.24 }]
+        );
+    }
+
+    #[test]
+    fn parse_text() {
+        let response = TranscriptionResponse::parse(
+            AudioResponseFormat::Text,
+            "Hello.\n".to_string()
+        ).unwrap();
+        assert_eq!(response.text(), "Hello.");
+
+        let response = TranscriptionResponse::parse(
+            AudioResponseFormat::Json,
+            r#"{"text":"Hello."}"#.to_string()
+        ).unwrap();
+        assert_eq!(response, TranscriptionResponse::Text(Transcription { text: "Hello.".to_string() }));
+    }
+}
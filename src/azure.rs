@@ -0,0 +1,186 @@
+/// Default version of the Azure OpenAI API.
+pub const DEFAULT_AZURE_API_VERSION: &str = "2024-10-21";
+
+/// Endpoints served by a model deployment on Azure
+/// rather than by the resource as a whole.
+const DEPLOYMENT_ENDPOINTS: [&str; 5] = [
+    "chat/completions",
+    "completions",
+    "embeddings",
+    "audio",
+    "images",
+];
+
+/// Configuration of an Azure OpenAI resource.
+///
+/// With it, requests are sent to the deployment-based URLs of the resource
+/// with the `api-version` query parameter,
+/// and the API key of the client is sent in the `api-key` header
+/// unless Entra ID authentication is chosen.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AzureConfig {
+    resource_endpoint: String,
+    deployment: String,
+    api_version: String,
+    auth: AzureAuth,
+}
+
+/// How the client authenticates with an Azure OpenAI resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AzureAuth {
+    /// The key is sent in the `api-key` header.
+    ApiKey,
+
+    /// The key is an Entra ID access token sent as a bearer token.
+    EntraId,
+}
+
+impl AzureConfig {
+    /// Creates a configuration with the resource endpoint,
+    /// e.g., `https://my-resource.openai.azure.com`, and the name of the model deployment.
+    ///
+    /// The API version defaults to `DEFAULT_AZURE_API_VERSION`.
+    pub fn new<S: AsRef<str>, T: AsRef<str>>(resource_endpoint: S, deployment: T) -> Self {
+        Self {
+            resource_endpoint: resource_endpoint.as_ref().trim_end_matches('/').to_string(),
+            deployment: deployment.as_ref().to_string(),
+            api_version: DEFAULT_AZURE_API_VERSION.to_string(),
+            auth: AzureAuth::ApiKey,
+        }
+    }
+
+    /// Sets the `api-version` query parameter.
+    pub fn api_version<S: AsRef<str>>(mut self, api_version: S) -> Self {
+        self.api_version = api_version.as_ref().to_string();
+        self
+    }
+
+    /// Sends the key of the client as an Entra ID bearer token
+    /// instead of in the `api-key` header.
+    pub fn entra_id(mut self) -> Self {
+        self.auth = AzureAuth::EntraId;
+        self
+    }
+
+    /// Gets the authentication scheme.
+    pub fn auth(&self) -> AzureAuth {
+        self.auth
+    }
+
+    /// Gets the full URL of an API endpoint on the resource.
+    pub(crate) fn endpoint(&self, path: &str) -> String {
+        let path = path.trim_start_matches('/');
+
+        // Requests to the model are routed through the deployment
+        let is_deployment_endpoint = DEPLOYMENT_ENDPOINTS.iter().any(|endpoint| {
+            path == *endpoint ||
+                path.starts_with(&format!("{}/", endpoint)) ||
+                path.starts_with(&format!("{}?", endpoint))
+        });
+        let url = if is_deployment_endpoint {
+            format!("{}/openai/deployments/{}/{}", self.resource_endpoint, self.deployment, path)
+        } else {
+            format!("{}/openai/{}", self.resource_endpoint, path)
+        };
+
+        // Append the API version to the existing query if any
+        let separator = if url.contains('?') { '&' } else { '?' };
+        format!("{}{}api-version={}", url, separator, self.api_version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use mockito::Matcher;
+    use crate::{ OpenAIClient, Result, chat::*, user_message };
+    use crate::chat::fixtures::{ chat_completion_body, chat_completion_chunk_body };
+    use super::*;
+
+    #[test]
+    fn test_azure_endpoint() {
+        let config = AzureConfig::new("https://my-resource.openai.azure.com/", "gpt-4o");
+
+        assert_eq!(
+            config.endpoint("chat/completions"),
+            "https://my-resource.openai.azure.com/openai/deployments/gpt-4o/chat/completions?api-version=2024-10-21"
+        );
+        assert_eq!(
+            config.endpoint("audio/transcriptions"),
+            "https://my-resource.openai.azure.com/openai/deployments/gpt-4o/audio/transcriptions?api-version=2024-10-21"
+        );
+        assert_eq!(
+            config.api_version("2024-05-01-preview").endpoint("files?purpose=batch"),
+            "https://my-resource.openai.azure.com/openai/files?purpose=batch&api-version=2024-05-01-preview"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_azure_chat_completion() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/openai/deployments/my-gpt-4o/chat/completions")
+            .match_query(Matcher::UrlEncoded("api-version".into(), "2024-10-21".into()))
+            .match_header("api-key", "azure-key")
+            .match_header("authorization", Matcher::Missing)
+            .with_header("content-type", "application/json")
+            .with_body(chat_completion_body())
+            .create_async().await;
+
+        // Create a client connected to the mock resource
+        let client = OpenAIClient::builder()
+            .api_key("azure-key")
+            .azure(AzureConfig::new(server.url(), "my-gpt-4o"))
+            .build()?;
+
+        let request_body = ChatRequestBody::builder("gpt-4o", vec![user_message!("Hi")]).build();
+        let chat_completion = create_chat_completion(&client, &request_body).await?;
+
+        mock.assert_async().await;
+        assert_eq!(chat_completion.choices[0].message.content.as_deref(), Some("Hello!"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_azure_chat_completion_stream_with_entra_id() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/openai/deployments/my-gpt-4o/chat/completions")
+            .match_query(Matcher::UrlEncoded("api-version".into(), "2024-10-21".into()))
+            .match_header("authorization", "Bearer entra-token")
+            .match_header("api-key", Matcher::Missing)
+            .with_header("content-type", "text/event-stream")
+            .with_body(
+                format!(
+                    "data: {}\n\ndata: {}\n\ndata: [DONE]\n\n",
+                    chat_completion_chunk_body("Hel"),
+                    chat_completion_chunk_body("lo")
+                )
+            )
+            .create_async().await;
+
+        // Create a client authenticated with an Entra ID token
+        let client = OpenAIClient::builder()
+            .api_key("entra-token")
+            .azure(AzureConfig::new(server.url(), "my-gpt-4o").entra_id())
+            .build()?;
+
+        let request_body = ChatRequestBody::builder("gpt-4o", vec![user_message!("Hi")]).build();
+        let mut stream = create_chat_completion_stream(&client, &request_body, false).await?;
+
+        let mut content = String::new();
+        while let Some(chunk) = stream.next().await {
+            if let Some(delta) = chunk?.choices[0].delta.content.as_ref() {
+                content.push_str(delta);
+            }
+        }
+
+        mock.assert_async().await;
+        assert_eq!(content, "Hello");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{ Result, Error, BatchesApiError, OpenAIClient, utils::encode_path_segment };
+use super::super::{ BATCHES_API_PATH, Batch };
+
+/// Cancels an in-progress batch.
+///
+/// The batch will be in the `Cancelling` status for up to 10 minutes
+/// before it is `Cancelled`, with partial results available in the output file.
+pub async fn cancel_batch<S: AsRef<str>>(client: &OpenAIClient, batch_id: S) -> Result<Batch> {
+    // Send the request
+    let response = match
+        client.send(|client| {
+            client.post(
+                client.endpoint(
+                    format!(
+                        "{}/{}/cancel",
+                        BATCHES_API_PATH,
+                        encode_path_segment(batch_id.as_ref())
+                    )
+                )
+            )
+        }).await
+    {
+        Ok(response) => response,
+        Err(Error::UnknownStatusCode { status_code: reqwest::StatusCode::NOT_FOUND, .. }) => {
+            return Err(
+                Error::BatchesApi(
+                    BatchesApiError::BatchNotFound(batch_id.as_ref().to_string())
+                )
+            );
+        }
+        Err(error) => {
+            return Err(error);
+        }
+    };
+
+    // Parse the response
+    match response.json::<Batch>().await {
+        Ok(batch) => Ok(batch),
+        Err(error) => Err(Error::BatchesApi(BatchesApiError::ParseToBatch { source: error })),
+    }
+}
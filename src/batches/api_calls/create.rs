@@ -0,0 +1,76 @@
+use crate::{ Result, Error, BatchesApiError, OpenAIClient };
+use super::super::{ BATCHES_API_PATH, BatchRequestBody, Batch };
+
+/// Creates and executes a batch from an uploaded file of requests.
+pub async fn create_batch(client: &OpenAIClient, request_body: &BatchRequestBody) -> Result<Batch> {
+    // Send the request
+    let response = client.send(|client| {
+        client.post(client.endpoint(BATCHES_API_PATH)).json(request_body)
+    }).await?;
+
+    // Parse the response
+    match response.json::<Batch>().await {
+        Ok(batch) => Ok(batch),
+        Err(error) => Err(Error::BatchesApi(BatchesApiError::ParseToBatch { source: error })),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use serde_json::json;
+    use crate::batches::*;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_create_batch() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/batches")
+            .match_header("authorization", "Bearer sk-test")
+            .match_body(
+                Matcher::Json(
+                    json!({
+                        "input_file_id": "file-abc123",
+                        "endpoint": "/v1/chat/completions",
+                        "completion_window": "24h",
+                        "metadata": { "run": "nightly" }
+                    })
+                )
+            )
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "id": "batch_abc123",
+                    "object": "batch",
+                    "endpoint": "/v1/chat/completions",
+                    "errors": null,
+                    "input_file_id": "file-abc123",
+                    "completion_window": "24h",
+                    "status": "validating",
+                    "output_file_id": null,
+                    "error_file_id": null,
+                    "created_at": 1711471533,
+                    "request_counts": { "total": 0, "completed": 0, "failed": 0 },
+                    "metadata": { "run": "nightly" }
+                }).to_string()
+            )
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // Create the batch
+        let request_body = BatchRequestBody::builder("file-abc123", BatchEndpoint::ChatCompletions)
+            .metadata("run", "nightly")
+            .build();
+        let batch = create_batch(&client, &request_body).await?;
+
+        mock.assert_async().await;
+        assert_eq!(batch.status, BatchStatus::Validating);
+        assert!(!batch.status.is_terminal());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{ Result, Error, BatchesApiError, OpenAIClient, ListResponse };
+use super::super::{ BATCHES_API_PATH, ListBatchesQuery, Batch };
+
+/// Lists a page of batches, from the most recent one.
+pub async fn list_batches(
+    client: &OpenAIClient,
+    query: &ListBatchesQuery
+) -> Result<ListResponse<Batch>> {
+    // Send the request
+    let response = client.send(|client| {
+        client.get(client.endpoint(BATCHES_API_PATH)).query(query)
+    }).await?;
+
+    // Parse the response
+    match response.json::<ListResponse<Batch>>().await {
+        Ok(response) => Ok(response),
+        Err(error) => Err(Error::BatchesApi(BatchesApiError::ParseToBatchList { source: error })),
+    }
+}
@@ -0,0 +1,14 @@
+mod create;
+pub use create::create_batch;
+
+mod retrieve;
+pub use retrieve::{ retrieve_batch, poll_batch };
+
+mod cancel;
+pub use cancel::cancel_batch;
+
+mod list;
+pub use list::list_batches;
+
+mod results;
+pub use results::download_batch_results;
@@ -0,0 +1,25 @@
+use crate::{ Result, OpenAIClient, files::retrieve_file_content };
+use super::super::{ Batch, BatchResults };
+
+/// Downloads the output file and the error file of a batch,
+/// and parses them to chat completions and failures keyed by custom ID.
+///
+/// Files that are not yet available are skipped.
+pub async fn download_batch_results(client: &OpenAIClient, batch: &Batch) -> Result<BatchResults> {
+    // Download the output file
+    let output = match &batch.output_file_id {
+        Some(file_id) => Some(retrieve_file_content(client, file_id).await?),
+        None => None,
+    };
+
+    // Download the error file
+    let errors = match &batch.error_file_id {
+        Some(file_id) => Some(retrieve_file_content(client, file_id).await?),
+        None => None,
+    };
+
+    BatchResults::parse(
+        output.as_ref().map(|output| String::from_utf8_lossy(output)).as_deref(),
+        errors.as_ref().map(|errors| String::from_utf8_lossy(errors)).as_deref()
+    )
+}
@@ -0,0 +1,104 @@
+use std::time::Duration;
+use crate::{ Result, Error, BatchesApiError, OpenAIClient, utils::encode_path_segment };
+use super::super::{ BATCHES_API_PATH, Batch };
+
+/// Retrieves a batch.
+pub async fn retrieve_batch<S: AsRef<str>>(client: &OpenAIClient, batch_id: S) -> Result<Batch> {
+    // Send the request
+    let response = match
+        client.send(|client| {
+            client
+                .get(
+                    client.endpoint(
+                        format!("{}/{}", BATCHES_API_PATH, encode_path_segment(batch_id.as_ref()))
+                    )
+                )
+        }).await
+    {
+        Ok(response) => response,
+        Err(Error::UnknownStatusCode { status_code: reqwest::StatusCode::NOT_FOUND, .. }) => {
+            return Err(
+                Error::BatchesApi(
+                    BatchesApiError::BatchNotFound(batch_id.as_ref().to_string())
+                )
+            );
+        }
+        Err(error) => {
+            return Err(error);
+        }
+    };
+
+    // Parse the response
+    match response.json::<Batch>().await {
+        Ok(batch) => Ok(batch),
+        Err(error) => Err(Error::BatchesApi(BatchesApiError::ParseToBatch { source: error })),
+    }
+}
+
+/// Retrieves a batch repeatedly at the given interval until it is
+/// completed, failed, expired or cancelled.
+pub async fn poll_batch<S: AsRef<str>>(
+    client: &OpenAIClient,
+    batch_id: S,
+    interval: Duration
+) -> Result<Batch> {
+    loop {
+        let batch = retrieve_batch(client, batch_id.as_ref()).await?;
+
+        if batch.status.is_terminal() {
+            return Ok(batch);
+        }
+
+        tokio::time::sleep(interval).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use crate::batches::*;
+    use super::*;
+
+    fn batch_json(status: &str) -> String {
+        json!({
+            "id": "batch_abc123",
+            "object": "batch",
+            "endpoint": "/v1/chat/completions",
+            "input_file_id": "file-abc123",
+            "completion_window": "24h",
+            "status": status,
+            "output_file_id": if status == "completed" { Some("file-cvaTdG") } else { None },
+            "created_at": 1711471533
+        }).to_string()
+    }
+
+    #[tokio::test]
+    async fn test_poll_batch() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let in_progress = server
+            .mock("GET", "/batches/batch_abc123")
+            .with_header("content-type", "application/json")
+            .with_body(batch_json("in_progress"))
+            .expect(2)
+            .create_async().await;
+        let completed = server
+            .mock("GET", "/batches/batch_abc123")
+            .with_header("content-type", "application/json")
+            .with_body(batch_json("completed"))
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // Poll until the batch is completed
+        let batch = poll_batch(&client, "batch_abc123", Duration::from_millis(1)).await?;
+
+        in_progress.assert_async().await;
+        completed.assert_async().await;
+        assert_eq!(batch.status, BatchStatus::Completed);
+        assert_eq!(batch.output_file_id.as_deref(), Some("file-cvaTdG"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,2 @@
+/// Path of the batches API under the base URL of the client.
+pub const BATCHES_API_PATH: &str = "batches";
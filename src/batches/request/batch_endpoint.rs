@@ -0,0 +1,17 @@
+use serde::{ Serialize, Deserialize };
+
+/// The endpoint used for all the requests in a batch.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum BatchEndpoint {
+    #[serde(rename = "/v1/chat/completions")]
+    ChatCompletions,
+
+    #[serde(rename = "/v1/embeddings")]
+    Embeddings,
+
+    #[serde(rename = "/v1/completions")]
+    Completions,
+
+    #[serde(rename = "/v1/responses")]
+    Responses,
+}
@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+use serde::Serialize;
+use super::BatchEndpoint;
+
+/// Request body of creating a batch.
+#[derive(Debug, Serialize)]
+pub struct BatchRequestBody {
+    input_file_id: String,
+    endpoint: BatchEndpoint,
+    completion_window: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<HashMap<String, String>>,
+}
+
+impl BatchRequestBody {
+    pub fn builder<S: AsRef<str>>(
+        input_file_id: S,
+        endpoint: BatchEndpoint
+    ) -> BatchRequestBodyBuilder {
+        BatchRequestBodyBuilder::new(input_file_id, endpoint)
+    }
+}
+
+pub struct BatchRequestBodyBuilder {
+    input_file_id: String,
+    endpoint: BatchEndpoint,
+    metadata: Option<HashMap<String, String>>,
+}
+
+impl BatchRequestBodyBuilder {
+    /// Creates a new builder with `None` values for all optional fields.
+    ///
+    /// The input file must be uploaded with the `Batch` purpose.
+    pub fn new<S: AsRef<str>>(input_file_id: S, endpoint: BatchEndpoint) -> Self {
+        Self {
+            input_file_id: input_file_id.as_ref().to_string(),
+            endpoint,
+            metadata: None,
+        }
+    }
+
+    /// Builds the request body.
+    ///
+    /// The completion window is always 24 hours, which is the only one supported.
+    pub fn build(self) -> BatchRequestBody {
+        BatchRequestBody {
+            input_file_id: self.input_file_id,
+            endpoint: self.endpoint,
+            completion_window: "24h".to_string(),
+            metadata: self.metadata,
+        }
+    }
+
+    /// Adds a key-value pair to the metadata of the batch.
+    pub fn metadata<K: AsRef<str>, V: AsRef<str>>(mut self, key: K, value: V) -> Self {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+}
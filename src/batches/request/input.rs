@@ -0,0 +1,121 @@
+use serde::Serialize;
+use crate::{ Result, Error, BatchesApiError, UploadFile, chat::ChatRequestBody };
+use super::BatchEndpoint;
+
+/// Chat completion requests to run in a batch, each identified by a unique custom ID.
+///
+/// It is serialized to the JSONL input file of the batches API,
+/// which should be uploaded with the `Batch` purpose.
+#[derive(Debug, Default)]
+pub struct BatchInput {
+    requests: Vec<(String, ChatRequestBody)>,
+}
+
+/// A line of the batch input file.
+#[derive(Serialize)]
+struct BatchInputLine<'a> {
+    custom_id: &'a str,
+    method: &'static str,
+    url: BatchEndpoint,
+    body: &'a ChatRequestBody,
+}
+
+impl BatchInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a request with a custom ID, which is used to match it with its output.
+    pub fn add<S: AsRef<str>>(mut self, custom_id: S, request_body: ChatRequestBody) -> Self {
+        self.requests.push((custom_id.as_ref().to_string(), request_body));
+        self
+    }
+
+    /// Gets the number of requests.
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// Serializes the requests to JSONL, one request per line.
+    pub fn to_jsonl(&self) -> Result<String> {
+        let mut jsonl = String::new();
+
+        for (custom_id, request_body) in self.requests.iter() {
+            let line = BatchInputLine {
+                custom_id,
+                method: "POST",
+                url: BatchEndpoint::ChatCompletions,
+                body: request_body,
+            };
+
+            match serde_json::to_string(&line) {
+                Ok(line) => {
+                    jsonl.push_str(&line);
+                    jsonl.push('\n');
+                }
+                Err(error) => {
+                    return Err(
+                        Error::BatchesApi(BatchesApiError::SerializeBatchInput { source: error })
+                    );
+                }
+            }
+        }
+
+        Ok(jsonl)
+    }
+
+    /// Serializes the requests to a JSONL file to upload.
+    pub fn to_upload_file<S: AsRef<str>>(&self, file_name: S) -> Result<UploadFile> {
+        Ok(UploadFile::from_bytes(file_name, self.to_jsonl()?))
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<(S, ChatRequestBody)> for BatchInput {
+    fn from_iter<I: IntoIterator<Item = (S, ChatRequestBody)>>(iter: I) -> Self {
+        Self {
+            requests: iter
+                .into_iter()
+                .map(|(custom_id, request_body)| (custom_id.as_ref().to_string(), request_body))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::chat::{ ChatRequestMessage, UserMessage };
+    use super::*;
+
+    #[test]
+    fn serialize_batch_input() {
+        let input: BatchInput = ["request-1", "request-2"]
+            .into_iter()
+            .map(|custom_id| {
+                (
+                    custom_id,
+                    ChatRequestBody::builder(
+                        "gpt-4o-mini",
+                        vec![ChatRequestMessage::User(UserMessage::new("Hello world!"))]
+                    )
+                        .max_tokens(1000)
+                        .build(),
+                )
+            })
+            .collect();
+
+        assert_eq!(input.len(), 2);
+        assert_eq!(
+            input.to_jsonl().unwrap(),
+            concat!(
+                r#"{"custom_id":"request-1","method":"POST","url":"/v1/chat/completions","body":{"model":"gpt-4o-mini","messages":[{"role":"user","content":"Hello world!"}],"max_tokens":1000}}"#,
+                "\n",
+                r#"{"custom_id":"request-2","method":"POST","url":"/v1/chat/completions","body":{"model":"gpt-4o-mini","messages":[{"role":"user","content":"Hello world!"}],"max_tokens":1000}}"#,
+                "\n"
+            )
+        );
+    }
+}
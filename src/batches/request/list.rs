@@ -0,0 +1,69 @@
+use serde::Serialize;
+use log::warn;
+
+const MIN_LIMIT: u32 = 1;
+const MAX_LIMIT: u32 = 100;
+
+/// Query parameters of listing batches.
+#[derive(Debug, Serialize, Clone, Default)]
+pub struct ListBatchesQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<String>,
+}
+
+impl ListBatchesQuery {
+    pub fn builder() -> ListBatchesQueryBuilder {
+        ListBatchesQueryBuilder::new()
+    }
+}
+
+#[derive(Default)]
+pub struct ListBatchesQueryBuilder {
+    limit: Option<u32>,
+    after: Option<String>,
+}
+
+impl ListBatchesQueryBuilder {
+    /// Creates a new builder with `None` values for all fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds the query.
+    pub fn build(self) -> ListBatchesQuery {
+        ListBatchesQuery { limit: self.limit, after: self.after }
+    }
+
+    /// Sets the number of batches in a page.
+    ///
+    /// The input value will be clamped in between 1 and 100.
+    pub fn limit(mut self, limit: u32) -> Self {
+        // Clamp the value to the valid range
+        let limit = if !(MIN_LIMIT..=MAX_LIMIT).contains(&limit) {
+            // Clamp the value
+            let l = limit.clamp(MIN_LIMIT, MAX_LIMIT);
+
+            // Warn the user
+            warn!(
+                "input value of limit is {limit} which is out of range, it is now clamped to {l}"
+            );
+
+            l
+        } else {
+            limit
+        };
+
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the cursor for pagination, which is the ID of
+    /// the last batch in the previous page.
+    pub fn after<S: AsRef<str>>(mut self, after: S) -> Self {
+        self.after = Some(after.as_ref().to_string());
+        self
+    }
+}
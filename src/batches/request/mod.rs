@@ -0,0 +1,11 @@
+mod batch_endpoint;
+pub use batch_endpoint::BatchEndpoint;
+
+mod input;
+pub use input::BatchInput;
+
+mod batch_request_body;
+pub use batch_request_body::{ BatchRequestBody, BatchRequestBodyBuilder };
+
+mod list;
+pub use list::{ ListBatchesQuery, ListBatchesQueryBuilder };
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+use super::super::BatchEndpoint;
+
+/// A batch of requests, and its progress.
+///
+/// The timestamps are Unix timestamps in seconds.
+#[derive(Debug, Deserialize, Clone)]
+pub struct Batch {
+    pub id: String,
+    pub object: String,
+    pub endpoint: BatchEndpoint,
+
+    /// Errors found while validating the input file.
+    pub errors: Option<BatchErrors>,
+
+    pub input_file_id: String,
+    pub completion_window: String,
+    pub status: BatchStatus,
+
+    /// ID of the file containing the outputs of the successfully executed requests.
+    pub output_file_id: Option<String>,
+
+    /// ID of the file containing the outputs of the requests with errors.
+    pub error_file_id: Option<String>,
+
+    pub created_at: u64,
+    pub in_progress_at: Option<u64>,
+    pub expires_at: Option<u64>,
+    pub finalizing_at: Option<u64>,
+    pub completed_at: Option<u64>,
+    pub failed_at: Option<u64>,
+    pub expired_at: Option<u64>,
+    pub cancelling_at: Option<u64>,
+    pub cancelled_at: Option<u64>,
+
+    pub request_counts: Option<BatchRequestCounts>,
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchStatus {
+    Validating,
+    Failed,
+    InProgress,
+    Finalizing,
+    Completed,
+    Expired,
+    Cancelling,
+    Cancelled,
+}
+
+impl BatchStatus {
+    /// Whether the batch will no longer change.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Failed | Self::Completed | Self::Expired | Self::Cancelled)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+pub struct BatchRequestCounts {
+    pub total: u32,
+    pub completed: u32,
+    pub failed: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct BatchErrors {
+    pub object: String,
+    pub data: Vec<BatchError>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct BatchError {
+    pub code: Option<String>,
+    pub message: Option<String>,
+    pub param: Option<String>,
+
+    /// The line number of the input file where the error occurred.
+    pub line: Option<u32>,
+}
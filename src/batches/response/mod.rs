@@ -0,0 +1,5 @@
+mod batch;
+pub use batch::{ Batch, BatchStatus, BatchRequestCounts, BatchErrors, BatchError };
+
+mod results;
+pub use results::{ BatchResults, BatchRequestFailure };
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+use crate::{ Result, Error, BatchesApiError, chat::ChatCompletion };
+
+/// The outputs of a batch of chat completion requests, keyed by their custom IDs.
+#[derive(Debug, Default)]
+pub struct BatchResults {
+    pub completions: HashMap<String, ChatCompletion>,
+    pub failures: HashMap<String, BatchRequestFailure>,
+}
+
+/// Why a request in a batch failed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchRequestFailure {
+    /// The HTTP status code of the response, if the request was sent.
+    pub status_code: Option<u16>,
+
+    pub code: Option<String>,
+    pub message: String,
+}
+
+/// A line of the batch output or error file.
+#[derive(Deserialize)]
+struct BatchOutputLine {
+    custom_id: String,
+    response: Option<BatchOutputResponse>,
+    error: Option<BatchOutputError>,
+}
+
+#[derive(Deserialize)]
+struct BatchOutputResponse {
+    status_code: u16,
+    body: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct BatchOutputError {
+    code: Option<String>,
+    message: Option<String>,
+}
+
+impl BatchResults {
+    /// Parses the content of the output file and/or the error file of a batch.
+    ///
+    /// Lines with a successful response are parsed to chat completions,
+    /// and the others are collected as failures.
+    pub fn parse(output: Option<&str>, errors: Option<&str>) -> Result<Self> {
+        let mut results = Self::default();
+
+        for content in [output, errors].into_iter().flatten() {
+            for (index, line) in content.lines().enumerate() {
+                // Skip empty lines
+                if line.trim().is_empty() {
+                    continue;
+                }
+
+                let line = match serde_json::from_str::<BatchOutputLine>(line) {
+                    Ok(line) => line,
+                    Err(error) => {
+                        return Err(
+                            Error::BatchesApi(BatchesApiError::ParseBatchOutputLine {
+                                line: index + 1,
+                                source: error,
+                            })
+                        );
+                    }
+                };
+
+                results.add(line)?;
+            }
+        }
+
+        Ok(results)
+    }
+
+    fn add(&mut self, line: BatchOutputLine) -> Result<()> {
+        match (line.response, line.error) {
+            (Some(response), None) if (200..300).contains(&response.status_code) => {
+                match serde_json::from_value::<ChatCompletion>(response.body) {
+                    Ok(completion) => {
+                        self.completions.insert(line.custom_id, completion);
+                    }
+                    Err(error) => {
+                        return Err(
+                            Error::BatchesApi(BatchesApiError::ParseToChatCompletion {
+                                custom_id: line.custom_id,
+                                source: error,
+                            })
+                        );
+                    }
+                }
+            }
+            (response, error) => {
+                // Get the error message from the error or the response body
+                let (code, message) = match error {
+                    Some(error) => (error.code, error.message.unwrap_or_default()),
+                    None => {
+                        let error = response
+                            .as_ref()
+                            .map(|response| response.body["error"].clone())
+                            .unwrap_or_default();
+                        (
+                            error["code"].as_str().map(|code| code.to_string()),
+                            error["message"].as_str().unwrap_or_default().to_string(),
+                        )
+                    }
+                };
+
+                self.failures.insert(line.custom_id, BatchRequestFailure {
+                    status_code: response.map(|response| response.status_code),
+                    code,
+                    message,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_batch_results() {
+        let output =
+            r#"{"id": "batch_req_123", "custom_id": "request-2", "response": {"status_code": 200, "request_id": "req_123", "body": {"id": "chatcmpl-123", "object": "chat.completion", "created": 1711652795, "model": "gpt-4o-mini", "choices": [{"index": 0, "message": {"role": "assistant", "content": "Hello."}, "logprobs": null, "finish_reason": "stop"}], "usage": {"prompt_tokens": 22, "completion_tokens": 2, "total_tokens": 24}, "system_fingerprint": "fp_123"}}, "error": null}
+"#;
+        let errors =
+            r#"{"id": "batch_req_456", "custom_id": "request-1", "response": {"status_code": 400, "request_id": "req_456", "body": {"error": {"message": "Invalid model.", "type": "invalid_request_error", "code": "model_not_found"}}}, "error": null}
+{"id": "batch_req_789", "custom_id": "request-3", "response": null, "error": {"code": "batch_expired", "message": "This request could not be executed before the completion window expired."}}
+"#;
+
+        let results = BatchResults::parse(Some(output), Some(errors)).unwrap();
+
+        assert_eq!(
+            results.completions["request-2"].choices[0].message.content.as_deref(),
+            Some("Hello.")
+        );
+        assert_eq!(results.failures["request-1"], BatchRequestFailure {
+            status_code: Some(400),
+            code: Some("model_not_found".to_string()),
+            message: "Invalid model.".to_string(),
+        });
+        assert_eq!(results.failures["request-3"].status_code, None);
+        assert_eq!(results.failures["request-3"].code.as_deref(), Some("batch_expired"));
+
+        // Report the line number of an invalid line
+        let result = BatchResults::parse(Some("\n{}\n"), None);
+        assert!(
+            matches!(
+                result,
+                Err(Error::BatchesApi(BatchesApiError::ParseBatchOutputLine { line: 2, .. }))
+            )
+        );
+    }
+}
@@ -0,0 +1,25 @@
+use std::collections::HashMap;
+use serde_json::Value;
+use crate::{ Result, Error, ChatApiError };
+use super::super::ChatRequestBody;
+
+/// Converts the request body to a map so that its fields can be modified.
+pub(super) fn request_body_to_map(
+    request_body: &ChatRequestBody
+) -> Result<HashMap<String, Value>> {
+    // Convert the request body to JSON value
+    let request_body = match serde_json::to_value(request_body) {
+        Ok(request_body) => request_body,
+        Err(error) => {
+            return Err(Error::ChatApi(ChatApiError::ChatRequestBodyToJson { source: error }));
+        }
+    };
+
+    // Convert the JSON value to a map
+    match serde_json::from_value(request_body) {
+        Ok(request_body) => Ok(request_body),
+        Err(error) => {
+            Err(Error::ChatApi(ChatApiError::ChatRequestBodyJsonToMap { source: error }))
+        }
+    }
+}
@@ -1,47 +1,80 @@
-use crate::{ Result, Error, OpenAIClient, ChatApiError };
-use super::super::{ endpoint::CHAT_COMPLETION_API_ENDPOINT, ChatRequestBody, ChatCompletion };
+use crate::{
+    Result,
+    Error,
+    OpenAIClient,
+    ChatApiError,
+    rate_limit::estimate_chat_tokens,
+};
+use super::request_body_to_map;
+use super::super::{ endpoint::CHAT_COMPLETION_API_PATH, ChatRequestBody, ChatCompletion };
 
 pub async fn create_chat_completion(
     client: &OpenAIClient,
     request_body: &ChatRequestBody
 ) -> Result<ChatCompletion> {
-    // Send the request
-    let response = match client.post(CHAT_COMPLETION_API_ENDPOINT).json(request_body).send().await {
-        Ok(response) =>
-            match response.error_for_status() {
-                Ok(response) => response,
-                Err(error) => {
-                    return Err(Error::from(error));
-                }
-            }
-        Err(error) => {
-            return Err(Error::from(error));
-        }
+    // Strip or rename the fields unsupported by the provider
+    let mut request_body = request_body_to_map(request_body)?;
+    client.field_policy().apply(&mut request_body);
+
+    // Send the request after waiting for the capacity of the rate limiter
+    // The request is in flight until the body is received
+    let model = request_body
+        .get("model")
+        .and_then(|model| model.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let estimated_tokens = match client.rate_limiter() {
+        Some(_) => estimate_chat_tokens(&request_body),
+        None => 0,
     };
+    let (response, _in_flight, taken_tokens) = client.execute_rate_limited(
+        &model,
+        estimated_tokens,
+        |client| {
+            client.post(client.endpoint(CHAT_COMPLETION_API_PATH)).json(&request_body)
+        }
+    ).await?;
 
     // Parse the response
-    let response = match response.json::<ChatCompletion>().await {
-        Ok(response) => response,
-        Err(error) => {
-            return Err(Error::ChatApi(ChatApiError::ParseToChatCompletion { source: error }));
-        }
+    let headers = response.headers().clone();
+    let result = match response.json::<ChatCompletion>().await {
+        Ok(response) => Ok(response),
+        Err(error) => Err(Error::ChatApi(ChatApiError::ParseToChatCompletion { source: error })),
     };
 
-    Ok(response)
+    // Replace the taken tokens with the used ones, which are none if the response is not parsed,
+    // and then trust the remaining capacity reported by the API
+    if let Some(rate_limiter) = client.rate_limiter() {
+        match result.as_ref() {
+            Ok(response) => {
+                rate_limiter.reconcile(&model, taken_tokens, response.usage.total_tokens);
+                rate_limiter.update_from_headers(&model, &headers);
+            }
+            Err(_) => rate_limiter.reconcile(&model, taken_tokens, 0),
+        }
+    }
+
+    result
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+    use crate::prelude::*;
+    #[cfg(feature = "env")]
     use std::collections::HashMap;
+    #[cfg(feature = "env")]
     use futures::StreamExt;
-    use crate::prelude::*;
+    #[cfg(feature = "env")]
     use crate::utils::init_test_logger;
+    use crate::chat::fixtures::chat_completion_body;
     use super::*;
 
+    #[cfg(feature = "env")]
     #[tokio::test]
     async fn get_complete_chat_response() -> Result<()> {
         // Create a client
-        let client = OpenAIClient::new()?;
+        let client = OpenAIClient::from_env()?;
 
         // Build the request body
         let request_body = ChatRequestBody::builder(
@@ -61,10 +94,11 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "env")]
     #[tokio::test]
     async fn get_streamed_chat_response() -> Result<()> {
         // Create a client
-        let client = OpenAIClient::new()?;
+        let client = OpenAIClient::from_env()?;
 
         // Build the request body
         let request_body = ChatRequestBody::builder(
@@ -85,7 +119,7 @@ mod tests {
 
         // Send the request
         let response = client
-            .post(CHAT_COMPLETION_API_ENDPOINT)
+            .post(client.endpoint(CHAT_COMPLETION_API_PATH))
             // .json(&request_body)
             .json(&request_body)
             .send().await
@@ -106,13 +140,14 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "env")]
     #[tokio::test]
     async fn test_get_complete_chat_response() {
         // Initialize a logger
         init_test_logger();
 
         // Create a client
-        let client = OpenAIClient::new().unwrap();
+        let client = OpenAIClient::from_env().unwrap();
 
         // Build the request body
         let request_body = ChatRequestBody::builder(
@@ -142,4 +177,41 @@ mod tests {
 
         println!("{:#?}", response);
     }
+
+    #[tokio::test]
+    async fn test_create_chat_completion_with_meta() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/chat/completions")
+            .with_header("content-type", "application/json")
+            .with_header("x-request-id", "req_123")
+            .with_header("openai-processing-ms", "321")
+            .with_header("x-ratelimit-limit-requests", "500")
+            .with_header("x-ratelimit-remaining-requests", "499")
+            .with_header("x-ratelimit-remaining-tokens", "29990")
+            .with_header("x-ratelimit-reset-tokens", "20ms")
+            .with_body(chat_completion_body())
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        let request_body = ChatRequestBody::builder("gpt-4o", vec![user_message!("Hi")]).build();
+        let response = client.with_meta(|client| async move {
+            create_chat_completion(&client, &request_body).await
+        }).await?;
+
+        mock.assert_async().await;
+        assert_eq!(response.data.usage.total_tokens, 11);
+        assert_eq!(response.meta.request_id.as_deref(), Some("req_123"));
+        assert_eq!(response.meta.processing_time, Some(Duration::from_millis(321)));
+        assert_eq!(response.meta.limit_requests, Some(500));
+        assert_eq!(response.meta.remaining_requests, Some(499));
+        assert_eq!(response.meta.remaining_tokens, Some(29990));
+        assert_eq!(response.meta.reset_tokens, Some(Duration::from_millis(20)));
+        assert_eq!(response.meta.limit_tokens, None);
+
+        Ok(())
+    }
 }
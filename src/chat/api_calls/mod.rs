@@ -1,3 +1,6 @@
+mod body;
+use body::request_body_to_map;
+
 mod complete;
 pub use complete::create_chat_completion;
 
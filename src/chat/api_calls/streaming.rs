@@ -1,7 +1,12 @@
-use std::collections::HashMap;
+use std::sync::Arc;
 use serde_json::json;
-use crate::{ Result, Error, OpenAIClient, ChatApiError };
-use super::super::{ endpoint::CHAT_COMPLETION_API_ENDPOINT, ChatRequestBody, ChatCompletionStream };
+use crate::{
+    Result,
+    OpenAIClient,
+    rate_limit::{ estimate_chat_tokens, PendingUsage },
+};
+use super::request_body_to_map;
+use super::super::{ endpoint::CHAT_COMPLETION_API_PATH, ChatRequestBody, ChatCompletionStream };
 
 pub async fn create_chat_completion_stream(
     client: &OpenAIClient,
@@ -10,24 +15,7 @@ pub async fn create_chat_completion_stream(
 ) -> Result<ChatCompletionStream> {
     // We will first modify the request body so that
     // the fields `stream` and `stream_options` are set
-
-    // Convert the request body to JSON value
-    let request_body = match serde_json::to_value(&request_body) {
-        Ok(request_body) => request_body,
-        Err(error) => {
-            return Err(Error::ChatApi(ChatApiError::ChatRequestBodyToJson { source: error }));
-        }
-    };
-
-    // Convert the request body to a map so that it can be modified
-    let mut request_body: HashMap<String, serde_json::Value> = match
-        serde_json::from_value(request_body)
-    {
-        Ok(request_body) => request_body,
-        Err(error) => {
-            return Err(Error::ChatApi(ChatApiError::ChatRequestBodyJsonToMap { source: error }));
-        }
-    };
+    let mut request_body = request_body_to_map(request_body)?;
 
     // Set the `stream` field to `true`
     request_body.insert("stream".to_string(), serde_json::Value::Bool(true));
@@ -43,25 +31,45 @@ pub async fn create_chat_completion_stream(
         );
     }
 
-    // Send the request
-    let response = match client.post(CHAT_COMPLETION_API_ENDPOINT).json(&request_body).send().await {
-        Ok(response) =>
-            match response.error_for_status() {
-                Ok(response) => response,
-                Err(error) => {
-                    return Err(Error::from(error));
-                }
-            }
-        Err(error) => {
-            return Err(Error::from(error));
-        }
+    // Strip or rename the fields unsupported by the provider
+    client.field_policy().apply(&mut request_body);
+
+    // Send the request after waiting for the capacity of the rate limiter
+    let model = request_body
+        .get("model")
+        .and_then(|model| model.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let estimated_tokens = match client.rate_limiter() {
+        Some(_) => estimate_chat_tokens(&request_body),
+        None => 0,
     };
+    let (response, in_flight, taken_tokens) = client.execute_rate_limited(
+        &model,
+        estimated_tokens,
+        |client| {
+            client.post(client.endpoint(CHAT_COMPLETION_API_PATH)).json(&request_body)
+        }
+    ).await?;
+
+    // The taken tokens are reconciled when the usage arrives at the end of the stream
+    let pending_usage = client
+        .rate_limiter()
+        .map(|rate_limiter| {
+            PendingUsage::new(Arc::clone(rate_limiter), model.clone(), taken_tokens)
+        });
+
+    // Trust the remaining capacity reported by the API
+    if let Some(rate_limiter) = client.rate_limiter() {
+        rate_limiter.update_from_headers(&model, response.headers());
+    }
 
     // Get the bytes stream
     let bytes_stream = response.bytes_stream();
 
-    // Wrap the bytes stream in a ChatCompletionStream
-    Ok(ChatCompletionStream::new(bytes_stream))
+    // Wrap the bytes stream in a ChatCompletionStream,
+    // which keeps the request in flight until it is dropped
+    Ok(ChatCompletionStream::new(bytes_stream).in_flight(in_flight).pending_usage(pending_usage))
 }
 
 #[cfg(test)]
@@ -69,12 +77,17 @@ mod tests {
     use std::time::Duration;
     use futures::StreamExt;
     use crate::prelude::*;
+    use crate::chat::fixtures::chat_completion_chunk_body;
     use super::*;
 
+    #[cfg(feature = "env")]
     #[tokio::test]
     async fn test_create_chat_completion_stream() -> Result<()> {
         // Create a client
-        let client = OpenAIClient::builder().timeout(Duration::from_millis(3000)).build()?;
+        let client = OpenAIClient::builder()
+            .load_env()
+            .timeout(Duration::from_millis(3000))
+            .build()?;
 
         // Build the request body
         let request_body = ChatRequestBody::builder(
@@ -99,10 +112,14 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "env")]
     #[tokio::test]
     async fn tool_call() -> Result<()> {
         // Create a client
-        let client = OpenAIClient::builder().timeout(Duration::from_millis(3000)).build()?;
+        let client = OpenAIClient::builder()
+            .load_env()
+            .timeout(Duration::from_millis(3000))
+            .build()?;
 
         // Build the request body
         let request_body = ChatRequestBody::builder(
@@ -140,4 +157,42 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_create_chat_completion_stream_with_meta() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/chat/completions")
+            .with_header("content-type", "text/event-stream")
+            .with_header("x-request-id", "req_456")
+            .with_header("x-ratelimit-reset-requests", "6m0s")
+            .with_body(
+                format!(
+                    "data: {}\n\ndata: [DONE]\n\n",
+                    chat_completion_chunk_body("Hello")
+                )
+            )
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        let request_body = ChatRequestBody::builder("gpt-4o", vec![user_message!("Hi")]).build();
+        let response = client.with_meta(|client| async move {
+            create_chat_completion_stream(&client, &request_body, false).await
+        }).await?;
+
+        // The headers are available before the stream is consumed
+        assert_eq!(response.meta.request_id.as_deref(), Some("req_456"));
+        assert_eq!(response.meta.reset_requests, Some(Duration::from_secs(360)));
+
+        let mut stream = response.data;
+        let chunk = stream.next().await.unwrap()?;
+        assert_eq!(chunk.choices[0].delta.content.as_deref(), Some("Hello"));
+        assert!(stream.next().await.is_none());
+        mock.assert_async().await;
+
+        Ok(())
+    }
 }
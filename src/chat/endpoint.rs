@@ -1 +1,2 @@
-pub const CHAT_COMPLETION_API_ENDPOINT: &str = "https://api.openai.com/v1/chat/completions";
+/// Path of the chat completions API under the base URL of the client.
+pub const CHAT_COMPLETION_API_PATH: &str = "chat/completions";
@@ -0,0 +1,31 @@
+use serde_json::json;
+
+/// The body of a chat completion replying "Hello!" with 11 tokens used.
+pub(crate) fn chat_completion_body() -> String {
+    json!({
+        "id": "chatcmpl-123",
+        "object": "chat.completion",
+        "created": 1677652288,
+        "model": "gpt-4o",
+        "choices": [
+            {
+                "index": 0,
+                "message": { "role": "assistant", "content": "Hello!" },
+                "logprobs": null,
+                "finish_reason": "stop"
+            }
+        ],
+        "usage": { "prompt_tokens": 9, "completion_tokens": 2, "total_tokens": 11 }
+    }).to_string()
+}
+
+/// The data of a chat completion chunk with the given content.
+pub(crate) fn chat_completion_chunk_body(content: &str) -> String {
+    json!({
+        "id": "chatcmpl-123",
+        "object": "chat.completion.chunk",
+        "created": 1677652288,
+        "model": "gpt-4o",
+        "choices": [{ "index": 0, "delta": { "content": content }, "finish_reason": null }]
+    }).to_string()
+}
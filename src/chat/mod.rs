@@ -8,3 +8,6 @@ pub use response::*;
 
 mod api_calls;
 pub use api_calls::*;
+
+#[cfg(test)]
+pub(crate) mod fixtures;
@@ -1,5 +1,6 @@
 use serde::Serialize;
 use log::warn;
+use crate::{ Result, Error, ChatApiError, models::ModelRegistry };
 use super::{ message::ChatRequestMessage, tool::{ Tool, ToolChoice } };
 
 const MIN_FREQUENCY_PENALTY: f32 = -2.0;
@@ -42,6 +43,11 @@ impl ChatRequestBody {
     ) -> ChatRequestBodyBuilder {
         ChatRequestBodyBuilder::new(model, messages)
     }
+
+    /// Gets the messages of the conversation.
+    pub fn messages(&self) -> &[ChatRequestMessage] {
+        &self.messages
+    }
 }
 
 pub struct ChatRequestBodyBuilder {
@@ -90,6 +96,37 @@ impl ChatRequestBodyBuilder {
         }
     }
 
+    /// Builds the request body after checking it against
+    /// the capabilities of the model in the registry.
+    ///
+    /// An error is returned if `max_tokens` exceeds the maximum output tokens of the model,
+    /// or tools are provided to a model that does not support tools.
+    /// Models unknown to the registry are not checked.
+    pub fn build_checked(self, registry: &ModelRegistry) -> Result<ChatRequestBody> {
+        if let Some(capabilities) = registry.get(&self.model) {
+            // Check the max tokens
+            if let Some(max_tokens) = self.max_tokens {
+                if max_tokens > capabilities.max_output_tokens {
+                    return Err(
+                        Error::ChatApi(ChatApiError::MaxTokensExceedsLimit {
+                            model: self.model,
+                            max_tokens,
+                            limit: capabilities.max_output_tokens,
+                        })
+                    );
+                }
+            }
+
+            // Check the tools
+            let has_tools = self.tools.as_ref().is_some_and(|tools| !tools.is_empty());
+            if has_tools && !capabilities.supports_tools {
+                return Err(Error::ChatApi(ChatApiError::ToolsNotSupported(self.model)));
+            }
+        }
+
+        Ok(self.build())
+    }
+
     /// Sets the frequency penalty.
     ///
     /// The input value will be clampped in between -2.0 and 2.0.
@@ -100,8 +137,7 @@ impl ChatRequestBodyBuilder {
     pub fn frequency_penalty(mut self, frequency_penalty: f32) -> Self {
         // Clamp the value to the valid range
         let frequency_penalty = if
-            frequency_penalty < MIN_FREQUENCY_PENALTY ||
-            frequency_penalty > MAX_FREQUENCY_PENALTY
+            !(MIN_FREQUENCY_PENALTY..=MAX_FREQUENCY_PENALTY).contains(&frequency_penalty)
         {
             // Clamp the value
             let penalty = frequency_penalty.clamp(MIN_FREQUENCY_PENALTY, MAX_FREQUENCY_PENALTY);
@@ -170,7 +206,7 @@ impl ChatRequestBodyBuilder {
     pub fn top_p(mut self, top_p: f32) -> Self {
         // Clamp the value to the valid range
         // Since it is a probability, it should be between 0 and 1
-        let top_p = if top_p < MIN_TOP_P || top_p > MAX_TOP_P {
+        let top_p = if !(MIN_TOP_P..=MAX_TOP_P).contains(&top_p) {
             // Clamp the value
             let p = top_p.clamp(MIN_TOP_P, MAX_TOP_P);
 
@@ -307,6 +343,52 @@ mod tests {
         println!("{}", serde_json::to_string_pretty(&request_body).unwrap());
     }
 
+    #[test]
+    fn build_checked() {
+        let registry = ModelRegistry::builtin();
+
+        // Within the limits
+        let result = ChatRequestBody::builder(
+            "gpt-4o-2024-08-06",
+            vec![ChatRequestMessage::User(UserMessage::new("Hello?"))]
+        )
+            .max_tokens(16_384)
+            .build_checked(&registry);
+        assert!(result.is_ok());
+
+        // Too many max tokens
+        let result = ChatRequestBody::builder(
+            "gpt-3.5-turbo",
+            vec![ChatRequestMessage::User(UserMessage::new("Hello?"))]
+        )
+            .max_tokens(10_000)
+            .build_checked(&registry);
+        assert!(
+            matches!(
+                result,
+                Err(Error::ChatApi(ChatApiError::MaxTokensExceedsLimit { limit: 4_096, .. }))
+            )
+        );
+
+        // Tools on a model without tool support
+        let result = ChatRequestBody::builder(
+            "o1-mini",
+            vec![ChatRequestMessage::User(UserMessage::new("Hello?"))]
+        )
+            .tools(vec![Tool::Function(Function::new("foo"))])
+            .build_checked(&registry);
+        assert!(matches!(result, Err(Error::ChatApi(ChatApiError::ToolsNotSupported(_)))));
+
+        // Unknown models are not checked
+        let result = ChatRequestBody::builder(
+            "llama3",
+            vec![ChatRequestMessage::User(UserMessage::new("Hello?"))]
+        )
+            .max_tokens(1_000_000)
+            .build_checked(&registry);
+        assert!(result.is_ok());
+    }
+
     // #[test]
     // fn build_request_body_with_macros() {
     //     // Prepare request body
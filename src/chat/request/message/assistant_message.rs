@@ -24,6 +24,12 @@ impl AssistantMessage {
     }
 }
 
+impl Default for AssistantMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Serialize for AssistantMessage {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
         // Calculate number of fields to serialize
@@ -19,6 +19,11 @@ impl UserMessage {
     pub fn builder<S: AsRef<str>>(content: S) -> UserMessageBuilder {
         UserMessageBuilder::new(content)
     }
+
+    /// Gets the content of the message.
+    pub fn content(&self) -> &str {
+        &self.content
+    }
 }
 
 impl Serialize for UserMessage {
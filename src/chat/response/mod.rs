@@ -14,7 +14,11 @@ mod token_usage;
 pub use token_usage::ChatCompletionTokenUsage;
 
 mod tool;
-pub use tool::{ ChatCompletionToolCall, ChatCompletionChunkToolCall };
+pub use tool::{
+    ChatCompletionToolCall,
+    ChatCompletionToolCallFunction,
+    ChatCompletionChunkToolCall,
+};
 
 mod stream;
 pub use stream::ChatCompletionStream;
@@ -1,89 +1,619 @@
-use std::{ path::PathBuf, time::Duration };
-use reqwest::{ Client, ClientBuilder, IntoUrl, RequestBuilder };
-use lazy_static::lazy_static;
-use log::*;
-use crate::{ Result, Error };
-
-lazy_static! {
-    /// The OpenAI API key.
-    pub static ref OPENAI_API_KEY: Option<String> = {
-        let _ = DOTENV_FILE_PATH.as_ref();
-        dotenv::var("OPENAI_API_KEY").ok()
-    };
-
-    /// The path to the .env file.
-    static ref DOTENV_FILE_PATH: Option<PathBuf> = {
-        match dotenv::dotenv().ok() {
-            Some(path) => {
-                info!("loaded environment variables from {:?}", path);
-                Some(path)
-            }
-            None => {
-                warn!("failed to load environment variables");
-                None
-            }
-        }
-    };
-}
+use std::{
+    future::Future,
+    hash::{ DefaultHasher, Hash, Hasher },
+    sync::{ Arc, Mutex },
+    time::Duration,
+};
+use reqwest::{
+    Client,
+    ClientBuilder,
+    IntoUrl,
+    RequestBuilder,
+    Response,
+    StatusCode,
+    header::{ HeaderMap, HeaderValue },
+};
+use crate::{
+    Result,
+    Error,
+    AzureConfig,
+    AzureAuth,
+    CredentialProvider,
+    StaticCredential,
+    CredentialPool,
+    RateLimiter,
+    AuthScheme,
+    FieldPolicy,
+    ErrorFormat,
+    ProviderProfile,
+    ResponseMeta,
+    WithMeta,
+    pool::InFlightGuard,
+    rate_limit::MAX_RATE_LIMITED_RETRIES,
+    models::{ ModelCache, DEFAULT_MODEL_CACHE_TTL },
+};
+
+/// The default base URL of the OpenAI API.
+const DEFAULT_BASE_URL: &str = "https://api.openai.com/v1";
+
+/// The header specifying the organization used for a request.
+const ORGANIZATION_HEADER: &str = "OpenAI-Organization";
+
+/// The header specifying the project used for a request.
+const PROJECT_HEADER: &str = "OpenAI-Project";
 
 /// The OpenAI client.
 /// It is a simple wrapper around the `reqwest` HTTP client
 /// with the authorization when building requests.
+///
+/// Cloning the client is cheap,
+/// as the connection pool and the model cache are shared.
+#[derive(Clone)]
 pub struct OpenAIClient {
-    api_key: String,
+    credential: Option<Arc<dyn CredentialProvider>>,
+    pool: Option<Arc<CredentialPool>>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    base_url: String,
+    headers: HeaderMap,
     http_client: Client,
+    model_cache: Arc<ModelCache>,
+    azure: Option<AzureConfig>,
+    auth_scheme: AuthScheme,
+    field_policy: FieldPolicy,
+    error_format: ErrorFormat,
+
+    /// Where the information in the response headers is recorded for `with_meta`.
+    meta_recorder: Option<Arc<Mutex<ResponseMeta>>>,
 }
 
 impl OpenAIClient {
     /// Creates a new client with default settings.
+    ///
+    /// Nothing is read from the environment,
+    /// so it fails without an API key unless the provider takes no credentials.
     pub fn new() -> Result<Self> {
         Self::builder().build()
     }
 
+    /// Creates a new client with the settings read from the environment
+    /// and the `.env` file if any.
+    #[cfg(feature = "env")]
+    pub fn from_env() -> Result<Self> {
+        Self::builder().load_env().build()
+    }
+
     /// Creates a builder for OpenAIClient.
     pub fn builder() -> OpenAIClientBuilder {
         OpenAIClientBuilder::new()
     }
 
+    /// Creates a copy of the client that also sends the given headers,
+    /// which replace the default ones with the same names.
+    ///
+    /// It is meant for scoping headers, e.g., a trace ID, to a few API calls.
+    pub fn with_headers(&self, headers: HeaderMap) -> Self {
+        let mut client = self.clone();
+        client.headers.extend(headers);
+        client
+    }
+
+    /// Calls the API through a copy of the client
+    /// that records the rate limit and processing information in the response headers,
+    /// and returns the result together with the information of the last response.
+    ///
+    /// It works with any API call, e.g.,
+    /// `client.with_meta(|client| async move { generate_images(&client, &request_body).await })`.
+    /// The information is empty if no request is sent, e.g., when the model list is cached.
+    pub async fn with_meta<T, F, Fut>(&self, call: F) -> Result<WithMeta<T>>
+        where F: FnOnce(OpenAIClient) -> Fut, Fut: Future<Output = Result<T>>
+    {
+        let recorder = Arc::new(Mutex::new(ResponseMeta::default()));
+        let mut client = self.clone();
+        client.meta_recorder = Some(Arc::clone(&recorder));
+
+        let data = call(client).await?;
+        let meta = match recorder.lock() {
+            Ok(meta) => meta.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        };
+
+        Ok(WithMeta { data, meta })
+    }
+
+    /// Gets the headers sent with every request besides the authorization.
+    #[cfg(feature = "realtime")]
+    pub(crate) fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Gets the underlying HTTP client.
+    /// Requests built from it do not carry the authorization header,
+    /// which is suitable for accessing hosts other than the API, e.g., a CDN.
+    pub(crate) fn http_client(&self) -> &Client {
+        &self.http_client
+    }
+
+    /// Gets the current API key from the credential provider,
+    /// which is empty if the provider takes no credentials.
+    ///
+    /// With a credential pool, it is the key of the primary member.
+    pub(crate) fn api_key(&self) -> String {
+        match self.pool.as_ref().and_then(|pool| pool.primary().map(|index| (pool, index))) {
+            Some((pool, index)) => pool.credential(index).api_key(),
+            None =>
+                match self.credential.as_ref() {
+                    Some(credential) => credential.api_key(),
+                    None => String::new(),
+                }
+        }
+    }
+
+    /// Gets the base URL, which is that of the primary member of the credential pool if any.
+    fn base_url(&self) -> &str {
+        self.pool
+            .as_ref()
+            .and_then(|pool| pool.primary().and_then(|index| pool.base_url(index)))
+            .unwrap_or(self.base_url.as_str())
+    }
+
+    /// Creates a copy of the client that uses only the given key of the pool and its base URL.
+    fn with_member(&self, pool: &CredentialPool, index: usize) -> OpenAIClient {
+        let mut client = self.clone();
+        client.pool = None;
+        client.credential = Some(Arc::clone(pool.credential(index)));
+        if let Some(base_url) = pool.base_url(index) {
+            client.base_url = base_url.to_string();
+        }
+        client
+    }
+
+    /// Creates a copy of the client that uses only the primary key of the pool if any,
+    /// so that the key and the base URL of a request are from the same member
+    /// even if the primary key is benched in the meantime.
+    pub(crate) fn with_primary_member(&self) -> Option<OpenAIClient> {
+        let pool = self.pool.as_ref()?;
+        pool.primary().map(|index| self.with_member(pool, index))
+    }
+
+    /// Sends the request built by the closure.
+    ///
+    /// With a credential pool, the request is built with a key selected from the pool,
+    /// and it is retried with another key if the key is rate limited or rejected.
+    /// The response to the last try is returned if all keys are benched,
+    /// together with the guard keeping the request in flight for the key
+    /// until the body of the response is received.
+    pub(crate) async fn execute<F>(&self, build: F) -> Result<(Response, Option<InFlightGuard>)>
+        where F: Fn(&OpenAIClient) -> RequestBuilder
+    {
+        let (response, guard) = self.try_keys(&build).await?;
+        Ok((self.check_status(response).await?, guard))
+    }
+
+    /// Same as `execute`, but waits for the capacity of the rate limiter for the model first,
+    /// and also returns the tokens taken, which are given back if the request fails.
+    ///
+    /// After a 429 response, the model is paused for the time told by the response headers,
+    /// and the request is retried at most `MAX_RATE_LIMITED_RETRIES` times.
+    pub(crate) async fn execute_rate_limited<F>(
+        &self,
+        model: &str,
+        estimated_tokens: u32,
+        build: F
+    ) -> Result<(Response, Option<InFlightGuard>, u32)>
+        where F: Fn(&OpenAIClient) -> RequestBuilder
+    {
+        let rate_limiter = match self.rate_limiter.as_ref() {
+            Some(rate_limiter) => rate_limiter,
+            None => {
+                let (response, guard) = self.execute(build).await?;
+                return Ok((response, guard, 0));
+            }
+        };
+
+        let mut retries = 0;
+        loop {
+            let taken_tokens = rate_limiter.acquire(model, estimated_tokens).await;
+            let (response, guard) = match self.try_keys(&build).await {
+                Ok(response) => response,
+                Err(error) => {
+                    // No tokens are used if the request failed
+                    rate_limiter.reconcile(model, taken_tokens, 0);
+                    return Err(error);
+                }
+            };
+
+            // Wait as told by the API and retry
+            let status = response.status();
+            if status == StatusCode::TOO_MANY_REQUESTS && retries < MAX_RATE_LIMITED_RETRIES {
+                rate_limiter.reconcile(model, taken_tokens, 0);
+                rate_limiter.pause_from_headers(model, response.headers());
+                retries += 1;
+                continue;
+            }
+
+            return match self.check_status(response).await {
+                Ok(response) => Ok((response, guard, taken_tokens)),
+                Err(error) => {
+                    rate_limiter.reconcile(model, taken_tokens, 0);
+                    Err(error)
+                }
+            };
+        }
+    }
+
+    /// Sends the request built by the closure with the keys of the pool in turn as in `execute`,
+    /// and returns the response to the last try whatever its status.
+    async fn try_keys<F>(&self, build: &F) -> Result<(Response, Option<InFlightGuard>)>
+        where F: Fn(&OpenAIClient) -> RequestBuilder
+    {
+        let pool = match self.pool.as_ref() {
+            Some(pool) => pool,
+            None => {
+                return Ok((build(self).send().await?, None));
+            }
+        };
+
+        let mut tried = vec![];
+        let mut selected = pool.select(&tried);
+        loop {
+            let index = match selected {
+                Some(index) => index,
+                None => {
+                    // It is unreachable since the pool is not empty
+                    return Ok((build(self).send().await?, None));
+                }
+            };
+            tried.push(index);
+
+            // Build the request with the selected key and its base URL
+            let client = self.with_member(pool, index);
+            let guard = pool.dispatch(index);
+            let response = match build(&client).send().await {
+                Ok(response) => response,
+                Err(error) => {
+                    return Err(Error::from(error));
+                }
+            };
+
+            // Retry with another key if any
+            if pool.report(index, response.status()) {
+                selected = pool.select(&tried);
+                if selected.is_some() {
+                    continue;
+                }
+            }
+
+            return Ok((response, Some(guard)));
+        }
+    }
+
+    /// Sends the request built by the closure,
+    /// and fails if the status of the response is an error.
+    ///
+    /// With a credential pool, the request is built with the primary key,
+    /// which is benched if it is rate limited or rejected so that the next request uses another.
+    /// The request itself is not retried, as its body may not be replayable, e.g., a file upload.
+    pub(crate) async fn send<F>(&self, build: F) -> Result<Response>
+        where F: FnOnce(&OpenAIClient) -> RequestBuilder
+    {
+        let primary = self.pool
+            .as_ref()
+            .and_then(|pool| pool.primary().map(|index| (pool, index)));
+        let client = primary.map(|(pool, index)| self.with_member(pool, index));
+
+        // The request is counted for the key until the response is received
+        let in_flight = primary.map(|(pool, index)| pool.dispatch(index));
+        let response = match build(client.as_ref().unwrap_or(self)).send().await {
+            Ok(response) => response,
+            Err(error) => {
+                return Err(Error::from(error));
+            }
+        };
+        drop(in_flight);
+
+        if let Some((pool, index)) = primary {
+            pool.report(index, response.status());
+        }
+
+        self.check_status(response).await
+    }
+
+    /// Records the information in the response headers for `with_meta`,
+    /// and converts an error status to an error,
+    /// which keeps the message in the body if the provider reports errors there.
+    async fn check_status(&self, response: Response) -> Result<Response> {
+        if let Some(recorder) = self.meta_recorder.as_ref() {
+            let meta = ResponseMeta::from_headers(response.headers());
+            match recorder.lock() {
+                Ok(mut recorded) => {
+                    *recorded = meta;
+                }
+                Err(poisoned) => {
+                    *poisoned.into_inner() = meta;
+                }
+            }
+        }
+
+        let status_code = response.status();
+        let is_error = status_code.is_client_error() || status_code.is_server_error();
+        if is_error && self.error_format == ErrorFormat::Body {
+            let body = response.text().await.unwrap_or_default();
+            return Err(Error::Provider { status_code, message: ErrorFormat::message(&body) });
+        }
+
+        match response.error_for_status() {
+            Ok(response) => Ok(response),
+            Err(error) => Err(Error::from(error)),
+        }
+    }
+
+    /// Gets the policy applied to chat request bodies
+    /// for the fields unsupported by the provider.
+    pub(crate) fn field_policy(&self) -> &FieldPolicy {
+        &self.field_policy
+    }
+
+    /// Gets the rate limiter of chat completion requests if any.
+    pub(crate) fn rate_limiter(&self) -> Option<&Arc<RateLimiter>> {
+        self.rate_limiter.as_ref()
+    }
+
+    /// Gets a key identifying the account, organization and project of the requests,
+    /// so that the data cached for one of them is not returned for another.
+    pub(crate) fn account_key(&self) -> String {
+        let header = |name: &str| {
+            self.headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .unwrap_or_default()
+        };
+
+        // Only a hash of the API key is kept
+        let mut hasher = DefaultHasher::new();
+        self.api_key().hash(&mut hasher);
+
+        format!(
+            "{} {} {} {:x}",
+            self.endpoint(""),
+            header(ORGANIZATION_HEADER),
+            header(PROJECT_HEADER),
+            hasher.finish()
+        )
+    }
+
+    /// Gets the cache of the model lists.
+    pub(crate) fn model_cache(&self) -> &ModelCache {
+        &self.model_cache
+    }
+
+    /// Removes the cached model lists of all accounts
+    /// so that the next call of `list_models_cached` fetches it from the API.
+    pub fn clear_model_cache(&self) {
+        self.model_cache.clear();
+    }
+
+    /// Gets the full URL of an API endpoint
+    /// by joining the base URL and the endpoint path.
+    /// On Azure, the URL is instead built from the resource and deployment.
+    /// With a credential pool, it is the base URL of the primary member at the time of the call.
+    pub fn endpoint<S: AsRef<str>>(&self, path: S) -> String {
+        match self.azure.as_ref() {
+            Some(azure) => azure.endpoint(path.as_ref()),
+            None => format!("{}/{}", self.base_url(), path.as_ref().trim_start_matches('/')),
+        }
+    }
+
+    /// Gets the header carrying the credentials,
+    /// i.e., the authorization header with the API key,
+    /// or the `api-key` header on Azure unless Entra ID is used.
+    /// It is `None` if the provider takes no credentials.
+    pub(crate) fn auth_header(&self) -> Option<(&'static str, String)> {
+        let bearer = || ("Authorization", format!("Bearer {}", self.api_key()));
+
+        match self.azure.as_ref().map(AzureConfig::auth) {
+            Some(AzureAuth::ApiKey) => Some(("api-key", self.api_key())),
+            Some(AzureAuth::EntraId) => Some(bearer()),
+            None =>
+                match self.auth_scheme {
+                    AuthScheme::Bearer => Some(bearer()),
+                    AuthScheme::None => None,
+                }
+        }
+    }
+
+    /// Sets the header carrying the credentials if any.
+    fn authorize(&self, request_builder: RequestBuilder) -> RequestBuilder {
+        let (name, value) = match self.auth_header() {
+            Some(header) => header,
+            None => {
+                return request_builder;
+            }
+        };
+
+        match HeaderValue::from_str(&value) {
+            Ok(mut value) => {
+                // Keep the credentials out of the debug output
+                value.set_sensitive(true);
+                request_builder.header(name, value)
+            }
+            // The request fails when it is sent
+            Err(_) => request_builder.header(name, value),
+        }
+    }
+
     /// Creates a GET request builder.
     /// Authorization header will be set with the API key.
     pub fn get<U: IntoUrl>(&self, url: U) -> RequestBuilder {
-        self.http_client
-            .get(url)
+        // Set the authorization and default headers
+        self.authorize(self.http_client.get(url)).headers(self.headers.clone())
+    }
 
-            // Set the authorization header
-            .bearer_auth(self.api_key.as_str())
+    /// Creates a DELETE request builder.
+    /// Authorization header will be set with the API key.
+    pub fn delete<U: IntoUrl>(&self, url: U) -> RequestBuilder {
+        // Set the authorization and default headers
+        self.authorize(self.http_client.delete(url)).headers(self.headers.clone())
     }
 
     /// Creates a POST request builder.
     /// Authorization header will be set with the API key.
     pub fn post<U: IntoUrl>(&self, url: U) -> RequestBuilder {
-        self.http_client
-            .post(url)
-
-            // Set the authorization header
-            .header("Authorization", format!("Bearer {}", self.api_key.as_str()))
+        // Set the authorization and default headers
+        self.authorize(self.http_client.post(url)).headers(self.headers.clone())
     }
 }
 
 /// Builder for `OpenAIClient`.
 pub struct OpenAIClientBuilder {
-    api_key: Option<String>,
+    credential: Option<Arc<dyn CredentialProvider>>,
+    pool: Option<Arc<CredentialPool>>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    base_url: String,
+    organization: Option<String>,
+    project: Option<String>,
+    default_headers: HeaderMap,
+    model_cache_ttl: Duration,
     http_client_builder: ClientBuilder,
+    azure: Option<AzureConfig>,
+    auth_scheme: AuthScheme,
+    field_policy: FieldPolicy,
+    error_format: ErrorFormat,
 }
 
 impl OpenAIClientBuilder {
     /// Creates a new builder.
     pub fn new() -> Self {
         Self {
-            api_key: None,
+            credential: None,
+            pool: None,
+            rate_limiter: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            organization: None,
+            project: None,
+            default_headers: HeaderMap::new(),
+            model_cache_ttl: DEFAULT_MODEL_CACHE_TTL,
             http_client_builder: Client::builder(),
+            azure: None,
+            auth_scheme: AuthScheme::Bearer,
+            field_policy: FieldPolicy::new(),
+            error_format: ErrorFormat::OpenAI,
         }
     }
 
-    /// Sets the API key.
-    pub fn api_key<S: AsRef<str>>(mut self, api_key: S) -> Self {
-        self.api_key = Some(api_key.as_ref().to_string());
+    /// Sets a fixed API key.
+    pub fn api_key<S: AsRef<str>>(self, api_key: S) -> Self {
+        self.credential_provider(StaticCredential::new(api_key))
+    }
+
+    /// Sets the provider consulted for the API key on every request,
+    /// which replaces the API key set before.
+    pub fn credential_provider<C: CredentialProvider + 'static>(mut self, credential: C) -> Self {
+        self.credential = Some(Arc::new(credential));
+        self
+    }
+
+    /// Sets the base URL of the API.
+    ///
+    /// It defaults to `https://api.openai.com/v1`.
+    /// A trailing slash will be removed.
+    pub fn base_url<S: AsRef<str>>(mut self, base_url: S) -> Self {
+        self.base_url = base_url.as_ref().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Spreads chat completion requests across the keys of the pool,
+    /// which takes precedence over the API key.
+    ///
+    /// Other requests are sent with the first key that is not benched,
+    /// which is benched after a 429 or 401 response without retrying the request.
+    /// Keep a reference to the pool to report the health of its keys.
+    pub fn credential_pool(mut self, pool: Arc<CredentialPool>) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Makes chat completion requests wait for the capacity of the rate limiter,
+    /// and retry after a 429 response. Other requests are not limited.
+    ///
+    /// Keep a reference to the limiter to share it across clients using the same account.
+    pub fn rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Sets the organization, which is sent in the `OpenAI-Organization` header.
+    pub fn organization<S: AsRef<str>>(mut self, organization: S) -> Self {
+        self.organization = Some(organization.as_ref().to_string());
+        self
+    }
+
+    /// Sets the project, which is sent in the `OpenAI-Project` header.
+    pub fn project<S: AsRef<str>>(mut self, project: S) -> Self {
+        self.project = Some(project.as_ref().to_string());
+        self
+    }
+
+    /// Sets the headers sent with every request, e.g., those required by a gateway.
+    ///
+    /// The organization and project headers take precedence over them.
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.default_headers = headers;
+        self
+    }
+
+    /// Targets an OpenAI-compatible provider
+    /// by setting its base URL, auth scheme, field compatibility policy and error format.
+    ///
+    /// The base URL may be overridden afterward, e.g., with the address of a local server.
+    pub fn provider(mut self, profile: ProviderProfile) -> Self {
+        let (base_url, auth_scheme, field_policy, error_format) = profile.into_parts();
+        self.base_url = base_url;
+        self.auth_scheme = auth_scheme;
+        self.field_policy = field_policy;
+        self.error_format = error_format;
+        self
+    }
+
+    /// Sends requests to an Azure OpenAI resource instead,
+    /// in which case the base URL is ignored.
+    pub fn azure(mut self, config: AzureConfig) -> Self {
+        self.azure = Some(config);
+        self
+    }
+
+    /// Reads the API key, base URL, organization and project
+    /// from the environment variables `OPENAI_API_KEY`, `OPENAI_BASE_URL`,
+    /// `OPENAI_ORG_ID` and `OPENAI_PROJECT_ID`.
+    ///
+    /// The `.env` file in the current directory or its ancestors is loaded first if it exists,
+    /// which does not override the variables already set.
+    /// Only the variables present are applied, replacing the values set before this call.
+    #[cfg(feature = "env")]
+    pub fn load_env(self) -> Self {
+        self.load_env_vars(&EnvVarNames::default())
+    }
+
+    /// Same as `load_env` but reads the variables of the given names.
+    #[cfg(feature = "env")]
+    pub fn load_env_vars(mut self, names: &EnvVarNames) -> Self {
+        if let Ok(path) = dotenv::dotenv() {
+            log::debug!("loaded environment variables from {:?}", path);
+        }
+
+        let var = |name: &str| dotenv::var(name).ok();
+        if let Some(api_key) = var(&names.api_key) {
+            self = self.api_key(api_key);
+        }
+        if let Some(base_url) = var(&names.base_url) {
+            self = self.base_url(base_url);
+        }
+        if let Some(organization) = var(&names.organization) {
+            self = self.organization(organization);
+        }
+        if let Some(project) = var(&names.project) {
+            self = self.project(project);
+        }
+
         self
     }
 
@@ -93,21 +623,58 @@ impl OpenAIClientBuilder {
         self
     }
 
+    /// Sets how long the model list fetched by `list_models_cached` is kept.
+    ///
+    /// It defaults to one hour.
+    pub fn model_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.model_cache_ttl = ttl;
+        self
+    }
+
     /// Builds the OpenAI client.
     pub fn build(self) -> Result<OpenAIClient> {
-        // Get the API key
-        // If the API key is not set, try to get it from the environment variables
-        let api_key = self.api_key
-            .or(OPENAI_API_KEY.as_ref().map(|api_key| api_key.to_string()))
-            .ok_or(Error::ApiKeyNotSet)?;
+        // Check the API key
+        // A provider taking no credentials does not need one
+        let has_pool = self.pool.as_ref().is_some_and(|pool| !pool.is_empty());
+        if self.auth_scheme == AuthScheme::Bearer && self.credential.is_none() && !has_pool {
+            return Err(Error::ApiKeyNotSet);
+        }
+
+        // Collect the headers sent with every request
+        let mut headers = self.default_headers;
+        for (name, value) in [
+            (ORGANIZATION_HEADER, self.organization),
+            (PROJECT_HEADER, self.project),
+        ] {
+            if let Some(value) = value {
+                match HeaderValue::from_str(value.as_str()) {
+                    Ok(value) => {
+                        headers.insert(name, value);
+                    }
+                    Err(error) => {
+                        return Err(Error::InvalidHeaderValue { name, source: error });
+                    }
+                }
+            }
+        }
 
         // Build an HTTP client
         match self.http_client_builder.build() {
             // Return the OpenAI client
             Ok(http_client) => {
                 Ok(OpenAIClient {
-                    api_key,
+                    credential: self.credential,
+                    pool: self.pool.filter(|pool| !pool.is_empty()),
+                    rate_limiter: self.rate_limiter,
+                    base_url: self.base_url,
+                    headers,
                     http_client,
+                    model_cache: Arc::new(ModelCache::new(self.model_cache_ttl)),
+                    azure: self.azure,
+                    auth_scheme: self.auth_scheme,
+                    field_policy: self.field_policy,
+                    error_format: self.error_format,
+                    meta_recorder: None,
                 })
             }
 
@@ -117,37 +684,153 @@ impl OpenAIClientBuilder {
     }
 }
 
+/// Names of the environment variables read by `OpenAIClientBuilder::load_env_vars`.
+#[cfg(feature = "env")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnvVarNames {
+    api_key: String,
+    base_url: String,
+    organization: String,
+    project: String,
+}
+
+#[cfg(feature = "env")]
+impl Default for EnvVarNames {
+    fn default() -> Self {
+        Self {
+            api_key: "OPENAI_API_KEY".to_string(),
+            base_url: "OPENAI_BASE_URL".to_string(),
+            organization: "OPENAI_ORG_ID".to_string(),
+            project: "OPENAI_PROJECT_ID".to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "env")]
+impl EnvVarNames {
+    /// Sets the variable of the API key.
+    pub fn api_key<S: AsRef<str>>(mut self, name: S) -> Self {
+        self.api_key = name.as_ref().to_string();
+        self
+    }
+
+    /// Sets the variable of the base URL.
+    pub fn base_url<S: AsRef<str>>(mut self, name: S) -> Self {
+        self.base_url = name.as_ref().to_string();
+        self
+    }
+
+    /// Sets the variable of the organization.
+    pub fn organization<S: AsRef<str>>(mut self, name: S) -> Self {
+        self.organization = name.as_ref().to_string();
+        self
+    }
+
+    /// Sets the variable of the project.
+    pub fn project<S: AsRef<str>>(mut self, name: S) -> Self {
+        self.project = name.as_ref().to_string();
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    fn init_logger() {
-        let _ = env_logger
-            ::builder()
-            .filter_level(log::LevelFilter::Debug)
-            .is_test(true)
-            .try_init();
+    #[test]
+    fn test_build_openai_client_without_api_key() {
+        let client = OpenAIClientBuilder::new().build();
+        assert!(matches!(client, Err(Error::ApiKeyNotSet)));
     }
 
+    #[cfg(feature = "env")]
     #[test]
-    fn test_openai_api_key() {
-        // Initialize a logger
-        init_logger();
+    fn test_build_openai_client_from_env() -> Result<()> {
+        std::env::set_var("TEST_RUSTYOPENAI_API_KEY", "sk-env");
+        std::env::set_var("TEST_RUSTYOPENAI_BASE_URL", "http://localhost:8080/v1/");
 
-        // A logging message will be printed
-        // since this variable is accessed for the first time
-        assert!(OPENAI_API_KEY.as_ref().is_some());
+        let client = OpenAIClient::builder()
+            .load_env_vars(
+                &EnvVarNames::default()
+                    .api_key("TEST_RUSTYOPENAI_API_KEY")
+                    .base_url("TEST_RUSTYOPENAI_BASE_URL")
+                    .organization("TEST_RUSTYOPENAI_UNSET_ORG_ID")
+            )
+            .build()?;
 
-        // No more messages will be printed
-        assert!(OPENAI_API_KEY.as_ref().is_some());
+        assert_eq!(client.api_key(), "sk-env");
+        assert_eq!(client.endpoint("models"), "http://localhost:8080/v1/models");
+        assert!(client.headers.get(ORGANIZATION_HEADER).is_none());
+
+        Ok(())
     }
 
-    #[test]
-    fn test_build_openai_client() {
-        // Initialize a logger
-        init_logger();
+    #[tokio::test]
+    async fn test_organization_project_and_default_headers() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/models")
+            .match_header("authorization", "Bearer sk-test")
+            .match_header("openai-organization", "org-123")
+            .match_header("openai-project", "proj_123")
+            .match_header("x-tenant-id", "tenant-a")
+            .match_header("x-trace-id", mockito::Matcher::Missing)
+            .create_async().await;
+        let scoped_mock = server
+            .mock("GET", "/models")
+            .match_header("openai-organization", "org-123")
+            .match_header("x-tenant-id", "tenant-b")
+            .match_header("x-trace-id", "trace-1")
+            .create_async().await;
 
-        let client = OpenAIClientBuilder::new().build();
-        assert!(client.is_ok());
+        let mut headers = HeaderMap::new();
+        headers.insert("x-tenant-id", HeaderValue::from_static("tenant-a"));
+        let client = OpenAIClient::builder()
+            .api_key("sk-test")
+            .base_url(server.url())
+            .organization("org-123")
+            .project("proj_123")
+            .default_headers(headers)
+            .build()?;
+        client.get(client.endpoint("models")).send().await?;
+        mock.assert_async().await;
+
+        // Override the headers for a single request
+        let mut headers = HeaderMap::new();
+        headers.insert("x-tenant-id", HeaderValue::from_static("tenant-b"));
+        headers.insert("x-trace-id", HeaderValue::from_static("trace-1"));
+        let scoped_client = client.with_headers(headers);
+        scoped_client.get(scoped_client.endpoint("models")).send().await?;
+        scoped_mock.assert_async().await;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_with_meta() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/files/file-abc123")
+            .with_header("content-type", "application/json")
+            .with_header("x-request-id", "req_123")
+            .with_header("x-ratelimit-remaining-requests", "59")
+            .with_body(
+                r#"{"id":"file-abc123","object":"file","bytes":120000,"created_at":1677610602,"filename":"batch.jsonl","purpose":"batch"}"#
+            )
+            .create_async().await;
+
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+        let response = client.with_meta(|client| async move {
+            crate::files::retrieve_file(&client, "file-abc123").await
+        }).await?;
+        mock.assert_async().await;
+
+        assert_eq!(response.data.id, "file-abc123");
+        assert_eq!(response.meta.request_id.as_deref(), Some("req_123"));
+        assert_eq!(response.meta.remaining_requests, Some(59));
+
+        Ok(())
     }
 }
@@ -0,0 +1,220 @@
+use serde::Serialize;
+use crate::{
+    Result,
+    Error,
+    CompletionsApiError,
+    OpenAIClient,
+    utils::StreamingRequestBody,
+};
+use super::super::{
+    COMPLETIONS_API_PATH,
+    CompletionRequestBody,
+    Completion,
+    CompletionStream,
+};
+
+/// Request body of streaming a completion, optionally with the usage in the last chunk.
+#[derive(Serialize)]
+struct CompletionStreamRequestBody<'a> {
+    #[serde(flatten)]
+    body: StreamingRequestBody<'a, CompletionRequestBody>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stream_options: Option<StreamOptions>,
+}
+
+#[derive(Serialize)]
+struct StreamOptions {
+    include_usage: bool,
+}
+
+/// Creates a legacy text completion.
+pub async fn create_completion(
+    client: &OpenAIClient,
+    request_body: &CompletionRequestBody
+) -> Result<Completion> {
+    // Send the request
+    let response = client.send(|client| {
+        client.post(client.endpoint(COMPLETIONS_API_PATH)).json(request_body)
+    }).await?;
+
+    // Parse the response
+    match response.json::<Completion>().await {
+        Ok(completion) => Ok(completion),
+        Err(error) => {
+            Err(Error::CompletionsApi(CompletionsApiError::ParseToCompletion { source: error }))
+        }
+    }
+}
+
+/// Creates a legacy text completion, streaming its chunks.
+///
+/// If `include_usage` is true, the last chunk carries the token usage with no choices.
+pub async fn create_completion_stream(
+    client: &OpenAIClient,
+    request_body: &CompletionRequestBody,
+    include_usage: bool
+) -> Result<CompletionStream> {
+    let request_body = CompletionStreamRequestBody {
+        body: StreamingRequestBody::new(request_body),
+        stream_options: include_usage.then_some(StreamOptions { include_usage }),
+    };
+
+    // Send the request
+    let response = client.send(|client| {
+        client.post(client.endpoint(COMPLETIONS_API_PATH)).json(&request_body)
+    }).await?;
+
+    // Wrap the bytes stream in a CompletionStream
+    Ok(CompletionStream::new(response.bytes_stream()))
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use mockito::Matcher;
+    use serde_json::json;
+    use crate::chat::ChatCompletionFinishReason;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_create_completion() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/completions")
+            .match_header("authorization", "Bearer sk-test")
+            .match_body(
+                Matcher::Json(
+                    json!({
+                        "model": "gpt-3.5-turbo-instruct",
+                        "prompt": ["Say this is a test", "Say hello"],
+                        "max_tokens": 7,
+                        "logprobs": 1
+                    })
+                )
+            )
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "id": "cmpl-uqkvlQyYK7bGYrRHQ0eXlWi7",
+                    "object": "text_completion",
+                    "created": 1589478378,
+                    "model": "gpt-3.5-turbo-instruct",
+                    "system_fingerprint": "fp_44709d6fcb",
+                    "choices": [
+                        {
+                            "text": "\n\nThis is indeed a test",
+                            "index": 0,
+                            "logprobs": {
+                                "tokens": ["\n\n", "This"],
+                                "token_logprobs": [-0.1, -0.2],
+                                "top_logprobs": [{ "\n\n": -0.1 }, { "This": -0.2 }],
+                                "text_offset": [18, 20]
+                            },
+                            "finish_reason": "length"
+                        },
+                        {
+                            "text": "\n\nHello!",
+                            "index": 1,
+                            "logprobs": null,
+                            "finish_reason": "stop"
+                        }
+                    ],
+                    "usage": { "prompt_tokens": 8, "completion_tokens": 10, "total_tokens": 18 }
+                }).to_string()
+            )
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        let request_body = CompletionRequestBody::builder(
+            "gpt-3.5-turbo-instruct",
+            vec!["Say this is a test", "Say hello"]
+        )
+            .max_tokens(7)
+            .logprobs(1)
+            .build();
+        let completion = create_completion(&client, &request_body).await?;
+
+        mock.assert_async().await;
+        assert_eq!(completion.choices[0].finish_reason, Some(ChatCompletionFinishReason::Length));
+        assert_eq!(completion.choices[0].logprobs.as_ref().unwrap().text_offset, vec![18, 20]);
+        assert_eq!(completion.choices[1].text, "\n\nHello!");
+        assert_eq!(completion.usage.unwrap().total_tokens, 18);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_completion_stream() -> Result<()> {
+        let chunk = |text: &str| {
+            json!({
+                "id": "cmpl-abc123",
+                "object": "text_completion",
+                "created": 1589478378,
+                "model": "gpt-3.5-turbo-instruct",
+                "choices": [{ "text": text, "index": 0, "logprobs": null, "finish_reason": null }]
+            })
+        };
+        let usage = json!({
+            "id": "cmpl-abc123",
+            "object": "text_completion",
+            "created": 1589478378,
+            "model": "gpt-3.5-turbo-instruct",
+            "choices": [],
+            "usage": { "prompt_tokens": 5, "completion_tokens": 2, "total_tokens": 7 }
+        });
+
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/completions")
+            .match_body(
+                Matcher::Json(
+                    json!({
+                        "model": "gpt-3.5-turbo-instruct",
+                        "prompt": "Say hello",
+                        "stream": true,
+                        "stream_options": { "include_usage": true }
+                    })
+                )
+            )
+            .with_header("content-type", "text/event-stream")
+            .with_body(
+                format!(
+                    "data: {}\n\ndata: {}\n\ndata: {usage}\n\ndata: [DONE]\n\n",
+                    chunk("Hello"),
+                    chunk("!")
+                )
+            )
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        let request_body = CompletionRequestBody::builder(
+            "gpt-3.5-turbo-instruct",
+            "Say hello"
+        ).build();
+        let mut stream = create_completion_stream(&client, &request_body, true).await?;
+        let mut text = String::new();
+        let mut total_tokens = 0;
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            for choice in chunk.choices {
+                text.push_str(&choice.text);
+            }
+            if let Some(usage) = chunk.usage {
+                total_tokens = usage.total_tokens;
+            }
+        }
+
+        mock.assert_async().await;
+        assert_eq!(text, "Hello!");
+        assert_eq!(total_tokens, 7);
+
+        Ok(())
+    }
+}
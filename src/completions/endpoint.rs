@@ -0,0 +1,2 @@
+/// Path of the legacy completions API under the base URL of the client.
+pub const COMPLETIONS_API_PATH: &str = "completions";
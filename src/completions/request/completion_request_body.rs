@@ -0,0 +1,329 @@
+use serde::Serialize;
+use log::warn;
+use super::CompletionPrompt;
+
+const MIN_TEMPERATURE: f32 = 0.0;
+const MAX_TEMPERATURE: f32 = 2.0;
+const MIN_TOP_P: f32 = 0.0;
+const MAX_TOP_P: f32 = 1.0;
+const MIN_PENALTY: f32 = -2.0;
+const MAX_PENALTY: f32 = 2.0;
+const MAX_LOGPROBS: u32 = 5;
+const MAX_NUM_STOP_SEQUENCES: usize = 4;
+
+/// Request body of creating a legacy text completion.
+#[derive(Debug, Serialize)]
+pub struct CompletionRequestBody {
+    model: String,
+    prompt: CompletionPrompt,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suffix: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_tokens: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    n: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    best_of: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    echo: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    logprobs: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    presence_penalty: Option<f32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    frequency_penalty: Option<f32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<String>,
+}
+
+impl CompletionRequestBody {
+    pub fn builder<S: AsRef<str>, P: Into<CompletionPrompt>>(
+        model: S,
+        prompt: P
+    ) -> CompletionRequestBodyBuilder {
+        CompletionRequestBodyBuilder::new(model, prompt)
+    }
+}
+
+pub struct CompletionRequestBodyBuilder {
+    model: String,
+    prompt: CompletionPrompt,
+    suffix: Option<String>,
+    max_tokens: Option<u32>,
+    temperature: Option<f32>,
+    top_p: Option<f32>,
+    n: Option<u32>,
+    best_of: Option<u32>,
+    echo: Option<bool>,
+    logprobs: Option<u32>,
+    stop: Option<Vec<String>>,
+    presence_penalty: Option<f32>,
+    frequency_penalty: Option<f32>,
+    seed: Option<i64>,
+    user: Option<String>,
+}
+
+impl CompletionRequestBodyBuilder {
+    /// Creates a new builder with `None` values for all optional fields.
+    pub fn new<S: AsRef<str>, P: Into<CompletionPrompt>>(model: S, prompt: P) -> Self {
+        Self {
+            model: model.as_ref().to_string(),
+            prompt: prompt.into(),
+            suffix: None,
+            max_tokens: None,
+            temperature: None,
+            top_p: None,
+            n: None,
+            best_of: None,
+            echo: None,
+            logprobs: None,
+            stop: None,
+            presence_penalty: None,
+            frequency_penalty: None,
+            seed: None,
+            user: None,
+        }
+    }
+
+    /// Builds the request body.
+    pub fn build(self) -> CompletionRequestBody {
+        CompletionRequestBody {
+            model: self.model,
+            prompt: self.prompt,
+            suffix: self.suffix,
+            max_tokens: self.max_tokens,
+            temperature: self.temperature,
+            top_p: self.top_p,
+            n: self.n,
+            best_of: self.best_of,
+            echo: self.echo,
+            logprobs: self.logprobs,
+            stop: self.stop,
+            presence_penalty: self.presence_penalty,
+            frequency_penalty: self.frequency_penalty,
+            seed: self.seed,
+            user: self.user,
+        }
+    }
+
+    /// Sets the text that comes after the completion, for inserting text.
+    pub fn suffix<S: AsRef<str>>(mut self, suffix: S) -> Self {
+        self.suffix = Some(suffix.as_ref().to_string());
+        self
+    }
+
+    /// Sets the maximum number of tokens generated in the completion.
+    pub fn max_tokens(mut self, max_tokens: u32) -> Self {
+        self.max_tokens = Some(max_tokens);
+        self
+    }
+
+    /// Sets the sampling temperature.
+    ///
+    /// The input value will be clamped in between 0.0 and 2.0.
+    pub fn temperature(mut self, temperature: f32) -> Self {
+        // Clamp the value to the valid range
+        let temperature = if !(MIN_TEMPERATURE..=MAX_TEMPERATURE).contains(&temperature) {
+            // Clamp the value
+            let t = temperature.clamp(MIN_TEMPERATURE, MAX_TEMPERATURE);
+
+            // Warn the user
+            warn!(
+                "input value of temperature is {temperature} which is out of range, it is now clamped to {t}"
+            );
+
+            t
+        } else {
+            temperature
+        };
+
+        self.temperature = Some(temperature);
+        self
+    }
+
+    /// Sets the nucleus sampling probability mass.
+    ///
+    /// The input value will be clamped in between 0.0 and 1.0.
+    pub fn top_p(mut self, top_p: f32) -> Self {
+        // Clamp the value to the valid range
+        let top_p = if !(MIN_TOP_P..=MAX_TOP_P).contains(&top_p) {
+            // Clamp the value
+            let p = top_p.clamp(MIN_TOP_P, MAX_TOP_P);
+
+            // Warn the user
+            warn!("input value of top_p is {top_p} which is out of range, it is now clamped to {p}");
+
+            p
+        } else {
+            top_p
+        };
+
+        self.top_p = Some(top_p);
+        self
+    }
+
+    /// Sets how many completions to generate for each prompt.
+    ///
+    /// If the input value is 0, then it will be revised to 1.
+    pub fn n(mut self, n: u32) -> Self {
+        // Revise the value to 1 if it is 0
+        let n = if n == 0 {
+            // Warn the user
+            warn!("input value of n is 0, it is now revised to 1");
+            1
+        } else {
+            n
+        };
+
+        self.n = Some(n);
+        self
+    }
+
+    /// Sets how many completions to generate server-side, returning the best `n` of them.
+    ///
+    /// It must be at least `n`, which the API checks.
+    pub fn best_of(mut self, best_of: u32) -> Self {
+        self.best_of = Some(best_of);
+        self
+    }
+
+    /// Sets whether to echo back the prompt in addition to the completion.
+    pub fn echo(mut self, echo: bool) -> Self {
+        self.echo = Some(echo);
+        self
+    }
+
+    /// Sets the number of most likely tokens whose log probabilities are returned at each position.
+    ///
+    /// The input value will be clamped to at most 5.
+    pub fn logprobs(mut self, logprobs: u32) -> Self {
+        // Clamp the value to the valid range
+        let logprobs = if logprobs > MAX_LOGPROBS {
+            // Warn the user
+            warn!(
+                "input value of logprobs is {logprobs} which is out of range, it is now clamped to {MAX_LOGPROBS}"
+            );
+
+            MAX_LOGPROBS
+        } else {
+            logprobs
+        };
+
+        self.logprobs = Some(logprobs);
+        self
+    }
+
+    /// Sets the sequences where the API stops generating tokens.
+    ///
+    /// If there are more than 4 sequences, then only the first 4 will be kept.
+    pub fn stop<S: AsRef<str>>(mut self, stop: &[S]) -> Self {
+        if stop.len() > MAX_NUM_STOP_SEQUENCES {
+            // Warn the user
+            warn!(
+                "too many provided stop sequences, only the first {MAX_NUM_STOP_SEQUENCES} are kept"
+            );
+        }
+
+        self.stop = Some(
+            stop
+                .iter()
+                .take(MAX_NUM_STOP_SEQUENCES)
+                .map(|sequence| sequence.as_ref().to_string())
+                .collect()
+        );
+        self
+    }
+
+    /// Sets the presence penalty.
+    ///
+    /// The input value will be clamped in between -2.0 and 2.0.
+    pub fn presence_penalty(mut self, presence_penalty: f32) -> Self {
+        self.presence_penalty = Some(clamp_penalty("presence_penalty", presence_penalty));
+        self
+    }
+
+    /// Sets the frequency penalty.
+    ///
+    /// The input value will be clamped in between -2.0 and 2.0.
+    pub fn frequency_penalty(mut self, frequency_penalty: f32) -> Self {
+        self.frequency_penalty = Some(clamp_penalty("frequency_penalty", frequency_penalty));
+        self
+    }
+
+    /// Sets the seed for best-effort deterministic sampling.
+    pub fn seed(mut self, seed: i64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets the identifier of the end user, which helps the API detect abuse.
+    pub fn user<S: AsRef<str>>(mut self, user: S) -> Self {
+        self.user = Some(user.as_ref().to_string());
+        self
+    }
+}
+
+fn clamp_penalty(name: &str, penalty: f32) -> f32 {
+    if !(MIN_PENALTY..=MAX_PENALTY).contains(&penalty) {
+        // Clamp the value
+        let p = penalty.clamp(MIN_PENALTY, MAX_PENALTY);
+
+        // Warn the user
+        warn!("input value of {name} is {penalty} which is out of range, it is now clamped to {p}");
+
+        p
+    } else {
+        penalty
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn serialize_completion_request_body() {
+        let request_body = CompletionRequestBody::builder("davinci-002", vec![vec![1212, 318]])
+            .suffix("\n")
+            .echo(true)
+            .best_of(3)
+            .logprobs(10)
+            .stop(&["a", "b", "c", "d", "e"])
+            .build();
+
+        assert_eq!(
+            serde_json::to_value(&request_body).unwrap(),
+            json!({
+                "model": "davinci-002",
+                "prompt": [[1212, 318]],
+                "suffix": "\n",
+                "best_of": 3,
+                "echo": true,
+                "logprobs": 5,
+                "stop": ["a", "b", "c", "d"]
+            })
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod prompt;
+pub use prompt::CompletionPrompt;
+
+mod completion_request_body;
+pub use completion_request_body::{ CompletionRequestBody, CompletionRequestBodyBuilder };
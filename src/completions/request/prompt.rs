@@ -0,0 +1,54 @@
+use serde::Serialize;
+
+/// The prompt to complete, as texts or token arrays.
+///
+/// Multiple prompts are completed independently, with choices for each of them.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum CompletionPrompt {
+    Text(String),
+    Texts(Vec<String>),
+    Tokens(Vec<u32>),
+    TokenArrays(Vec<Vec<u32>>),
+}
+
+impl From<&str> for CompletionPrompt {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<String> for CompletionPrompt {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<Vec<String>> for CompletionPrompt {
+    fn from(texts: Vec<String>) -> Self {
+        Self::Texts(texts)
+    }
+}
+
+impl From<Vec<&str>> for CompletionPrompt {
+    fn from(texts: Vec<&str>) -> Self {
+        Self::Texts(
+            texts
+                .into_iter()
+                .map(|text| text.to_string())
+                .collect()
+        )
+    }
+}
+
+impl From<Vec<u32>> for CompletionPrompt {
+    fn from(tokens: Vec<u32>) -> Self {
+        Self::Tokens(tokens)
+    }
+}
+
+impl From<Vec<Vec<u32>>> for CompletionPrompt {
+    fn from(token_arrays: Vec<Vec<u32>>) -> Self {
+        Self::TokenArrays(token_arrays)
+    }
+}
@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+use crate::chat::{ ChatCompletionFinishReason, ChatCompletionTokenUsage };
+
+/// A legacy text completion, or a chunk of it when streamed.
+#[derive(Debug, Deserialize, Clone)]
+pub struct Completion {
+    pub id: String,
+    pub object: String,
+
+    /// The Unix timestamp in seconds of when the completion was created.
+    pub created: u64,
+
+    pub model: String,
+    pub system_fingerprint: Option<String>,
+    pub choices: Vec<CompletionChoice>,
+
+    /// Token usage of the request, which is absent in the chunks of a stream
+    /// except the last one if usage is included.
+    pub usage: Option<ChatCompletionTokenUsage>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CompletionChoice {
+    pub text: String,
+
+    /// The index of the choice, ordered by the prompts and then the choices of each prompt.
+    pub index: u32,
+
+    pub logprobs: Option<CompletionLogprobs>,
+    pub finish_reason: Option<ChatCompletionFinishReason>,
+}
+
+/// The log probabilities of the tokens of a choice.
+#[derive(Debug, Deserialize, Clone)]
+pub struct CompletionLogprobs {
+    pub tokens: Vec<String>,
+
+    /// The log probability of each token, which is absent for the first token of an echoed prompt.
+    pub token_logprobs: Vec<Option<f64>>,
+
+    /// The most likely tokens with their log probabilities at each position.
+    pub top_logprobs: Option<Vec<Option<HashMap<String, f64>>>>,
+
+    /// The character offset of each token in the text.
+    pub text_offset: Vec<u32>,
+}
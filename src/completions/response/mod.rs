@@ -0,0 +1,5 @@
+mod completion;
+pub use completion::{ Completion, CompletionChoice, CompletionLogprobs };
+
+mod stream;
+pub use stream::CompletionStream;
@@ -0,0 +1,56 @@
+use std::{ pin::Pin, task::{ Context, Poll } };
+use bytes::Bytes;
+use futures::{ Stream, StreamExt };
+use crate::{ Result, Error, CompletionsApiError, sse::DataChunkStream };
+use super::Completion;
+
+pub struct CompletionStream {
+    stream: DataChunkStream,
+}
+
+impl CompletionStream {
+    pub fn new<S: 'static + Stream<Item = reqwest::Result<Bytes>>>(stream: S) -> Self {
+        Self { stream: DataChunkStream::new(stream) }
+    }
+}
+
+impl Stream for CompletionStream {
+    type Item = Result<Completion>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.stream.poll_next_unpin(cx) {
+            Poll::Ready(Some(Ok(data_chunk))) => {
+                // Parse to a completion chunk
+                match serde_json::from_str(&data_chunk) {
+                    Ok(chunk) => Poll::Ready(Some(Ok(chunk))),
+                    Err(error) => {
+                        Poll::Ready(
+                            Some(
+                                Err(
+                                    Error::CompletionsApi(
+                                        CompletionsApiError::ParseToCompletionChunk {
+                                            source: error,
+                                        }
+                                    )
+                                )
+                            )
+                        )
+                    }
+                }
+            }
+            Poll::Ready(Some(Err(error))) => {
+                Poll::Ready(
+                    Some(
+                        Err(
+                            Error::CompletionsApi(CompletionsApiError::ReceiveStreamedBytes {
+                                source: error,
+                            })
+                        )
+                    )
+                )
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
@@ -0,0 +1,373 @@
+use std::{
+    fmt::Display,
+    future::Future,
+    path::Path,
+    sync::{ Arc, RwLock, atomic::{ AtomicBool, Ordering } },
+    time::Duration,
+};
+use log::warn;
+use tokio::task::JoinHandle;
+use crate::{ Result, Error };
+
+/// A source of the API key, which is consulted on every request
+/// so that rotated keys are picked up without rebuilding the client.
+///
+/// Since it is called for each request, getting the key should be cheap,
+/// e.g., by caching it and refreshing the cache when needed.
+pub trait CredentialProvider: Send + Sync {
+    /// Gets the current API key.
+    fn api_key(&self) -> String;
+}
+
+/// Reads a lock even if a thread panicked while holding it,
+/// as the cached key is always in a valid state.
+fn read<T: Clone>(lock: &RwLock<T>) -> T {
+    match lock.read() {
+        Ok(value) => value.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    }
+}
+
+fn write<T>(lock: &RwLock<T>, value: T) {
+    match lock.write() {
+        Ok(mut guard) => {
+            *guard = value;
+        }
+        Err(poisoned) => {
+            *poisoned.into_inner() = value;
+        }
+    }
+}
+
+/// The default interval between two reads of a credential file.
+pub const DEFAULT_FILE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A fixed API key.
+#[derive(Debug, Clone)]
+pub struct StaticCredential {
+    api_key: String,
+}
+
+impl StaticCredential {
+    pub fn new<S: AsRef<str>>(api_key: S) -> Self {
+        Self { api_key: api_key.as_ref().to_string() }
+    }
+}
+
+impl CredentialProvider for StaticCredential {
+    fn api_key(&self) -> String {
+        self.api_key.clone()
+    }
+}
+
+/// An API key read from an environment variable on every request.
+///
+/// The last key read is kept if the variable is removed.
+#[derive(Debug)]
+pub struct EnvCredential {
+    name: String,
+    last_api_key: RwLock<String>,
+
+    /// Whether the user has been warned that the variable is not set.
+    warned: AtomicBool,
+}
+
+impl EnvCredential {
+    /// Creates a provider reading the variable of the given name.
+    /// It fails if the variable is not set.
+    pub fn new<S: AsRef<str>>(name: S) -> Result<Self> {
+        match std::env::var(name.as_ref()) {
+            Ok(api_key) =>
+                Ok(Self {
+                    name: name.as_ref().to_string(),
+                    last_api_key: RwLock::new(api_key),
+                    warned: AtomicBool::new(false),
+                }),
+            Err(_) => Err(Error::ApiKeyNotSet),
+        }
+    }
+}
+
+impl CredentialProvider for EnvCredential {
+    fn api_key(&self) -> String {
+        match std::env::var(&self.name) {
+            Ok(api_key) => {
+                write(&self.last_api_key, api_key.clone());
+                self.warned.store(false, Ordering::Relaxed);
+                api_key
+            }
+            Err(_) => {
+                // Warn the user once until the variable is set again
+                if !self.warned.swap(true, Ordering::Relaxed) {
+                    warn!(
+                        "environment variable {} is not set, the last API key is used",
+                        self.name
+                    );
+                }
+                read(&self.last_api_key)
+            }
+        }
+    }
+}
+
+/// Spawns a task replacing the cached key with the one refreshed every `interval`,
+/// counting the interval from now.
+/// The cached key is kept if the refresh gives no key.
+fn spawn_refresh<F, Fut>(
+    cache: &Arc<RwLock<String>>,
+    interval: Duration,
+    refresh: F
+) -> JoinHandle<()>
+    where F: Fn() -> Fut + Send + 'static, Fut: Future<Output = Option<String>> + Send
+{
+    let cache = Arc::clone(cache);
+    let mut interval = tokio::time::interval(interval);
+    tokio::spawn(async move {
+        // The first tick completes immediately
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+            if let Some(api_key) = refresh().await {
+                write(&cache, api_key);
+            }
+        }
+    })
+}
+
+/// An API key stored in a file, e.g., a mounted secret,
+/// which is read again in the background every check interval.
+///
+/// The file is read in full on each check,
+/// so that a rotation within the same modification time is not missed.
+/// The last key read is kept if the file becomes unreadable.
+/// The background task stops when the provider is dropped.
+#[derive(Debug)]
+pub struct FileCredential {
+    api_key: Arc<RwLock<String>>,
+    refresh_task: JoinHandle<()>,
+}
+
+impl FileCredential {
+    /// Reads the file at the given path once
+    /// and then spawns a task reading it again every `check_interval`,
+    /// e.g., `DEFAULT_FILE_CHECK_INTERVAL`.
+    /// It fails if the first read fails.
+    pub async fn new<P: AsRef<Path>>(path: P, check_interval: Duration) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let api_key = Arc::new(RwLock::new(Self::read_file(&path).await?));
+
+        // Read the file again in the background
+        let refresh_task = spawn_refresh(&api_key, check_interval, move || {
+            let path = path.clone();
+            async move {
+                match Self::read_file(&path).await {
+                    Ok(api_key) => Some(api_key),
+                    Err(error) => {
+                        // Warn the user
+                        warn!("{}, the last API key is used", error);
+                        None
+                    }
+                }
+            }
+        });
+
+        Ok(Self { api_key, refresh_task })
+    }
+
+    async fn read_file(path: &Path) -> Result<String> {
+        match tokio::fs::read_to_string(path).await {
+            Ok(content) => Ok(content.trim().to_string()),
+            Err(error) => {
+                Err(Error::ReadCredentialFile { path: path.to_path_buf(), source: error })
+            }
+        }
+    }
+}
+
+impl CredentialProvider for FileCredential {
+    fn api_key(&self) -> String {
+        read(&self.api_key)
+    }
+}
+
+impl Drop for FileCredential {
+    fn drop(&mut self) {
+        self.refresh_task.abort();
+    }
+}
+
+/// An API key fetched asynchronously, e.g., from a secrets service,
+/// and refreshed in the background at a fixed interval.
+///
+/// The last key fetched is kept if a refresh fails.
+/// The background task stops when the provider is dropped.
+pub struct AsyncFetchCredential {
+    api_key: Arc<RwLock<String>>,
+    refresh_task: JoinHandle<()>,
+}
+
+impl AsyncFetchCredential {
+    /// Fetches the key once and then spawns a task refetching it every `refresh_interval`.
+    /// It fails if the first fetch fails.
+    pub async fn new<F, Fut, E>(fetch: F, refresh_interval: Duration) -> Result<Self>
+        where
+            F: Fn() -> Fut + Send + Sync + 'static,
+            Fut: Future<Output = std::result::Result<String, E>> + Send,
+            E: Display
+    {
+        let api_key = match fetch().await {
+            Ok(api_key) => Arc::new(RwLock::new(api_key)),
+            Err(error) => {
+                return Err(Error::FetchCredential { message: error.to_string() });
+            }
+        };
+
+        // Refresh the key in the background
+        let refresh_task = spawn_refresh(&api_key, refresh_interval, move || {
+            let fetched = fetch();
+            async move {
+                match fetched.await {
+                    Ok(api_key) => Some(api_key),
+                    Err(error) => {
+                        // Warn the user
+                        warn!("failed to refresh the API key, the last one is used: {}", error);
+                        None
+                    }
+                }
+            }
+        });
+
+        Ok(Self { api_key, refresh_task })
+    }
+}
+
+impl CredentialProvider for AsyncFetchCredential {
+    fn api_key(&self) -> String {
+        read(&self.api_key)
+    }
+}
+
+impl Drop for AsyncFetchCredential {
+    fn drop(&mut self) {
+        self.refresh_task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use crate::OpenAIClient;
+    use super::*;
+
+    /// Advances the paused time and lets the background task run.
+    async fn advance(duration: Duration) {
+        tokio::time::advance(duration).await;
+        tokio::task::yield_now().await;
+    }
+
+    /// Waits until the provider gives the expected key,
+    /// as the file is read on a blocking thread.
+    async fn wait_for_key<C: CredentialProvider>(credential: &C, expected: &str) {
+        while credential.api_key() != expected {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_file_credential() -> Result<()> {
+        let path = std::env::temp_dir().join("rustyopenai-test-file-credential");
+        std::fs::write(&path, "sk-first\n").unwrap();
+
+        let credential = FileCredential::new(&path, Duration::from_millis(200)).await?;
+        assert_eq!(credential.api_key(), "sk-first");
+
+        // The file is not read again before the interval
+        std::fs::write(&path, "sk-second\n").unwrap();
+        advance(Duration::from_millis(199)).await;
+        assert_eq!(credential.api_key(), "sk-first");
+
+        // The rotation is picked up even within the same modification time
+        advance(Duration::from_millis(1)).await;
+        wait_for_key(&credential, "sk-second").await;
+
+        // The last key is kept after the file is removed
+        std::fs::remove_file(&path).unwrap();
+        advance(Duration::from_millis(200)).await;
+        assert_eq!(credential.api_key(), "sk-second");
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_async_fetch_credential() -> Result<()> {
+        let num_fetches = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&num_fetches);
+        let credential = AsyncFetchCredential::new(
+            move || {
+                let counter = Arc::clone(&counter);
+                async move {
+                    let n = counter.fetch_add(1, Ordering::SeqCst);
+                    match n {
+                        2 => Err("secrets service unavailable"),
+                        _ => Ok(format!("sk-{}", n)),
+                    }
+                }
+            },
+            Duration::from_millis(200)
+        ).await?;
+        assert_eq!(credential.api_key(), "sk-0");
+
+        // The key is not refreshed before the interval
+        advance(Duration::from_millis(199)).await;
+        assert_eq!(credential.api_key(), "sk-0");
+
+        // The key is refreshed after the interval
+        advance(Duration::from_millis(1)).await;
+        assert_eq!(credential.api_key(), "sk-1");
+
+        // The last key is kept if a refresh fails
+        advance(Duration::from_millis(200)).await;
+        assert_eq!(credential.api_key(), "sk-1");
+        assert_eq!(num_fetches.load(Ordering::SeqCst), 3);
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_client_picks_up_rotated_key() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let first_mock = server
+            .mock("GET", "/models")
+            .match_header("authorization", "Bearer sk-first")
+            .create_async().await;
+        let second_mock = server
+            .mock("GET", "/models")
+            .match_header("authorization", "Bearer sk-second")
+            .create_async().await;
+
+        let path = std::env::temp_dir().join("rustyopenai-test-client-credential");
+        std::fs::write(&path, "sk-first").unwrap();
+
+        let client = OpenAIClient::builder()
+            .credential_provider(FileCredential::new(&path, Duration::from_millis(200)).await?)
+            .base_url(server.url())
+            .build()?;
+        client.get(client.endpoint("models")).send().await?;
+        first_mock.assert_async().await;
+
+        // Rotate the key
+        std::fs::write(&path, "sk-second").unwrap();
+        advance(Duration::from_millis(200)).await;
+        while client.api_key() != "sk-second" {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+        client.get(client.endpoint("models")).send().await?;
+        second_mock.assert_async().await;
+
+        std::fs::remove_file(&path).unwrap();
+
+        Ok(())
+    }
+}
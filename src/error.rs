@@ -1,3 +1,5 @@
+use crate::moderations::{ ModeratedContent, ModerationCategory };
+
 /// The result type of this library.
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -12,6 +14,22 @@ pub enum Error {
         source: reqwest::Error,
     },
 
+    #[error("invalid value of the header {name}: {source}")] InvalidHeaderValue {
+        name: &'static str,
+        #[source]
+        source: reqwest::header::InvalidHeaderValue,
+    },
+
+    #[error("failed to read the API key from {path:?}: {source}")] ReadCredentialFile {
+        path: std::path::PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("failed to fetch the API key: {message}")] FetchCredential {
+        message: String,
+    },
+
     #[error("failed to authenticate with the provided OpenAI API")]
     Authentication,
 
@@ -36,6 +54,11 @@ pub enum Error {
         source: reqwest::Error,
     },
 
+    #[error("the provider responded with status code {status_code}: {message}")] Provider {
+        status_code: reqwest::StatusCode,
+        message: String,
+    },
+
     #[error("the request timed out")]
     Timeout,
 
@@ -47,21 +70,54 @@ pub enum Error {
         source: reqwest::Error,
     },
 
+    #[error("failed to read the file {file_name} to upload: {source}")] ReadUploadFile {
+        file_name: String,
+
+        #[source]
+        source: std::io::Error,
+    },
+
     #[error("failed to request the models API: {0}")] ModelsApi(ModelsApiError),
 
     #[error("failed to request the chat API: {0}")] ChatApi(ChatApiError),
+
+    #[error("failed to request the images API: {0}")] ImagesApi(ImagesApiError),
+
+    #[error("failed to request the audio API: {0}")] AudioApi(AudioApiError),
+
+    #[error("failed to request the moderations API: {0}")] ModerationsApi(ModerationsApiError),
+
+    #[error("failed to request the files API: {0}")] FilesApi(FilesApiError),
+
+    #[error("failed to request the batches API: {0}")] BatchesApi(BatchesApiError),
+
+    #[error("failed to request the fine-tuning API: {0}")] FineTuningApi(FineTuningApiError),
+
+    #[error("failed to request the assistants API: {0}")] AssistantsApi(AssistantsApiError),
+
+    #[error("failed to request the vector stores API: {0}")] VectorStoresApi(VectorStoresApiError),
+
+    #[error("failed to request the responses API: {0}")] ResponsesApi(ResponsesApiError),
+
+    #[error("failed to request the completions API: {0}")] CompletionsApi(CompletionsApiError),
+
+    #[cfg(feature = "realtime")]
+    #[error("failed to communicate with the realtime API: {0}")] RealtimeApi(RealtimeApiError),
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum ModelsApiError {
+    #[deprecated(note = "the model list is parsed at once, failing with `ParseToModelList`")]
     #[error("failed to parse the response to JSON: {source}")] ParseToJson {
         #[source]
         source: reqwest::Error,
     },
 
+    #[deprecated(note = "the model list is parsed at once, failing with `ParseToModelList`")]
     #[error("the received JSON response does not contain the data property")]
     MissingDataProperty,
 
+    #[deprecated(note = "the model list is parsed at once, failing with `ParseToModelList`")]
     #[error(
         "failed to parse the data property of the JSON response to a vector of Models: {source}"
     )] ParseToModels {
@@ -69,12 +125,22 @@ pub enum ModelsApiError {
         source: serde_json::Error,
     },
 
+    #[error("failed to parse the response to a list of models: {source}")] ParseToModelList {
+        #[source]
+        source: reqwest::Error,
+    },
+
     #[error("model with name {0} is not found")] ModelNotFound(String),
 
     #[error("failed to parse JSON response to a Model: {source}")] ParseToModel {
         #[source]
         source: reqwest::Error,
     },
+
+    #[error("failed to parse the response to a deleted model: {source}")] ParseToDeletedModel {
+        #[source]
+        source: reqwest::Error,
+    },
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -109,6 +175,330 @@ pub enum ChatApiError {
         #[source]
         source: serde_json::Error,
     },
+
+    #[error(
+        "max_tokens is {max_tokens}, which exceeds the limit of {limit} output tokens of model {model}"
+    )] MaxTokensExceedsLimit {
+        model: String,
+        max_tokens: u32,
+        limit: u32,
+    },
+
+    #[error("model {0} does not support tools")] ToolsNotSupported(String),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ImagesApiError {
+    #[error("failed to parse the response to images: {source}")] ParseToImages {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("failed to decode the base64-encoded image: {source}")] DecodeBase64 {
+        #[source]
+        source: base64::DecodeError,
+    },
+
+    #[error("failed to download the image: {source}")] DownloadImage {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("failed to write the image to {path:?}: {source}")] WriteImage {
+        path: std::path::PathBuf,
+
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("the image {0} is not a valid PNG file")] NotPng(String),
+
+    #[error("the image {0} is not square")] NotSquare(String),
+
+    #[error("the image {file_name} has {size} bytes, which exceeds the limit of {max_size} bytes")] ImageTooLarge {
+        file_name: String,
+        size: u64,
+        max_size: u64,
+    },
+
+    #[error(
+        "the mask has dimensions {mask_width}x{mask_height}, but the image has dimensions {image_width}x{image_height}"
+    )] MaskDimensionsMismatch {
+        image_width: u32,
+        image_height: u32,
+        mask_width: u32,
+        mask_height: u32,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AudioApiError {
+    #[error("the audio file {0} is not in a supported format")] UnsupportedFileFormat(String),
+
+    #[error(
+        "the audio file {file_name} has {size} bytes, which exceeds the limit of {max_size} bytes"
+    )] FileTooLarge {
+        file_name: String,
+        size: u64,
+        max_size: u64,
+    },
+
+    #[error("failed to receive the response body: {source}")] ReceiveResponseBody {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("failed to parse the response to a transcription: {source}")] ParseToTranscription {
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("failed to parse the subtitle timestamp {0}")] ParseSubtitleTimestamp(String),
+
+    #[error("failed to receive the bytes of the generated audio: {source}")] ReceiveSpeechBytes {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("failed to write the generated audio: {source}")] WriteSpeech {
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ModerationsApiError {
+    #[error("failed to parse the response to a moderation: {source}")] ParseToModeration {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("the {content} is flagged in categories {categories:?}")] ContentFlagged {
+        content: ModeratedContent,
+        categories: Vec<ModerationCategory>,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FilesApiError {
+    #[error(
+        "the file {file_name} has {size} bytes, which exceeds the limit of {max_size} bytes"
+    )] FileTooLarge {
+        file_name: String,
+        size: u64,
+        max_size: u64,
+    },
+
+    #[error("file with ID {0} is not found")] FileNotFound(String),
+
+    #[error("failed to parse the response to a file: {source}")] ParseToFile {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("failed to parse the response to a list of files: {source}")] ParseToFileList {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("failed to parse the response to a deleted file: {source}")] ParseToDeletedFile {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("failed to receive the content of the file: {source}")] ReceiveFileContent {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("failed to write the content of the file: {source}")] WriteFileContent {
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BatchesApiError {
+    #[error("failed to serialize the batch input: {source}")] SerializeBatchInput {
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("batch with ID {0} is not found")] BatchNotFound(String),
+
+    #[error("failed to parse the response to a batch: {source}")] ParseToBatch {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("failed to parse the response to a list of batches: {source}")] ParseToBatchList {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("failed to parse line {line} of the batch output: {source}")] ParseBatchOutputLine {
+        line: usize,
+
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error(
+        "failed to parse the output of request {custom_id} to a chat completion: {source}"
+    )] ParseToChatCompletion {
+        custom_id: String,
+
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FineTuningApiError {
+    #[error("fine-tuning job with ID {0} is not found")] FineTuningJobNotFound(String),
+
+    #[error("failed to parse the response to a fine-tuning job: {source}")] ParseToFineTuningJob {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error(
+        "failed to parse the response to a list of fine-tuning jobs: {source}"
+    )] ParseToFineTuningJobList {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error(
+        "failed to parse the response to a list of fine-tuning events: {source}"
+    )] ParseToFineTuningEventList {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error(
+        "failed to parse the response to a list of fine-tuning checkpoints: {source}"
+    )] ParseToFineTuningCheckpointList {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("failed to read the training file {path:?}: {source}")] ReadTrainingFile {
+        path: std::path::PathBuf,
+
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AssistantsApiError {
+    #[error("object with ID {0} is not found")] ObjectNotFound(String),
+
+    #[error("failed to parse the response to a {object}: {source}")] ParseResponse {
+        object: &'static str,
+
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("failed to receive a chunk of bytes from the API: {source}")] ReceiveStreamedBytes {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("failed to parse the data of the {event} event: {source}")] ParseStreamEvent {
+        event: String,
+
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum VectorStoresApiError {
+    #[error("object with ID {0} is not found")] ObjectNotFound(String),
+
+    #[error("failed to parse the response to a {object}: {source}")] ParseResponse {
+        object: &'static str,
+
+        #[source]
+        source: reqwest::Error,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ResponsesApiError {
+    #[error("response with ID {0} is not found")] ResponseNotFound(String),
+
+    #[error("failed to parse the response to a model response: {source}")] ParseToResponse {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error(
+        "failed to parse the response to a deleted response: {source}"
+    )] ParseToDeletedResponse {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("failed to receive a chunk of bytes from the API: {source}")] ReceiveStreamedBytes {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("failed to parse to a response stream event: {source}")] ParseStreamEvent {
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CompletionsApiError {
+    #[error("failed to parse the response to a completion: {source}")] ParseToCompletion {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("failed to receive a chunk of bytes from the API: {source}")] ReceiveStreamedBytes {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("failed to parse to a completion chunk: {source}")] ParseToCompletionChunk {
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+#[cfg(feature = "realtime")]
+#[derive(Debug, thiserror::Error)]
+pub enum RealtimeApiError {
+    #[error("failed to connect to the realtime API: {source}")] Connect {
+        #[source]
+        source: Box<tokio_tungstenite::tungstenite::Error>,
+    },
+
+    #[error("failed to send a client event: {source}")] SendClientEvent {
+        #[source]
+        source: Box<tokio_tungstenite::tungstenite::Error>,
+    },
+
+    #[error("failed to receive a server event: {source}")] ReceiveServerEvent {
+        #[source]
+        source: Box<tokio_tungstenite::tungstenite::Error>,
+    },
+
+    #[error("failed to serialize a client event: {source}")] SerializeClientEvent {
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("failed to parse to a server event: {source}")] ParseServerEvent {
+        #[source]
+        source: serde_json::Error,
+    },
 }
 
 impl From<reqwest::Error> for Error {
@@ -0,0 +1,94 @@
+use bytes::Bytes;
+use crate::{ Result, Error, FilesApiError, OpenAIClient, utils::encode_path_segment };
+use super::super::{ FILES_API_PATH, FileContentStream };
+
+/// Retrieves the whole content of a file.
+pub async fn retrieve_file_content<S: AsRef<str>>(
+    client: &OpenAIClient,
+    file_id: S
+) -> Result<Bytes> {
+    // Send the request
+    let response = send_file_content_request(client, file_id.as_ref()).await?;
+
+    // Receive all the bytes
+    match response.bytes().await {
+        Ok(bytes) => Ok(bytes),
+        Err(error) => Err(Error::FilesApi(FilesApiError::ReceiveFileContent { source: error })),
+    }
+}
+
+/// Retrieves the content of a file as a stream of bytes,
+/// which is suitable for large files such as batch outputs.
+pub async fn retrieve_file_content_stream<S: AsRef<str>>(
+    client: &OpenAIClient,
+    file_id: S
+) -> Result<FileContentStream> {
+    // Send the request
+    let response = send_file_content_request(client, file_id.as_ref()).await?;
+
+    // Wrap the bytes stream in a FileContentStream
+    Ok(FileContentStream::new(response.bytes_stream()))
+}
+
+async fn send_file_content_request(
+    client: &OpenAIClient,
+    file_id: &str
+) -> Result<reqwest::Response> {
+    match
+        client.send(|client| {
+            client
+                .get(
+                    client.endpoint(
+                        format!("{}/{}/content", FILES_API_PATH, encode_path_segment(file_id))
+                    )
+                )
+        }).await
+    {
+        Ok(response) => Ok(response),
+        Err(Error::UnknownStatusCode { status_code: reqwest::StatusCode::NOT_FOUND, .. }) => {
+            Err(Error::FilesApi(FilesApiError::FileNotFound(file_id.to_string())))
+        }
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_retrieve_file_content() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/files/file-abc123/content")
+            .match_header("authorization", "Bearer sk-test")
+            .with_header("content-type", "application/octet-stream")
+            .with_body("{\"a\":1}\n{\"b\":2}\n")
+            .expect(2)
+            .create_async().await;
+        server.mock("GET", "/files/missing/content").with_status(404).create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // Get the whole content
+        let content = retrieve_file_content(&client, "file-abc123").await?;
+        assert_eq!(content, "{\"a\":1}\n{\"b\":2}\n");
+
+        // Stream the content to a buffer
+        let mut buffer: Vec<u8> = vec![];
+        let num_bytes = retrieve_file_content_stream(&client, "file-abc123").await?
+            .write_to(&mut buffer).await?;
+        assert_eq!(num_bytes, 16);
+        assert_eq!(buffer, content);
+
+        mock.assert_async().await;
+
+        // Get the content of a missing file
+        let result = retrieve_file_content(&client, "missing").await;
+        assert!(matches!(result, Err(Error::FilesApi(FilesApiError::FileNotFound(_)))));
+
+        Ok(())
+    }
+}
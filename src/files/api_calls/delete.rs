@@ -0,0 +1,67 @@
+use crate::{
+    Result,
+    Error,
+    FilesApiError,
+    OpenAIClient,
+    DeletedObject,
+    utils::encode_path_segment,
+};
+use super::super::FILES_API_PATH;
+
+/// Deletes a file.
+pub async fn delete_file<S: AsRef<str>>(
+    client: &OpenAIClient,
+    file_id: S
+) -> Result<DeletedObject> {
+    // Send the request
+    let response = match
+        client.send(|client| {
+            client
+                .delete(
+                    client.endpoint(
+                        format!("{}/{}", FILES_API_PATH, encode_path_segment(file_id.as_ref()))
+                    )
+                )
+        }).await
+    {
+        Ok(response) => response,
+        Err(Error::UnknownStatusCode { status_code: reqwest::StatusCode::NOT_FOUND, .. }) => {
+            return Err(Error::FilesApi(FilesApiError::FileNotFound(file_id.as_ref().to_string())));
+        }
+        Err(error) => {
+            return Err(error);
+        }
+    };
+
+    // Parse the response
+    match response.json::<DeletedObject>().await {
+        Ok(deleted_file) => Ok(deleted_file),
+        Err(error) => Err(Error::FilesApi(FilesApiError::ParseToDeletedFile { source: error })),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_delete_file() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("DELETE", "/files/file-abc123")
+            .match_header("authorization", "Bearer sk-test")
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"id":"file-abc123","object":"file","deleted":true}"#)
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        let deleted_file = delete_file(&client, "file-abc123").await?;
+        mock.assert_async().await;
+        assert!(deleted_file.deleted);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{ Result, Error, FilesApiError, OpenAIClient, ListResponse };
+use super::super::{ FILES_API_PATH, ListFilesQuery, FileObject };
+
+/// Lists a page of files.
+///
+/// Use the `last_id` of the page as the `after` cursor of the query to get the next page.
+pub async fn list_files(
+    client: &OpenAIClient,
+    query: &ListFilesQuery
+) -> Result<ListResponse<FileObject>> {
+    // Send the request
+    let response = client.send(|client| {
+        client.get(client.endpoint(FILES_API_PATH)).query(query)
+    }).await?;
+
+    // Parse the response
+    match response.json::<ListResponse<FileObject>>().await {
+        Ok(response) => Ok(response),
+        Err(error) => Err(Error::FilesApi(FilesApiError::ParseToFileList { source: error })),
+    }
+}
+
+/// Lists all the files matching the query, following the pagination cursor
+/// until there are no more pages.
+pub async fn list_all_files(
+    client: &OpenAIClient,
+    query: &ListFilesQuery
+) -> Result<Vec<FileObject>> {
+    let mut files = vec![];
+    let mut query = query.clone();
+
+    loop {
+        let page = list_files(client, &query).await?;
+        files.extend(page.data);
+
+        // Move to the next page
+        match page.last_id {
+            Some(last_id) if page.has_more => {
+                query = query.with_after(last_id);
+            }
+            _ => {
+                break;
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use serde_json::json;
+    use crate::{ ListOrder, files::* };
+    use super::*;
+
+    fn file_json(id: &str) -> serde_json::Value {
+        json!({
+            "id": id,
+            "object": "file",
+            "bytes": 140,
+            "created_at": 1613779121,
+            "filename": "mydata.jsonl",
+            "purpose": "fine-tune",
+            "status": "processed"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_list_all_files() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let first_page = server
+            .mock("GET", "/files")
+            .match_query(
+                Matcher::AllOf(
+                    vec![
+                        Matcher::UrlEncoded("purpose".to_string(), "fine-tune".to_string()),
+                        Matcher::UrlEncoded("limit".to_string(), "2".to_string()),
+                        Matcher::UrlEncoded("order".to_string(), "asc".to_string())
+                    ]
+                )
+            )
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "object": "list",
+                    "data": [file_json("file-1"), file_json("file-2")],
+                    "first_id": "file-1",
+                    "last_id": "file-2",
+                    "has_more": true
+                }).to_string()
+            )
+            .expect(1)
+            .create_async().await;
+        let second_page = server
+            .mock("GET", "/files")
+            .match_query(Matcher::UrlEncoded("after".to_string(), "file-2".to_string()))
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "object": "list",
+                    "data": [file_json("file-3")],
+                    "first_id": "file-3",
+                    "last_id": "file-3",
+                    "has_more": false
+                }).to_string()
+            )
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // List all the pages
+        let query = ListFilesQuery::builder()
+            .purpose(FilePurpose::FineTune)
+            .limit(2)
+            .order(ListOrder::Asc)
+            .build();
+        let files = list_all_files(&client, &query).await?;
+
+        first_page.assert_async().await;
+        second_page.assert_async().await;
+        assert_eq!(
+            files
+                .iter()
+                .map(|file| file.id.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["file-1", "file-2", "file-3"]
+        );
+        assert_eq!(files[0].status, Some(FileStatus::Processed));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+mod upload;
+pub use upload::upload_file;
+
+mod list;
+pub use list::{ list_files, list_all_files };
+
+mod retrieve;
+pub use retrieve::retrieve_file;
+
+mod content;
+pub use content::{ retrieve_file_content, retrieve_file_content_stream };
+
+mod delete;
+pub use delete::delete_file;
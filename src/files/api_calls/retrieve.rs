@@ -0,0 +1,31 @@
+use crate::{ Result, Error, FilesApiError, OpenAIClient, utils::encode_path_segment };
+use super::super::{ FILES_API_PATH, FileObject };
+
+/// Retrieves the metadata of a file.
+pub async fn retrieve_file<S: AsRef<str>>(client: &OpenAIClient, file_id: S) -> Result<FileObject> {
+    // Send the request
+    let response = match
+        client.send(|client| {
+            client
+                .get(
+                    client.endpoint(
+                        format!("{}/{}", FILES_API_PATH, encode_path_segment(file_id.as_ref()))
+                    )
+                )
+        }).await
+    {
+        Ok(response) => response,
+        Err(Error::UnknownStatusCode { status_code: reqwest::StatusCode::NOT_FOUND, .. }) => {
+            return Err(Error::FilesApi(FilesApiError::FileNotFound(file_id.as_ref().to_string())));
+        }
+        Err(error) => {
+            return Err(error);
+        }
+    };
+
+    // Parse the response
+    match response.json::<FileObject>().await {
+        Ok(file) => Ok(file),
+        Err(error) => Err(Error::FilesApi(FilesApiError::ParseToFile { source: error })),
+    }
+}
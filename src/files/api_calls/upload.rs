@@ -0,0 +1,82 @@
+use crate::{ Result, Error, FilesApiError, OpenAIClient };
+use super::super::{ FILES_API_PATH, FileUploadRequestBody, FileObject };
+
+/// Uploads a file that can be used across various endpoints.
+///
+/// Individual files can be up to 512 MB.
+pub async fn upload_file(
+    client: &OpenAIClient,
+    request_body: FileUploadRequestBody
+) -> Result<FileObject> {
+    // Convert the request body to a multipart form
+    let form = request_body.into_form().await?;
+
+    // Send the request
+    let response = client.send(|client| {
+        client.post(client.endpoint(FILES_API_PATH)).multipart(form)
+    }).await?;
+
+    // Parse the response
+    match response.json::<FileObject>().await {
+        Ok(file) => Ok(file),
+        Err(error) => Err(Error::FilesApi(FilesApiError::ParseToFile { source: error })),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use serde_json::json;
+    use crate::{ UploadFile, files::* };
+    use super::*;
+
+    #[tokio::test]
+    async fn test_upload_file() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/files")
+            .match_header("authorization", "Bearer sk-test")
+            .match_header("content-type", Matcher::Regex("^multipart/form-data".to_string()))
+            .match_body(
+                Matcher::AllOf(
+                    vec![
+                        Matcher::Regex(r#"name="purpose"\r\n\r\nbatch\r\n"#.to_string()),
+                        Matcher::Regex(r#"name="file"; filename="batch.jsonl""#.to_string()),
+                        Matcher::Regex(r#"\{"custom_id":"request-1"\}"#.to_string())
+                    ]
+                )
+            )
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "id": "file-abc123",
+                    "object": "file",
+                    "bytes": 25,
+                    "created_at": 1613677385,
+                    "filename": "batch.jsonl",
+                    "purpose": "batch"
+                }).to_string()
+            )
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // Upload the file
+        let file = upload_file(
+            &client,
+            FileUploadRequestBody::new(
+                UploadFile::from_bytes("batch.jsonl", r#"{"custom_id":"request-1"}"#),
+                FilePurpose::Batch
+            )
+        ).await?;
+
+        mock.assert_async().await;
+        assert_eq!(file.id, "file-abc123");
+        assert_eq!(file.purpose, FilePurpose::Batch);
+        assert_eq!(file.expires_at, None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,2 @@
+/// Path of the files API under the base URL of the client.
+pub const FILES_API_PATH: &str = "files";
@@ -0,0 +1,11 @@
+mod endpoint;
+use endpoint::FILES_API_PATH;
+
+mod request;
+pub use request::*;
+
+mod response;
+pub use response::*;
+
+mod api_calls;
+pub use api_calls::*;
@@ -0,0 +1,102 @@
+use serde::Serialize;
+use log::warn;
+use crate::ListOrder;
+use super::FilePurpose;
+
+const MIN_LIMIT: u32 = 1;
+const MAX_LIMIT: u32 = 10000;
+
+/// Query parameters of listing files.
+#[derive(Debug, Serialize, Clone, Default)]
+pub struct ListFilesQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    purpose: Option<FilePurpose>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order: Option<ListOrder>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<String>,
+}
+
+impl ListFilesQuery {
+    pub fn builder() -> ListFilesQueryBuilder {
+        ListFilesQueryBuilder::new()
+    }
+
+    /// Gets a copy of the query starting after the given file ID.
+    pub(crate) fn with_after<S: AsRef<str>>(&self, after: S) -> Self {
+        Self { after: Some(after.as_ref().to_string()), ..self.clone() }
+    }
+}
+
+#[derive(Default)]
+pub struct ListFilesQueryBuilder {
+    purpose: Option<FilePurpose>,
+    limit: Option<u32>,
+    order: Option<ListOrder>,
+    after: Option<String>,
+}
+
+impl ListFilesQueryBuilder {
+    /// Creates a new builder with `None` values for all fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds the query.
+    pub fn build(self) -> ListFilesQuery {
+        ListFilesQuery {
+            purpose: self.purpose,
+            limit: self.limit,
+            order: self.order,
+            after: self.after,
+        }
+    }
+
+    /// Only lists files with the given purpose.
+    pub fn purpose(mut self, purpose: FilePurpose) -> Self {
+        self.purpose = Some(purpose);
+        self
+    }
+
+    /// Sets the number of files in a page.
+    ///
+    /// The input value will be clamped in between 1 and 10000.
+    pub fn limit(mut self, limit: u32) -> Self {
+        // Clamp the value to the valid range
+        let limit = if !(MIN_LIMIT..=MAX_LIMIT).contains(&limit) {
+            // Clamp the value
+            let l = limit.clamp(MIN_LIMIT, MAX_LIMIT);
+
+            // Warn the user
+            warn!(
+                "input value of limit is {limit} which is out of range, it is now clamped to {l}"
+            );
+
+            l
+        } else {
+            limit
+        };
+
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the sort order by the creation time of the files.
+    pub fn order(mut self, order: ListOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the cursor for pagination, which is the ID of
+    /// the last file in the previous page.
+    pub fn after<S: AsRef<str>>(mut self, after: S) -> Self {
+        self.after = Some(after.as_ref().to_string());
+        self
+    }
+}
+
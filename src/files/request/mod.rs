@@ -0,0 +1,8 @@
+mod purpose;
+pub use purpose::FilePurpose;
+
+mod upload;
+pub use upload::FileUploadRequestBody;
+
+mod list;
+pub use list::{ ListFilesQuery, ListFilesQueryBuilder };
@@ -0,0 +1,35 @@
+use serde::{ Serialize, Deserialize };
+
+/// The intended purpose of a file.
+///
+/// The `*Output` and `*Results` purposes are only assigned by the API
+/// to the files it generates, and cannot be used for uploading.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum FilePurpose {
+    #[serde(rename = "assistants")]
+    Assistants,
+
+    #[serde(rename = "assistants_output")]
+    AssistantsOutput,
+
+    #[serde(rename = "batch")]
+    Batch,
+
+    #[serde(rename = "batch_output")]
+    BatchOutput,
+
+    #[serde(rename = "fine-tune")]
+    FineTune,
+
+    #[serde(rename = "fine-tune-results")]
+    FineTuneResults,
+
+    #[serde(rename = "vision")]
+    Vision,
+
+    #[serde(rename = "user_data")]
+    UserData,
+
+    #[serde(rename = "evals")]
+    Evals,
+}
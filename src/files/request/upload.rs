@@ -0,0 +1,40 @@
+use reqwest::multipart::Form;
+use crate::{ Result, Error, FilesApiError, UploadFile, utils::to_form_text };
+use super::FilePurpose;
+
+/// Maximum size of a file to upload, which is 512 MB.
+const MAX_FILE_SIZE: u64 = 512 * 1024 * 1024;
+
+/// Request body of uploading a file.
+///
+/// It is sent as a multipart form rather than JSON.
+/// Files on disk are streamed to the API without being fully loaded into memory,
+/// so use `UploadFile::from_path` for large files.
+pub struct FileUploadRequestBody {
+    file: UploadFile,
+    purpose: FilePurpose,
+}
+
+impl FileUploadRequestBody {
+    pub fn new(file: UploadFile, purpose: FilePurpose) -> Self {
+        Self { file, purpose }
+    }
+
+    /// Validates the file size, and converts to a multipart form.
+    pub(crate) async fn into_form(self) -> Result<Form> {
+        let file = self.file.prepare(0).await?;
+
+        // Check the file size
+        if file.length > MAX_FILE_SIZE {
+            return Err(
+                Error::FilesApi(FilesApiError::FileTooLarge {
+                    file_name: file.file_name,
+                    size: file.length,
+                    max_size: MAX_FILE_SIZE,
+                })
+            );
+        }
+
+        Ok(Form::new().text("purpose", to_form_text(&self.purpose)).part("file", file.into_part()))
+    }
+}
@@ -0,0 +1,74 @@
+use std::{ path::Path, pin::Pin, task::{ Context, Poll } };
+use bytes::Bytes;
+use futures::{ Stream, StreamExt };
+use tokio::io::{ AsyncWrite, AsyncWriteExt };
+use crate::{ Result, Error, FilesApiError };
+
+/// A stream of the content bytes of a file.
+///
+/// Large files can be written out as they are downloaded,
+/// without buffering the whole content in memory.
+pub struct FileContentStream {
+    stream: Pin<Box<dyn Stream<Item = reqwest::Result<Bytes>> + Send>>,
+}
+
+impl FileContentStream {
+    pub fn new<S: 'static + Stream<Item = reqwest::Result<Bytes>> + Send>(stream: S) -> Self {
+        Self { stream: Box::pin(stream) }
+    }
+
+    /// Writes all the content bytes to the writer as they arrive,
+    /// and returns the number of bytes written.
+    pub async fn write_to<W: AsyncWrite + Unpin>(mut self, writer: &mut W) -> Result<u64> {
+        let mut num_bytes = 0;
+
+        while let Some(bytes) = self.next().await {
+            let bytes = bytes?;
+
+            // Write the chunk
+            if let Err(error) = writer.write_all(&bytes).await {
+                return Err(Error::FilesApi(FilesApiError::WriteFileContent { source: error }));
+            }
+
+            num_bytes += bytes.len() as u64;
+        }
+
+        // Flush the writer
+        if let Err(error) = writer.flush().await {
+            return Err(Error::FilesApi(FilesApiError::WriteFileContent { source: error }));
+        }
+
+        Ok(num_bytes)
+    }
+
+    /// Saves the content to the given file path,
+    /// and returns the number of bytes written.
+    pub async fn save<P: AsRef<Path>>(self, path: P) -> Result<u64> {
+        let mut file = match tokio::fs::File::create(path).await {
+            Ok(file) => file,
+            Err(error) => {
+                return Err(Error::FilesApi(FilesApiError::WriteFileContent { source: error }));
+            }
+        };
+
+        self.write_to(&mut file).await
+    }
+}
+
+impl Stream for FileContentStream {
+    type Item = Result<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.stream.poll_next_unpin(cx) {
+            Poll::Ready(Some(Ok(bytes))) => Poll::Ready(Some(Ok(bytes))),
+            Poll::Ready(Some(Err(error))) => {
+                Poll::Ready(
+                    Some(Err(Error::FilesApi(FilesApiError::ReceiveFileContent { source: error })))
+                )
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
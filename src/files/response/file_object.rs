@@ -0,0 +1,35 @@
+use serde::Deserialize;
+use super::super::FilePurpose;
+
+/// A document uploaded to or generated by the API.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct FileObject {
+    pub id: String,
+    pub object: String,
+
+    /// Size of the file in bytes.
+    pub bytes: u64,
+
+    /// Unix timestamp in seconds of when the file was created.
+    pub created_at: u64,
+
+    /// Unix timestamp in seconds of when the file will expire, if ever.
+    pub expires_at: Option<u64>,
+
+    pub filename: String,
+    pub purpose: FilePurpose,
+
+    /// Deprecated status of the file.
+    pub status: Option<FileStatus>,
+
+    /// Deprecated details of why a fine-tuning training file failed validation.
+    pub status_details: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FileStatus {
+    Uploaded,
+    Processed,
+    Error,
+}
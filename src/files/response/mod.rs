@@ -0,0 +1,5 @@
+mod file_object;
+pub use file_object::{ FileObject, FileStatus };
+
+mod content_stream;
+pub use content_stream::FileContentStream;
@@ -0,0 +1,46 @@
+use crate::{ Result, Error, FineTuningApiError, OpenAIClient, utils::encode_path_segment };
+use super::super::{ FINE_TUNING_JOBS_API_PATH, FineTuningJob };
+
+/// Cancels a running fine-tuning job.
+pub async fn cancel_fine_tuning_job<S: AsRef<str>>(
+    client: &OpenAIClient,
+    job_id: S
+) -> Result<FineTuningJob> {
+    // Send the request
+    let response = match
+        client.send(|client| {
+            client
+                .post(
+                    client.endpoint(
+                        format!(
+                            "{}/{}/cancel",
+                            FINE_TUNING_JOBS_API_PATH,
+                            encode_path_segment(job_id.as_ref())
+                        )
+                    )
+                )
+        }).await
+    {
+        Ok(response) => response,
+        Err(Error::UnknownStatusCode { status_code: reqwest::StatusCode::NOT_FOUND, .. }) => {
+            return Err(
+                Error::FineTuningApi(
+                    FineTuningApiError::FineTuningJobNotFound(
+                        job_id.as_ref().to_string()
+                    )
+                )
+            );
+        }
+        Err(error) => {
+            return Err(error);
+        }
+    };
+
+    // Parse the response
+    match response.json::<FineTuningJob>().await {
+        Ok(job) => Ok(job),
+        Err(error) => {
+            Err(Error::FineTuningApi(FineTuningApiError::ParseToFineTuningJob { source: error }))
+        }
+    }
+}
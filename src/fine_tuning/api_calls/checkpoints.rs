@@ -0,0 +1,60 @@
+use crate::{
+    Result,
+    Error,
+    FineTuningApiError,
+    OpenAIClient,
+    ListResponse,
+    utils::encode_path_segment,
+};
+use super::super::{ FINE_TUNING_JOBS_API_PATH, FineTuningListQuery, FineTuningJobCheckpoint };
+
+/// Lists a page of the checkpoints of a fine-tuning job, from the most recent one.
+pub async fn list_fine_tuning_checkpoints<S: AsRef<str>>(
+    client: &OpenAIClient,
+    job_id: S,
+    query: &FineTuningListQuery
+) -> Result<ListResponse<FineTuningJobCheckpoint>> {
+    // Send the request
+    let response = match
+        client.send(|client| {
+            client
+                .get(
+                    client.endpoint(
+                        format!(
+                            "{}/{}/checkpoints",
+                            FINE_TUNING_JOBS_API_PATH,
+                            encode_path_segment(job_id.as_ref())
+                        )
+                    )
+                )
+                .query(query)
+        }).await
+    {
+        Ok(response) => response,
+        Err(Error::UnknownStatusCode { status_code: reqwest::StatusCode::NOT_FOUND, .. }) => {
+            return Err(
+                Error::FineTuningApi(
+                    FineTuningApiError::FineTuningJobNotFound(
+                        job_id.as_ref().to_string()
+                    )
+                )
+            );
+        }
+        Err(error) => {
+            return Err(error);
+        }
+    };
+
+    // Parse the response
+    match response.json::<ListResponse<FineTuningJobCheckpoint>>().await {
+        Ok(response) => Ok(response),
+        Err(error) => {
+            Err(
+                Error::FineTuningApi(FineTuningApiError::ParseToFineTuningCheckpointList {
+                    source: error,
+                })
+            )
+        }
+    }
+}
+
@@ -0,0 +1,86 @@
+use crate::{ Result, Error, FineTuningApiError, OpenAIClient };
+use super::super::{ FINE_TUNING_JOBS_API_PATH, FineTuningJobRequestBody, FineTuningJob };
+
+/// Creates a fine-tuning job which begins the process of
+/// creating a new model from a given dataset.
+pub async fn create_fine_tuning_job(
+    client: &OpenAIClient,
+    request_body: &FineTuningJobRequestBody
+) -> Result<FineTuningJob> {
+    // Send the request
+    let response = client.send(|client| {
+        client.post(client.endpoint(FINE_TUNING_JOBS_API_PATH)).json(request_body)
+    }).await?;
+
+    // Parse the response
+    match response.json::<FineTuningJob>().await {
+        Ok(job) => Ok(job),
+        Err(error) => {
+            Err(Error::FineTuningApi(FineTuningApiError::ParseToFineTuningJob { source: error }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use serde_json::json;
+    use crate::fine_tuning::*;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_create_fine_tuning_job() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/fine_tuning/jobs")
+            .match_header("authorization", "Bearer sk-test")
+            .match_body(
+                Matcher::Json(
+                    json!({
+                        "model": "gpt-4o-mini",
+                        "training_file": "file-abc123",
+                        "validation_file": "file-def456"
+                    })
+                )
+            )
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "object": "fine_tuning.job",
+                    "id": "ftjob-abc123",
+                    "model": "gpt-4o-mini-2024-07-18",
+                    "created_at": 1721764800,
+                    "fine_tuned_model": null,
+                    "organization_id": "org-123",
+                    "result_files": [],
+                    "status": "queued",
+                    "validation_file": "file-def456",
+                    "training_file": "file-abc123",
+                    "hyperparameters": {
+                        "batch_size": "auto",
+                        "learning_rate_multiplier": "auto",
+                        "n_epochs": "auto"
+                    },
+                    "seed": 683058546
+                }).to_string()
+            )
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // Create the job
+        let request_body = FineTuningJobRequestBody::builder("gpt-4o-mini", "file-abc123")
+            .validation_file("file-def456")
+            .build();
+        let job = create_fine_tuning_job(&client, &request_body).await?;
+
+        mock.assert_async().await;
+        assert_eq!(job.status, FineTuningJobStatus::Queued);
+        assert_eq!(job.hyperparameters.n_epochs, Some(HyperparameterValue::Auto));
+        assert_eq!(job.fine_tuned_model, None);
+
+        Ok(())
+    }
+}
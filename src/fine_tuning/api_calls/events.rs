@@ -0,0 +1,139 @@
+use crate::{
+    Result,
+    Error,
+    FineTuningApiError,
+    OpenAIClient,
+    ListResponse,
+    utils::encode_path_segment,
+};
+use super::super::{ FINE_TUNING_JOBS_API_PATH, FineTuningListQuery, FineTuningJobEvent };
+
+/// Lists a page of the status updates of a fine-tuning job, from the most recent one.
+pub async fn list_fine_tuning_events<S: AsRef<str>>(
+    client: &OpenAIClient,
+    job_id: S,
+    query: &FineTuningListQuery
+) -> Result<ListResponse<FineTuningJobEvent>> {
+    // Send the request
+    let response = match
+        client.send(|client| {
+            client
+                .get(
+                    client.endpoint(
+                        format!(
+                            "{}/{}/events",
+                            FINE_TUNING_JOBS_API_PATH,
+                            encode_path_segment(job_id.as_ref())
+                        )
+                    )
+                )
+                .query(query)
+        }).await
+    {
+        Ok(response) => response,
+        Err(Error::UnknownStatusCode { status_code: reqwest::StatusCode::NOT_FOUND, .. }) => {
+            return Err(
+                Error::FineTuningApi(
+                    FineTuningApiError::FineTuningJobNotFound(
+                        job_id.as_ref().to_string()
+                    )
+                )
+            );
+        }
+        Err(error) => {
+            return Err(error);
+        }
+    };
+
+    // Parse the response
+    match response.json::<ListResponse<FineTuningJobEvent>>().await {
+        Ok(response) => Ok(response),
+        Err(error) => {
+            Err(
+                Error::FineTuningApi(FineTuningApiError::ParseToFineTuningEventList {
+                    source: error,
+                })
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use serde_json::json;
+    use crate::fine_tuning::*;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_list_fine_tuning_events() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/fine_tuning/jobs/ftjob-abc123/events")
+            .match_query(
+                Matcher::AllOf(
+                    vec![
+                        Matcher::UrlEncoded("limit".to_string(), "2".to_string()),
+                        Matcher::UrlEncoded("after".to_string(), "ft-event-0".to_string())
+                    ]
+                )
+            )
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "object": "list",
+                    "data": [
+                        {
+                            "object": "fine_tuning.job.event",
+                            "id": "ft-event-2",
+                            "created_at": 1721764800,
+                            "level": "info",
+                            "message": "Fine tuning job successfully completed",
+                            "data": null,
+                            "type": "message"
+                        },
+                        {
+                            "object": "fine_tuning.job.event",
+                            "id": "ft-event-1",
+                            "created_at": 1721764790,
+                            "level": "info",
+                            "message": "Step 100/100: training loss=0.12",
+                            "data": { "step": 100, "train_loss": 0.12 },
+                            "type": "metrics"
+                        }
+                    ],
+                    "has_more": true
+                }).to_string()
+            )
+            .create_async().await;
+        server
+            .mock("GET", "/fine_tuning/jobs/missing/events")
+            .match_query(Matcher::Any)
+            .with_status(404)
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // List the events
+        let query = FineTuningListQuery::builder().limit(2).after("ft-event-0").build();
+        let events = list_fine_tuning_events(&client, "ftjob-abc123", &query).await?;
+
+        mock.assert_async().await;
+        assert!(events.has_more);
+        assert_eq!(events.data[0].level, FineTuningJobEventLevel::Info);
+        assert_eq!(events.data[1].event_type.as_deref(), Some("metrics"));
+
+        // List the events of a missing job
+        let result = list_fine_tuning_events(&client, "missing", &query).await;
+        assert!(
+            matches!(
+                result,
+                Err(Error::FineTuningApi(FineTuningApiError::FineTuningJobNotFound(_)))
+            )
+        );
+
+        Ok(())
+    }
+}
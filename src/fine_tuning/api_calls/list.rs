@@ -0,0 +1,23 @@
+use crate::{ Result, Error, FineTuningApiError, OpenAIClient, ListResponse };
+use super::super::{ FINE_TUNING_JOBS_API_PATH, FineTuningListQuery, FineTuningJob };
+
+/// Lists a page of the fine-tuning jobs of your organization, from the most recent one.
+pub async fn list_fine_tuning_jobs(
+    client: &OpenAIClient,
+    query: &FineTuningListQuery
+) -> Result<ListResponse<FineTuningJob>> {
+    // Send the request
+    let response = client.send(|client| {
+        client.get(client.endpoint(FINE_TUNING_JOBS_API_PATH)).query(query)
+    }).await?;
+
+    // Parse the response
+    match response.json::<ListResponse<FineTuningJob>>().await {
+        Ok(response) => Ok(response),
+        Err(error) => {
+            Err(
+                Error::FineTuningApi(FineTuningApiError::ParseToFineTuningJobList { source: error })
+            )
+        }
+    }
+}
@@ -0,0 +1,17 @@
+mod create;
+pub use create::create_fine_tuning_job;
+
+mod list;
+pub use list::list_fine_tuning_jobs;
+
+mod retrieve;
+pub use retrieve::retrieve_fine_tuning_job;
+
+mod cancel;
+pub use cancel::cancel_fine_tuning_job;
+
+mod events;
+pub use events::list_fine_tuning_events;
+
+mod checkpoints;
+pub use checkpoints::list_fine_tuning_checkpoints;
@@ -0,0 +1,2 @@
+/// Path of the fine-tuning jobs API under the base URL of the client.
+pub const FINE_TUNING_JOBS_API_PATH: &str = "fine_tuning/jobs";
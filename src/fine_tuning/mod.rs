@@ -0,0 +1,14 @@
+mod endpoint;
+use endpoint::FINE_TUNING_JOBS_API_PATH;
+
+mod request;
+pub use request::*;
+
+mod response;
+pub use response::*;
+
+mod api_calls;
+pub use api_calls::*;
+
+mod validation;
+pub use validation::*;
@@ -0,0 +1,75 @@
+use serde::{ Serialize, Serializer, Deserialize, Deserializer, de::Error };
+
+/// The hyperparameters used for a fine-tuning job.
+///
+/// Each of them is chosen by the API if it is not set or set to `Auto`.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+pub struct Hyperparameters {
+    /// Number of examples in each batch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batch_size: Option<HyperparameterValue<u32>>,
+
+    /// Scaling factor for the learning rate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub learning_rate_multiplier: Option<HyperparameterValue<f64>>,
+
+    /// The number of epochs to train the model for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n_epochs: Option<HyperparameterValue<u32>>,
+}
+
+/// A hyperparameter which is either a specific value,
+/// or `"auto"` to let the API choose it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HyperparameterValue<T> {
+    Auto,
+    Value(T),
+}
+
+impl<T: Serialize> Serialize for HyperparameterValue<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        match self {
+            Self::Auto => serializer.serialize_str("auto"),
+            Self::Value(value) => value.serialize(serializer),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for HyperparameterValue<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum RawValue<T> {
+            Value(T),
+            Text(String),
+        }
+
+        match RawValue::deserialize(deserializer)? {
+            RawValue::Value(value) => Ok(Self::Value(value)),
+            RawValue::Text(text) if text == "auto" => Ok(Self::Auto),
+            RawValue::Text(text) => Err(D::Error::custom(format!("invalid hyperparameter {text}"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_hyperparameters() {
+        let hyperparameters = Hyperparameters {
+            batch_size: Some(HyperparameterValue::Auto),
+            learning_rate_multiplier: Some(HyperparameterValue::Value(0.5)),
+            n_epochs: None,
+        };
+        let json = serde_json::to_string(&hyperparameters).unwrap();
+        assert_eq!(json, r#"{"batch_size":"auto","learning_rate_multiplier":0.5}"#);
+
+        let hyperparameters: Hyperparameters = serde_json
+            ::from_str(r#"{"batch_size":"auto","learning_rate_multiplier":0.5,"n_epochs":3}"#)
+            .unwrap();
+        assert_eq!(hyperparameters.batch_size, Some(HyperparameterValue::Auto));
+        assert_eq!(hyperparameters.n_epochs, Some(HyperparameterValue::Value(3)));
+    }
+}
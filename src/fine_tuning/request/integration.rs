@@ -0,0 +1,78 @@
+use serde::{ Serialize, Deserialize };
+
+/// An integration to enable for a fine-tuning job.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", content = "wandb", rename_all = "snake_case")]
+pub enum FineTuningIntegration {
+    /// Reports the metrics of the job to Weights and Biases.
+    Wandb(WandbIntegration),
+}
+
+/// Settings of the Weights and Biases integration.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct WandbIntegration {
+    pub project: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+}
+
+impl WandbIntegration {
+    pub fn builder<S: AsRef<str>>(project: S) -> WandbIntegrationBuilder {
+        WandbIntegrationBuilder::new(project)
+    }
+}
+
+pub struct WandbIntegrationBuilder {
+    project: String,
+    name: Option<String>,
+    entity: Option<String>,
+    tags: Option<Vec<String>>,
+}
+
+impl WandbIntegrationBuilder {
+    /// Creates a new builder with `None` values for all optional fields.
+    pub fn new<S: AsRef<str>>(project: S) -> Self {
+        Self {
+            project: project.as_ref().to_string(),
+            name: None,
+            entity: None,
+            tags: None,
+        }
+    }
+
+    /// Builds the integration.
+    pub fn build(self) -> FineTuningIntegration {
+        FineTuningIntegration::Wandb(WandbIntegration {
+            project: self.project,
+            name: self.name,
+            entity: self.entity,
+            tags: self.tags,
+        })
+    }
+
+    /// Sets the display name of the run.
+    /// It defaults to the ID of the job.
+    pub fn name<S: AsRef<str>>(mut self, name: S) -> Self {
+        self.name = Some(name.as_ref().to_string());
+        self
+    }
+
+    /// Sets the team or username to create the run under.
+    pub fn entity<S: AsRef<str>>(mut self, entity: S) -> Self {
+        self.entity = Some(entity.as_ref().to_string());
+        self
+    }
+
+    /// Adds a tag to the run.
+    pub fn tag<S: AsRef<str>>(mut self, tag: S) -> Self {
+        self.tags.get_or_insert_with(Vec::new).push(tag.as_ref().to_string());
+        self
+    }
+}
@@ -0,0 +1,129 @@
+use serde::Serialize;
+use super::{ Hyperparameters, FineTuningIntegration };
+
+/// Request body of creating a fine-tuning job.
+#[derive(Debug, Serialize)]
+pub struct FineTuningJobRequestBody {
+    model: String,
+    training_file: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hyperparameters: Option<Hyperparameters>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suffix: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    validation_file: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    integrations: Option<Vec<FineTuningIntegration>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
+}
+
+impl FineTuningJobRequestBody {
+    pub fn builder<S: AsRef<str>, T: AsRef<str>>(
+        model: S,
+        training_file: T
+    ) -> FineTuningJobRequestBodyBuilder {
+        FineTuningJobRequestBodyBuilder::new(model, training_file)
+    }
+}
+
+pub struct FineTuningJobRequestBodyBuilder {
+    model: String,
+    training_file: String,
+    hyperparameters: Option<Hyperparameters>,
+    suffix: Option<String>,
+    validation_file: Option<String>,
+    integrations: Option<Vec<FineTuningIntegration>>,
+    seed: Option<u64>,
+}
+
+impl FineTuningJobRequestBodyBuilder {
+    /// Creates a new builder with `None` values for all optional fields.
+    ///
+    /// The training file is the ID of a file uploaded with the `FineTune` purpose.
+    pub fn new<S: AsRef<str>, T: AsRef<str>>(model: S, training_file: T) -> Self {
+        Self {
+            model: model.as_ref().to_string(),
+            training_file: training_file.as_ref().to_string(),
+            hyperparameters: None,
+            suffix: None,
+            validation_file: None,
+            integrations: None,
+            seed: None,
+        }
+    }
+
+    /// Builds the request body.
+    pub fn build(self) -> FineTuningJobRequestBody {
+        FineTuningJobRequestBody {
+            model: self.model,
+            training_file: self.training_file,
+            hyperparameters: self.hyperparameters,
+            suffix: self.suffix,
+            validation_file: self.validation_file,
+            integrations: self.integrations,
+            seed: self.seed,
+        }
+    }
+
+    /// Sets the hyperparameters used for the job.
+    pub fn hyperparameters(mut self, hyperparameters: Hyperparameters) -> Self {
+        self.hyperparameters = Some(hyperparameters);
+        self
+    }
+
+    /// Sets a string of up to 64 characters that will be added to the fine-tuned model name.
+    pub fn suffix<S: AsRef<str>>(mut self, suffix: S) -> Self {
+        self.suffix = Some(suffix.as_ref().to_string());
+        self
+    }
+
+    /// Sets the ID of an uploaded file used to generate validation metrics periodically.
+    pub fn validation_file<S: AsRef<str>>(mut self, validation_file: S) -> Self {
+        self.validation_file = Some(validation_file.as_ref().to_string());
+        self
+    }
+
+    /// Adds an integration to enable for the job.
+    pub fn integration(mut self, integration: FineTuningIntegration) -> Self {
+        self.integrations.get_or_insert_with(Vec::new).push(integration);
+        self
+    }
+
+    /// Sets the seed to make the job reproducible.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{ HyperparameterValue, WandbIntegration };
+
+    #[test]
+    fn serialize_fine_tuning_job_request_body() {
+        let body = FineTuningJobRequestBody::builder("gpt-4o-mini", "file-abc123")
+            .hyperparameters(Hyperparameters {
+                n_epochs: Some(HyperparameterValue::Value(2)),
+                ..Default::default()
+            })
+            .suffix("custom-model-name")
+            .integration(
+                WandbIntegration::builder("my-wandb-project").tag("first-experiment").build()
+            )
+            .seed(42)
+            .build();
+        let json = serde_json::to_string(&body).unwrap();
+        assert_eq!(
+            json,
+            r#"{"model":"gpt-4o-mini","training_file":"file-abc123","hyperparameters":{"n_epochs":2},"suffix":"custom-model-name","integrations":[{"type":"wandb","wandb":{"project":"my-wandb-project","tags":["first-experiment"]}}],"seed":42}"#
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use serde::Serialize;
+use log::warn;
+
+const MIN_LIMIT: u32 = 1;
+const MAX_LIMIT: u32 = 100;
+
+/// Query parameters of listing fine-tuning jobs, or the events or checkpoints of a job.
+#[derive(Debug, Serialize, Clone, Default)]
+pub struct FineTuningListQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<String>,
+}
+
+impl FineTuningListQuery {
+    pub fn builder() -> FineTuningListQueryBuilder {
+        FineTuningListQueryBuilder::new()
+    }
+}
+
+#[derive(Default)]
+pub struct FineTuningListQueryBuilder {
+    limit: Option<u32>,
+    after: Option<String>,
+}
+
+impl FineTuningListQueryBuilder {
+    /// Creates a new builder with `None` values for all fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds the query.
+    pub fn build(self) -> FineTuningListQuery {
+        FineTuningListQuery { limit: self.limit, after: self.after }
+    }
+
+    /// Sets the number of items in a page.
+    ///
+    /// The input value will be clamped in between 1 and 100.
+    pub fn limit(mut self, limit: u32) -> Self {
+        // Clamp the value to the valid range
+        let limit = if !(MIN_LIMIT..=MAX_LIMIT).contains(&limit) {
+            // Clamp the value
+            let l = limit.clamp(MIN_LIMIT, MAX_LIMIT);
+
+            // Warn the user
+            warn!(
+                "input value of limit is {limit} which is out of range, it is now clamped to {l}"
+            );
+
+            l
+        } else {
+            limit
+        };
+
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the cursor for pagination, which is the ID of
+    /// the last item in the previous page.
+    pub fn after<S: AsRef<str>>(mut self, after: S) -> Self {
+        self.after = Some(after.as_ref().to_string());
+        self
+    }
+}
@@ -0,0 +1,11 @@
+mod job_request_body;
+pub use job_request_body::{ FineTuningJobRequestBody, FineTuningJobRequestBodyBuilder };
+
+mod hyperparameters;
+pub use hyperparameters::{ Hyperparameters, HyperparameterValue };
+
+mod integration;
+pub use integration::{ FineTuningIntegration, WandbIntegration, WandbIntegrationBuilder };
+
+mod list;
+pub use list::{ FineTuningListQuery, FineTuningListQueryBuilder };
@@ -0,0 +1,29 @@
+use serde::Deserialize;
+
+/// A model checkpoint created at the end of an epoch of a fine-tuning job,
+/// which can be used as a fine-tuned model.
+#[derive(Debug, Deserialize, Clone)]
+pub struct FineTuningJobCheckpoint {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+
+    /// Name of the fine-tuned model of the checkpoint.
+    pub fine_tuned_model_checkpoint: String,
+
+    pub step_number: u32,
+    pub metrics: FineTuningCheckpointMetrics,
+    pub fine_tuning_job_id: String,
+}
+
+/// Metrics at the step of a checkpoint.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+pub struct FineTuningCheckpointMetrics {
+    pub step: Option<f64>,
+    pub train_loss: Option<f64>,
+    pub train_mean_token_accuracy: Option<f64>,
+    pub valid_loss: Option<f64>,
+    pub valid_mean_token_accuracy: Option<f64>,
+    pub full_valid_loss: Option<f64>,
+    pub full_valid_mean_token_accuracy: Option<f64>,
+}
@@ -0,0 +1,26 @@
+use serde::Deserialize;
+
+/// An event of a fine-tuning job, such as a status change or training metrics.
+#[derive(Debug, Deserialize, Clone)]
+pub struct FineTuningJobEvent {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+    pub level: FineTuningJobEventLevel,
+    pub message: String,
+
+    /// Data of the metrics event, such as the step and the training loss.
+    pub data: Option<serde_json::Value>,
+
+    /// The type of event, e.g., `message` or `metrics`.
+    #[serde(rename = "type")]
+    pub event_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FineTuningJobEventLevel {
+    Info,
+    Warn,
+    Error,
+}
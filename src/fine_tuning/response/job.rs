@@ -0,0 +1,69 @@
+use serde::Deserialize;
+use super::super::{ Hyperparameters, FineTuningIntegration };
+
+/// A fine-tuning job, and its progress.
+///
+/// The timestamps are Unix timestamps in seconds.
+#[derive(Debug, Deserialize, Clone)]
+pub struct FineTuningJob {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+
+    /// Why the job failed, if it did.
+    pub error: Option<FineTuningJobError>,
+
+    /// Name of the fine-tuned model, which is only present once the job succeeded.
+    pub fine_tuned_model: Option<String>,
+
+    pub finished_at: Option<u64>,
+    pub hyperparameters: Hyperparameters,
+
+    /// The base model being fine-tuned.
+    pub model: String,
+
+    pub organization_id: String,
+
+    /// IDs of the files containing the results of the job.
+    pub result_files: Vec<String>,
+
+    pub status: FineTuningJobStatus,
+
+    /// The total number of billable tokens processed by the job.
+    pub trained_tokens: Option<u64>,
+
+    pub training_file: String,
+    pub validation_file: Option<String>,
+    pub integrations: Option<Vec<FineTuningIntegration>>,
+    pub seed: Option<u64>,
+
+    /// When the job is estimated to finish.
+    pub estimated_finish: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FineTuningJobStatus {
+    ValidatingFiles,
+    Queued,
+    Running,
+    Succeeded,
+    Failed,
+    Cancelled,
+}
+
+impl FineTuningJobStatus {
+    /// Whether the job will no longer change.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Succeeded | Self::Failed | Self::Cancelled)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct FineTuningJobError {
+    pub code: String,
+    pub message: String,
+
+    /// The parameter that was invalid, e.g., `training_file`.
+    pub param: Option<String>,
+}
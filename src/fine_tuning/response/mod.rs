@@ -0,0 +1,8 @@
+mod job;
+pub use job::{ FineTuningJob, FineTuningJobStatus, FineTuningJobError };
+
+mod event;
+pub use event::{ FineTuningJobEvent, FineTuningJobEventLevel };
+
+mod checkpoint;
+pub use checkpoint::{ FineTuningJobCheckpoint, FineTuningCheckpointMetrics };
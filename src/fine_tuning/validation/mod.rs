@@ -0,0 +1,5 @@
+mod validator;
+pub use validator::{ TrainingFileValidator, TrainingFileValidatorBuilder };
+
+mod report;
+pub use report::{ TrainingFileReport, TrainingFileError, TrainingFileErrorKind, TokenStats };
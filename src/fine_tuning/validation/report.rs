@@ -0,0 +1,132 @@
+/// The result of validating a chat fine-tuning JSONL file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrainingFileReport {
+    /// The number of non-empty lines, i.e., training examples.
+    pub num_examples: usize,
+
+    /// Errors found in the examples, in the order of the lines.
+    pub errors: Vec<TrainingFileError>,
+
+    /// Token counts of the examples without format errors.
+    /// It is `None` if there are no such examples.
+    pub token_stats: Option<TokenStats>,
+}
+
+impl TrainingFileReport {
+    /// Whether the file can be uploaded for fine-tuning.
+    pub fn is_valid(&self) -> bool {
+        self.num_examples > 0 && self.errors.is_empty()
+    }
+}
+
+/// An error found in a line of the training file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrainingFileError {
+    /// The line number starting from 1.
+    pub line: usize,
+
+    pub kind: TrainingFileErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum TrainingFileErrorKind {
+    #[error("the line is not valid JSON: {0}")] InvalidJson(String),
+
+    #[error("the example has no messages")]
+    MissingMessages,
+
+    #[error("message {index} is not an object")] InvalidMessage {
+        index: usize,
+    },
+
+    #[error("message {index} has an unrecognized key {key}")] UnrecognizedKey {
+        index: usize,
+        key: String,
+    },
+
+    #[error("message {index} has no role")] MissingRole {
+        index: usize,
+    },
+
+    #[error("message {index} has an unrecognized role {role}")] UnrecognizedRole {
+        index: usize,
+        role: String,
+    },
+
+    #[error("message {index} has no content")] MissingContent {
+        index: usize,
+    },
+
+    #[error(
+        "message {index} has content which is neither a string nor a list of parts"
+    )] InvalidContent {
+        index: usize,
+    },
+
+    #[error("message {index} has a weight other than 0 or 1")] InvalidWeight {
+        index: usize,
+    },
+
+    #[error(
+        "message {index} has a weight, which is only allowed in assistant messages"
+    )] UnexpectedWeight {
+        index: usize,
+    },
+
+    #[error("message {index} has an invalid tool call: {reason}")] InvalidToolCall {
+        index: usize,
+        reason: String,
+    },
+
+    #[error("tool message {index} has no tool_call_id")] MissingToolCallId {
+        index: usize,
+    },
+
+    #[error("the example has no assistant message")]
+    MissingAssistantMessage,
+
+    #[error(
+        "the example has {tokens} tokens, which exceeds the limit of {limit} tokens"
+    )] ExampleTooLong {
+        tokens: usize,
+        limit: usize,
+    },
+}
+
+/// Statistics of the token counts of the examples.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TokenStats {
+    pub min: usize,
+    pub max: usize,
+    pub mean: f64,
+    pub median: usize,
+
+    /// The total number of tokens of all the examples.
+    pub total: usize,
+
+    /// The number of tokens in the assistant messages that are trained on,
+    /// i.e., without a weight of 0.
+    pub trained: usize,
+}
+
+impl TokenStats {
+    /// Calculates the statistics from the token counts of the examples
+    /// and the number of trained tokens.
+    pub(crate) fn new(mut counts: Vec<usize>, trained: usize) -> Option<Self> {
+        if counts.is_empty() {
+            return None;
+        }
+
+        counts.sort_unstable();
+        let total = counts.iter().sum::<usize>();
+
+        Some(Self {
+            min: counts[0],
+            max: counts[counts.len() - 1],
+            mean: (total as f64) / (counts.len() as f64),
+            median: counts[counts.len() / 2],
+            total,
+            trained,
+        })
+    }
+}
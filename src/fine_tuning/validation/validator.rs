@@ -0,0 +1,427 @@
+use std::path::Path;
+use serde_json::{ Map, Value };
+use crate::{ Result, Error, FineTuningApiError };
+use super::{ TrainingFileReport, TrainingFileError, TrainingFileErrorKind, TokenStats };
+
+/// The default maximum number of tokens in an example.
+const DEFAULT_MAX_TOKENS_PER_EXAMPLE: usize = 65536;
+
+/// Tokens added for each message and for the whole example by the chat format.
+const TOKENS_PER_MESSAGE: usize = 3;
+const TOKENS_PER_EXAMPLE: usize = 3;
+
+const RECOGNIZED_ROLES: [&str; 6] = [
+    "system",
+    "developer",
+    "user",
+    "assistant",
+    "tool",
+    "function",
+];
+const RECOGNIZED_KEYS: [&str; 8] = [
+    "role",
+    "content",
+    "name",
+    "weight",
+    "tool_calls",
+    "tool_call_id",
+    "function_call",
+    "refusal",
+];
+
+/// A counter of the tokens in a piece of text.
+type TokenCounter = Box<dyn Fn(&str) -> usize + Send + Sync>;
+
+/// A local validator of chat fine-tuning JSONL files,
+/// which finds format errors and counts tokens before uploading.
+///
+/// Without a custom token counter, tokens are estimated at
+/// four characters per token, which is close for English text.
+pub struct TrainingFileValidator {
+    max_tokens_per_example: usize,
+    token_counter: TokenCounter,
+}
+
+impl TrainingFileValidator {
+    pub fn builder() -> TrainingFileValidatorBuilder {
+        TrainingFileValidatorBuilder::new()
+    }
+
+    /// Validates the content of a training file.
+    pub fn validate(&self, content: &str) -> TrainingFileReport {
+        let mut num_examples = 0;
+        let mut errors = vec![];
+        let mut token_counts = vec![];
+        let mut trained_tokens = 0;
+
+        for (index, line) in content.lines().enumerate() {
+            // Skip empty lines
+            if line.trim().is_empty() {
+                continue;
+            }
+            num_examples += 1;
+
+            match self.validate_example(line) {
+                Ok((tokens, trained)) => {
+                    token_counts.push(tokens);
+                    trained_tokens += trained;
+                }
+                Err(kinds) => {
+                    errors.extend(
+                        kinds.into_iter().map(|kind| TrainingFileError { line: index + 1, kind })
+                    );
+                }
+            }
+        }
+
+        TrainingFileReport {
+            num_examples,
+            errors,
+            token_stats: TokenStats::new(token_counts, trained_tokens),
+        }
+    }
+
+    /// Reads and validates a training file.
+    pub async fn validate_file<P: AsRef<Path>>(&self, path: P) -> Result<TrainingFileReport> {
+        match tokio::fs::read_to_string(path.as_ref()).await {
+            Ok(content) => Ok(self.validate(&content)),
+            Err(error) => {
+                Err(
+                    Error::FineTuningApi(FineTuningApiError::ReadTrainingFile {
+                        path: path.as_ref().to_path_buf(),
+                        source: error,
+                    })
+                )
+            }
+        }
+    }
+
+    /// Validates an example, and returns the number of tokens in it
+    /// and the number of trained tokens if it is valid.
+    fn validate_example(
+        &self,
+        line: &str
+    ) -> std::result::Result<(usize, usize), Vec<TrainingFileErrorKind>> {
+        // Parse the line
+        let example = match serde_json::from_str::<Value>(line) {
+            Ok(example) => example,
+            Err(error) => {
+                return Err(vec![TrainingFileErrorKind::InvalidJson(error.to_string())]);
+            }
+        };
+
+        // Get the messages
+        let messages = match example.get("messages").and_then(|messages| messages.as_array()) {
+            Some(messages) if !messages.is_empty() => messages,
+            _ => {
+                return Err(vec![TrainingFileErrorKind::MissingMessages]);
+            }
+        };
+
+        let mut errors = vec![];
+        let mut tokens = TOKENS_PER_EXAMPLE;
+        let mut trained_tokens = 0;
+        let mut has_assistant_message = false;
+
+        for (index, message) in messages.iter().enumerate() {
+            let message = match message.as_object() {
+                Some(message) => message,
+                None => {
+                    errors.push(TrainingFileErrorKind::InvalidMessage { index });
+                    continue;
+                }
+            };
+
+            let role = validate_message(index, message, &mut errors);
+            let message_tokens = self.count_message_tokens(message);
+            tokens += message_tokens;
+
+            if role == Some("assistant") {
+                has_assistant_message = true;
+
+                // Assistant messages are trained on unless their weight is 0
+                if message.get("weight").and_then(|weight| weight.as_u64()) != Some(0) {
+                    trained_tokens += message_tokens;
+                }
+            }
+        }
+
+        if !has_assistant_message {
+            errors.push(TrainingFileErrorKind::MissingAssistantMessage);
+        }
+
+        if tokens > self.max_tokens_per_example {
+            errors.push(TrainingFileErrorKind::ExampleTooLong {
+                tokens,
+                limit: self.max_tokens_per_example,
+            });
+        }
+
+        if errors.is_empty() {
+            Ok((tokens, trained_tokens))
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Counts the tokens of a message including its content, name and tool calls.
+    fn count_message_tokens(&self, message: &Map<String, Value>) -> usize {
+        let mut tokens = TOKENS_PER_MESSAGE;
+
+        for (key, value) in message.iter() {
+            match (key.as_str(), value) {
+                ("content" | "name" | "refusal", Value::String(text)) => {
+                    tokens += (self.token_counter)(text);
+                }
+                ("content", Value::Array(parts)) => {
+                    // Only count the text parts
+                    for part in parts.iter() {
+                        if let Some(text) = part.get("text").and_then(|text| text.as_str()) {
+                            tokens += (self.token_counter)(text);
+                        }
+                    }
+                }
+                ("tool_calls" | "function_call", value) => {
+                    tokens += (self.token_counter)(&value.to_string());
+                }
+                _ => {}
+            }
+        }
+
+        tokens
+    }
+}
+
+impl Default for TrainingFileValidator {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// Validates a message, and returns its role if it is recognized.
+fn validate_message<'a>(
+    index: usize,
+    message: &'a Map<String, Value>,
+    errors: &mut Vec<TrainingFileErrorKind>
+) -> Option<&'a str> {
+    // Check the keys
+    for key in message.keys() {
+        if !RECOGNIZED_KEYS.contains(&key.as_str()) {
+            errors.push(TrainingFileErrorKind::UnrecognizedKey { index, key: key.clone() });
+        }
+    }
+
+    // Check the role
+    let role = match message.get("role").and_then(|role| role.as_str()) {
+        Some(role) if RECOGNIZED_ROLES.contains(&role) => Some(role),
+        Some(role) => {
+            errors.push(TrainingFileErrorKind::UnrecognizedRole { index, role: role.to_string() });
+            None
+        }
+        None => {
+            errors.push(TrainingFileErrorKind::MissingRole { index });
+            None
+        }
+    };
+
+    // Check the content
+    let has_calls = message.contains_key("tool_calls") || message.contains_key("function_call");
+    match message.get("content") {
+        Some(Value::String(_)) | Some(Value::Array(_)) => {}
+        None | Some(Value::Null) if role == Some("assistant") && has_calls => {}
+        None | Some(Value::Null) => errors.push(TrainingFileErrorKind::MissingContent { index }),
+        Some(_) => errors.push(TrainingFileErrorKind::InvalidContent { index }),
+    }
+
+    // Check the weight
+    if let Some(weight) = message.get("weight") {
+        if role != Some("assistant") {
+            errors.push(TrainingFileErrorKind::UnexpectedWeight { index });
+        } else if !matches!(weight.as_u64(), Some(0 | 1)) {
+            errors.push(TrainingFileErrorKind::InvalidWeight { index });
+        }
+    }
+
+    // Check the tool calls
+    if let Some(tool_calls) = message.get("tool_calls") {
+        if role != Some("assistant") {
+            errors.push(TrainingFileErrorKind::InvalidToolCall {
+                index,
+                reason: "only assistant messages can have tool calls".to_string(),
+            });
+        } else if let Err(reason) = validate_tool_calls(tool_calls) {
+            errors.push(TrainingFileErrorKind::InvalidToolCall { index, reason });
+        }
+    }
+
+    // Tool messages must refer to a tool call
+    if role == Some("tool") && !message.get("tool_call_id").is_some_and(|id| id.is_string()) {
+        errors.push(TrainingFileErrorKind::MissingToolCallId { index });
+    }
+
+    role
+}
+
+/// Checks that the tool calls are a list of function calls with JSON arguments.
+fn validate_tool_calls(tool_calls: &Value) -> std::result::Result<(), String> {
+    let tool_calls = match tool_calls.as_array() {
+        Some(tool_calls) => tool_calls,
+        None => {
+            return Err("tool_calls is not a list".to_string());
+        }
+    };
+
+    for tool_call in tool_calls.iter() {
+        if !tool_call.get("id").is_some_and(|id| id.is_string()) {
+            return Err("a tool call has no id".to_string());
+        }
+        if tool_call.get("type").and_then(|tool_type| tool_type.as_str()) != Some("function") {
+            return Err("a tool call is not of type function".to_string());
+        }
+
+        let function = tool_call.get("function").cloned().unwrap_or_default();
+        if !function["name"].is_string() {
+            return Err("a tool call has no function name".to_string());
+        }
+        match function["arguments"].as_str() {
+            Some(arguments) if serde_json::from_str::<Value>(arguments).is_ok() => {}
+            _ => {
+                return Err("a tool call has no arguments in JSON".to_string());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Estimates the number of tokens in the text.
+fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+pub struct TrainingFileValidatorBuilder {
+    max_tokens_per_example: usize,
+    token_counter: TokenCounter,
+}
+
+impl TrainingFileValidatorBuilder {
+    /// Creates a new builder with the default limit of 65536 tokens per example
+    /// and the estimated token counter.
+    pub fn new() -> Self {
+        Self {
+            max_tokens_per_example: DEFAULT_MAX_TOKENS_PER_EXAMPLE,
+            token_counter: Box::new(estimate_tokens),
+        }
+    }
+
+    /// Builds the validator.
+    pub fn build(self) -> TrainingFileValidator {
+        TrainingFileValidator {
+            max_tokens_per_example: self.max_tokens_per_example,
+            token_counter: self.token_counter,
+        }
+    }
+
+    /// Sets the maximum number of tokens in an example,
+    /// which depends on the model to fine-tune.
+    pub fn max_tokens_per_example(mut self, max_tokens_per_example: usize) -> Self {
+        self.max_tokens_per_example = max_tokens_per_example;
+        self
+    }
+
+    /// Sets the function that counts the tokens in a piece of text,
+    /// e.g., one backed by the tokenizer of the model to fine-tune.
+    pub fn token_counter<F>(mut self, token_counter: F) -> Self
+        where F: Fn(&str) -> usize + Send + Sync + 'static
+    {
+        self.token_counter = Box::new(token_counter);
+        self
+    }
+}
+
+impl Default for TrainingFileValidatorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_training_file() {
+        let content = [
+            // A valid example
+            r#"{"messages": [{"role": "system", "content": "Marv is sarcastic."}, {"role": "user", "content": "Hi"}, {"role": "assistant", "content": "Oh, hello.", "weight": 1}]}"#,
+            "",
+            // A valid example with tool calls
+            r#"{"messages": [{"role": "user", "content": "Weather?"}, {"role": "assistant", "tool_calls": [{"id": "call_1", "type": "function", "function": {"name": "get_weather", "arguments": "{\"city\": \"Paris\"}"}}]}, {"role": "tool", "tool_call_id": "call_1", "content": "Sunny"}, {"role": "assistant", "content": "Sunny.", "weight": 0}]}"#,
+            // Invalid JSON
+            r#"{"messages": "#,
+            // Format errors
+            r#"{"messages": [{"role": "human", "content": "Hi"}, {"role": "user", "weight": 2, "foo": 1}]}"#,
+            // Invalid tool call
+            r#"{"messages": [{"role": "user", "content": "Hi"}, {"role": "assistant", "tool_calls": [{"id": "call_1", "type": "function", "function": {"name": "f", "arguments": "{"}}]}]}"#,
+        ].join("\n");
+
+        // Count one token per word
+        let validator = TrainingFileValidator::builder()
+            .token_counter(|text| text.split_whitespace().count())
+            .build();
+        let report = validator.validate(&content);
+
+        assert_eq!(report.num_examples, 5);
+        assert!(!report.is_valid());
+        assert!(
+            matches!(
+                report.errors[0],
+                TrainingFileError { line: 4, kind: TrainingFileErrorKind::InvalidJson(_) }
+            )
+        );
+        assert_eq!(
+            report.errors[1..]
+                .iter()
+                .map(|error| (error.line, error.kind.clone()))
+                .collect::<Vec<(usize, TrainingFileErrorKind)>>(),
+            vec![
+                (
+                    5,
+                    TrainingFileErrorKind::UnrecognizedRole { index: 0, role: "human".to_string() },
+                ),
+                (5, TrainingFileErrorKind::UnrecognizedKey { index: 1, key: "foo".to_string() }),
+                (5, TrainingFileErrorKind::MissingContent { index: 1 }),
+                (5, TrainingFileErrorKind::UnexpectedWeight { index: 1 }),
+                (5, TrainingFileErrorKind::MissingAssistantMessage),
+                (
+                    6,
+                    TrainingFileErrorKind::InvalidToolCall {
+                        index: 1,
+                        reason: "a tool call has no arguments in JSON".to_string(),
+                    },
+                )
+            ]
+        );
+
+        // Token stats of the two valid examples
+        let stats = report.token_stats.unwrap();
+        assert_eq!(stats.min, 3 + (3 + 3) + (3 + 1) + (3 + 2));
+        assert_eq!(stats.total, stats.min + stats.max);
+        assert_eq!(stats.max, 3 + (3 + 1) + (3 + 2) + (3 + 1) + (3 + 1));
+        assert_eq!(stats.trained, (3 + 2) + (3 + 2));
+    }
+
+    #[test]
+    fn limit_tokens_per_example() {
+        let validator = TrainingFileValidator::builder().max_tokens_per_example(10).build();
+        let report = validator.validate(
+            r#"{"messages": [{"role": "user", "content": "Hi"}, {"role": "assistant", "content": "Hello there, how can I help?"}]}"#
+        );
+
+        assert_eq!(report.errors, vec![TrainingFileError {
+            line: 1,
+            kind: TrainingFileErrorKind::ExampleTooLong { tokens: 17, limit: 10 },
+        }]);
+        assert_eq!(report.token_stats, None);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{ Result, Error, ImagesApiError, OpenAIClient };
+use super::super::{ IMAGE_EDITS_API_PATH, ImageEditRequestBody, Image, ImagesResponse };
+
+/// Creates edited or extended images given an original image and a prompt.
+///
+/// The image and mask are validated before uploading.
+pub async fn edit_images(
+    client: &OpenAIClient,
+    request_body: ImageEditRequestBody
+) -> Result<Vec<Image>> {
+    // Convert the request body to a multipart form
+    let form = request_body.into_form().await?;
+
+    // Send the request
+    let response = client.send(|client| {
+        client.post(client.endpoint(IMAGE_EDITS_API_PATH)).multipart(form)
+    }).await?;
+
+    // Parse the response
+    let response = match response.json::<ImagesResponse>().await {
+        Ok(response) => response,
+        Err(error) => {
+            return Err(Error::ImagesApi(ImagesApiError::ParseToImages { source: error }));
+        }
+    };
+
+    Ok(response.data)
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use serde_json::json;
+    use crate::{ UploadFile, images::*, images::request::png::png_header };
+    use super::*;
+
+    #[tokio::test]
+    async fn test_edit_images() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/images/edits")
+            .match_header("authorization", "Bearer sk-test")
+            .match_header("content-type", Matcher::Regex("^multipart/form-data".to_string()))
+            .match_body(
+                Matcher::AllOf(
+                    vec![
+                        Matcher::Regex(r#"name="image"; filename="otter.png""#.to_string()),
+                        Matcher::Regex(r#"name="mask"; filename="mask.png""#.to_string()),
+                        Matcher::Regex(r#"name="prompt"\r\n\r\nAdd a hat"#.to_string()),
+                        Matcher::Regex(r#"name="response_format"\r\n\r\nb64_json"#.to_string())
+                    ]
+                )
+            )
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "created": 1589478378,
+                    "data": [{ "b64_json": "aGVsbG8=" }]
+                }).to_string()
+            )
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // Build the request body
+        let request_body = ImageEditRequestBody::builder(
+            UploadFile::from_bytes("otter.png", png_header(256, 256)),
+            "Add a hat"
+        )
+            .mask(UploadFile::from_bytes("mask.png", png_header(256, 256)))
+            .response_format(ImageResponseFormat::Base64)
+            .build();
+
+        // Send the request
+        let images = edit_images(&client, request_body).await?;
+        mock.assert_async().await;
+
+        assert_eq!(
+            images,
+            vec![Image { revised_prompt: None, image: ImageContent::Base64("aGVsbG8=".to_string()) }]
+        );
+
+        Ok(())
+    }
+}
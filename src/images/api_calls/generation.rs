@@ -1,18 +1,87 @@
-use lazy_static::lazy_static;
-use crate::{ Result, Error, OpenAIClient };
-use super::super::{ IMAGES_API_ENDPOINT, ImageGenerationRequestBody };
-
-lazy_static! {
-    static ref GENERATE_IMAGE_API_ENDPOINT: String = format!(
-        "{}/{}",
-        IMAGES_API_ENDPOINT,
-        "generations"
-    );
-}
+use crate::{ Result, Error, ImagesApiError, OpenAIClient };
+use super::super::{ IMAGE_GENERATIONS_API_PATH, ImageGenerationRequestBody, Image, ImagesResponse };
 
+/// Creates images given a prompt.
 pub async fn generate_images(
     client: &OpenAIClient,
-    request_body: ImageGenerationRequestBody
-) -> Result<()> {
-    todo!()
+    request_body: &ImageGenerationRequestBody
+) -> Result<Vec<Image>> {
+    // Send the request
+    let response = client.send(|client| {
+        client
+            .post(client.endpoint(IMAGE_GENERATIONS_API_PATH))
+            .json(request_body)
+    }).await?;
+
+    // Parse the response
+    let response = match response.json::<ImagesResponse>().await {
+        Ok(response) => response,
+        Err(error) => {
+            return Err(Error::ImagesApi(ImagesApiError::ParseToImages { source: error }));
+        }
+    };
+
+    Ok(response.data)
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use serde_json::json;
+    use crate::images::*;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_generate_images() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/images/generations")
+            .match_header("authorization", "Bearer sk-test")
+            .match_body(
+                Matcher::Json(
+                    json!({
+                        "prompt": "A cute baby sea otter",
+                        "model": "dall-e-3",
+                        "size": "1024x1024"
+                    })
+                )
+            )
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "created": 1589478378,
+                    "data": [
+                        {
+                            "revised_prompt": "A fluffy baby sea otter.",
+                            "url": "https://example.com/otter.png"
+                        }
+                    ]
+                }).to_string()
+            )
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // Build the request body
+        let request_body = ImageGenerationRequestBody::builder("A cute baby sea otter")
+            .model(TextToImageModel::DallE3)
+            .size(ImageSize::Large)
+            .build();
+
+        // Send the request
+        let images = generate_images(&client, &request_body).await?;
+        mock.assert_async().await;
+
+        assert_eq!(
+            images,
+            vec![Image {
+                revised_prompt: Some("A fluffy baby sea otter.".to_string()),
+                image: ImageContent::Url("https://example.com/otter.png".to_string()),
+            }]
+        );
+
+        Ok(())
+    }
 }
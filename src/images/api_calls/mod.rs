@@ -1,2 +1,8 @@
 mod generation;
 pub use generation::generate_images;
+
+mod edit;
+pub use edit::edit_images;
+
+mod variation;
+pub use variation::create_image_variations;
@@ -0,0 +1,30 @@
+use crate::{ Result, Error, ImagesApiError, OpenAIClient };
+use super::super::{ IMAGE_VARIATIONS_API_PATH, ImageVariationRequestBody, Image, ImagesResponse };
+
+/// Creates variations of a given image.
+///
+/// The image is validated before uploading.
+pub async fn create_image_variations(
+    client: &OpenAIClient,
+    request_body: ImageVariationRequestBody
+) -> Result<Vec<Image>> {
+    // Convert the request body to a multipart form
+    let form = request_body.into_form().await?;
+
+    // Send the request
+    let response = client.send(|client| {
+        client
+            .post(client.endpoint(IMAGE_VARIATIONS_API_PATH))
+            .multipart(form)
+    }).await?;
+
+    // Parse the response
+    let response = match response.json::<ImagesResponse>().await {
+        Ok(response) => response,
+        Err(error) => {
+            return Err(Error::ImagesApi(ImagesApiError::ParseToImages { source: error }));
+        }
+    };
+
+    Ok(response.data)
+}
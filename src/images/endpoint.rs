@@ -1 +1,8 @@
-pub const IMAGES_API_ENDPOINT: &str = "https://api.openai.com/v1/images";
+/// Path of the image generation API under the base URL of the client.
+pub const IMAGE_GENERATIONS_API_PATH: &str = "images/generations";
+
+/// Path of the image edit API under the base URL of the client.
+pub const IMAGE_EDITS_API_PATH: &str = "images/edits";
+
+/// Path of the image variation API under the base URL of the client.
+pub const IMAGE_VARIATIONS_API_PATH: &str = "images/variations";
@@ -1,5 +1,5 @@
 mod endpoint;
-use endpoint::IMAGES_API_ENDPOINT;
+use endpoint::{ IMAGE_GENERATIONS_API_PATH, IMAGE_EDITS_API_PATH, IMAGE_VARIATIONS_API_PATH };
 
 mod request;
 pub use request::*;
@@ -7,5 +7,8 @@ pub use request::*;
 mod response;
 pub use response::*;
 
+mod image_format;
+pub use image_format::ImageFormat;
+
 mod api_calls;
 pub use api_calls::*;
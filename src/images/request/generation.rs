@@ -1,5 +1,9 @@
 use serde::Serialize;
-use super::{ TextToImageModel, ImageResponseFormat };
+use log::warn;
+use super::{ TextToImageModel, ImageSize, ImageQuality, ImageStyle, ImageResponseFormat };
+
+const MIN_NUM_IMAGES: u32 = 1;
+const MAX_NUM_IMAGES: u32 = 10;
 
 #[derive(Debug, Serialize)]
 pub struct ImageGenerationRequestBody {
@@ -11,48 +15,166 @@ pub struct ImageGenerationRequestBody {
     #[serde(skip_serializing_if = "Option::is_none")]
     n: Option<u32>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quality: Option<ImageQuality>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     response_format: Option<ImageResponseFormat>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<ImageSize>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    style: Option<ImageStyle>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<String>,
+}
+
+impl ImageGenerationRequestBody {
+    pub fn builder<S: AsRef<str>>(prompt: S) -> ImageGenerationRequestBodyBuilder {
+        ImageGenerationRequestBodyBuilder::new(prompt)
+    }
 }
 
 pub struct ImageGenerationRequestBodyBuilder {
     prompt: String,
     model: Option<TextToImageModel>,
     n: Option<u32>,
+    quality: Option<ImageQuality>,
     response_format: Option<ImageResponseFormat>,
+    size: Option<ImageSize>,
+    style: Option<ImageStyle>,
+    user: Option<String>,
 }
 
 impl ImageGenerationRequestBodyBuilder {
-    pub fn new(prompt: String) -> Self {
+    /// Creates a new builder with `None` values for all optional fields.
+    pub fn new<S: AsRef<str>>(prompt: S) -> Self {
         Self {
-            prompt,
+            prompt: prompt.as_ref().to_string(),
             model: None,
             n: None,
+            quality: None,
             response_format: None,
+            size: None,
+            style: None,
+            user: None,
         }
     }
 
+    /// Builds the request body.
     pub fn build(self) -> ImageGenerationRequestBody {
         ImageGenerationRequestBody {
             prompt: self.prompt,
             model: self.model,
             n: self.n,
+            quality: self.quality,
             response_format: self.response_format,
+            size: self.size,
+            style: self.style,
+            user: self.user,
         }
     }
 
+    /// Sets the model to use for image generation.
+    ///
+    /// It defaults to DALL·E 2 on the server side.
     pub fn model(mut self, model: TextToImageModel) -> Self {
         self.model = Some(model);
         self
     }
 
+    /// Sets the number of images to generate.
+    ///
+    /// The input value will be clamped in between 1 and 10.
+    /// Note that DALL·E 3 only supports generating one image at a time.
     pub fn n(mut self, n: u32) -> Self {
+        // Clamp the value to the valid range
+        let n = if !(MIN_NUM_IMAGES..=MAX_NUM_IMAGES).contains(&n) {
+            // Clamp the value
+            let num_images = n.clamp(MIN_NUM_IMAGES, MAX_NUM_IMAGES);
+
+            // Warn the user
+            warn!("input value of n is {n} which is out of range, it is now clamped to {num_images}");
+
+            num_images
+        } else {
+            n
+        };
+
         self.n = Some(n);
         self
     }
 
+    /// Sets the quality of the images.
+    ///
+    /// This is only supported by DALL·E 3.
+    pub fn quality(mut self, quality: ImageQuality) -> Self {
+        self.quality = Some(quality);
+        self
+    }
+
+    /// Sets the format in which the generated images are returned.
     pub fn response_format(mut self, response_format: ImageResponseFormat) -> Self {
         self.response_format = Some(response_format);
         self
     }
+
+    /// Sets the size of the generated images.
+    pub fn size(mut self, size: ImageSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the style of the generated images.
+    ///
+    /// This is only supported by DALL·E 3.
+    pub fn style(mut self, style: ImageStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Sets a unique identifier representing your end-user,
+    /// which can help OpenAI to monitor and detect abuse.
+    pub fn user<S: AsRef<str>>(mut self, user: S) -> Self {
+        self.user = Some(user.as_ref().to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_image_generation_request_body() {
+        let body = ImageGenerationRequestBody::builder("A cute baby sea otter").build();
+        let json = serde_json::to_string(&body).unwrap();
+        assert_eq!(json, r#"{"prompt":"A cute baby sea otter"}"#);
+
+        let body = ImageGenerationRequestBody::builder("A cute baby sea otter")
+            .model(TextToImageModel::DallE3)
+            .n(1)
+            .quality(ImageQuality::Hd)
+            .response_format(ImageResponseFormat::Base64)
+            .size(ImageSize::LargeLandscape)
+            .style(ImageStyle::Natural)
+            .user("user-1234")
+            .build();
+        let json = serde_json::to_string(&body).unwrap();
+        assert_eq!(
+            json,
+            r#"{"prompt":"A cute baby sea otter","model":"dall-e-3","n":1,"quality":"hd","response_format":"b64_json","size":"1792x1024","style":"natural","user":"user-1234"}"#
+        );
+    }
+
+    #[test]
+    fn clamp_number_of_images() {
+        let body = ImageGenerationRequestBody::builder("A cute baby sea otter").n(0).build();
+        assert_eq!(body.n, Some(1));
+
+        let body = ImageGenerationRequestBody::builder("A cute baby sea otter").n(42).build();
+        assert_eq!(body.n, Some(10));
+    }
 }
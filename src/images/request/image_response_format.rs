@@ -1,7 +1,13 @@
 use serde::Serialize;
 
-#[derive(Debug, Serialize)]
+/// The format in which the generated images are returned.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
 pub enum ImageResponseFormat {
+    /// The images are returned as URLs, which are only valid for 60 minutes.
+    #[serde(rename = "url")]
     Url,
+
+    /// The images are returned as base64-encoded JSON strings.
+    #[serde(rename = "b64_json")]
     Base64,
 }
@@ -7,5 +7,19 @@ pub use model::TextToImageModel;
 mod size;
 pub use size::ImageSize;
 
+mod quality;
+pub use quality::ImageQuality;
+
+mod style;
+pub use style::ImageStyle;
+
 mod image_response_format;
 pub use image_response_format::ImageResponseFormat;
+
+mod edit;
+pub use edit::{ ImageEditRequestBody, ImageEditRequestBodyBuilder };
+
+mod variation;
+pub use variation::{ ImageVariationRequestBody, ImageVariationRequestBodyBuilder };
+
+pub(crate) mod png;
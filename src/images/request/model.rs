@@ -1,7 +1,10 @@
 use serde::Serialize;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
 pub enum TextToImageModel {
+    #[serde(rename = "dall-e-2")]
     DallE2,
+
+    #[serde(rename = "dall-e-3")]
     DallE3,
 }
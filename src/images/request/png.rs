@@ -0,0 +1,112 @@
+use crate::{ Result, Error, ImagesApiError, upload::PreparedUpload };
+
+/// The signature that every PNG file starts with.
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// Length of the PNG header that is needed to read the image dimensions,
+/// i.e., the signature followed by the beginning of the IHDR chunk.
+pub(crate) const PNG_HEADER_LENGTH: usize = 24;
+
+/// Maximum size of an image to upload, which is 4 MB.
+const MAX_IMAGE_FILE_SIZE: u64 = 4 * 1024 * 1024;
+
+/// Validates that the uploaded image is a square PNG file less than 4 MB,
+/// and returns its width and height.
+pub(crate) fn validate_png_image(upload: &PreparedUpload) -> Result<(u32, u32)> {
+    // Check the file size
+    if upload.length > MAX_IMAGE_FILE_SIZE {
+        return Err(
+            Error::ImagesApi(ImagesApiError::ImageTooLarge {
+                file_name: upload.file_name.clone(),
+                size: upload.length,
+                max_size: MAX_IMAGE_FILE_SIZE,
+            })
+        );
+    }
+
+    // Read the dimensions from the header
+    let (width, height) = match png_dimensions(&upload.header) {
+        Some(dimensions) => dimensions,
+        None => {
+            return Err(Error::ImagesApi(ImagesApiError::NotPng(upload.file_name.clone())));
+        }
+    };
+
+    // Check that the image is square
+    if width != height {
+        return Err(Error::ImagesApi(ImagesApiError::NotSquare(upload.file_name.clone())));
+    }
+
+    Ok((width, height))
+}
+
+/// Reads the width and height from the PNG header.
+/// Returns `None` if the header is not a valid PNG header.
+fn png_dimensions(header: &[u8]) -> Option<(u32, u32)> {
+    if header.len() < PNG_HEADER_LENGTH || !header.starts_with(PNG_SIGNATURE) {
+        return None;
+    }
+
+    // The first chunk must be IHDR, whose data starts with the width and height
+    if &header[12..16] != b"IHDR" {
+        return None;
+    }
+
+    let width = u32::from_be_bytes(header[16..20].try_into().ok()?);
+    let height = u32::from_be_bytes(header[20..24].try_into().ok()?);
+
+    Some((width, height))
+}
+
+/// Creates the leading bytes of a PNG file with the given dimensions for testing.
+#[cfg(test)]
+pub(crate) fn png_header(width: u32, height: u32) -> Vec<u8> {
+    let mut header = PNG_SIGNATURE.to_vec();
+    header.extend(13u32.to_be_bytes());
+    header.extend(b"IHDR");
+    header.extend(width.to_be_bytes());
+    header.extend(height.to_be_bytes());
+
+    header
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UploadFile;
+    use super::*;
+
+    #[tokio::test]
+    async fn validate_png() -> Result<()> {
+        // A valid square image
+        let upload = UploadFile::from_bytes("image.png", png_header(512, 512))
+            .prepare(PNG_HEADER_LENGTH).await?;
+        assert_eq!(validate_png_image(&upload)?, (512, 512));
+
+        // Not square
+        let upload = UploadFile::from_bytes("image.png", png_header(512, 256))
+            .prepare(PNG_HEADER_LENGTH).await?;
+        assert!(
+            matches!(validate_png_image(&upload), Err(Error::ImagesApi(ImagesApiError::NotSquare(_))))
+        );
+
+        // Not a PNG file
+        let upload = UploadFile::from_bytes("image.jpg", b"\xff\xd8\xff\xe0".to_vec())
+            .prepare(PNG_HEADER_LENGTH).await?;
+        assert!(
+            matches!(validate_png_image(&upload), Err(Error::ImagesApi(ImagesApiError::NotPng(_))))
+        );
+
+        // Too large
+        let mut bytes = png_header(1024, 1024);
+        bytes.resize(5 * 1024 * 1024, 0);
+        let upload = UploadFile::from_bytes("image.png", bytes).prepare(PNG_HEADER_LENGTH).await?;
+        assert!(
+            matches!(
+                validate_png_image(&upload),
+                Err(Error::ImagesApi(ImagesApiError::ImageTooLarge { .. }))
+            )
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+/// The quality of the generated images.
+/// This is only supported by DALL·E 3.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageQuality {
+    Standard,
+
+    /// Creates images with finer details and greater consistency across the image.
+    Hd,
+}
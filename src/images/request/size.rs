@@ -1,7 +1,28 @@
+use serde::Serialize;
+
+/// The size of the generated images.
+///
+/// DALL·E 2 supports `Small`, `Medium` and `Large`, while
+/// DALL·E 3 supports `Large`, `LargePortrait` and `LargeLandscape`.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
 pub enum ImageSize {
+    /// 256x256.
+    #[serde(rename = "256x256")]
     Small,
+
+    /// 512x512.
+    #[serde(rename = "512x512")]
     Medium,
+
+    /// 1024x1024.
+    #[serde(rename = "1024x1024")]
     Large,
+
+    /// 1024x1792.
+    #[serde(rename = "1024x1792")]
     LargePortrait,
+
+    /// 1792x1024.
+    #[serde(rename = "1792x1024")]
     LargeLandscape,
 }
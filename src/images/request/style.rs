@@ -0,0 +1,13 @@
+use serde::Serialize;
+
+/// The style of the generated images.
+/// This is only supported by DALL·E 3.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageStyle {
+    /// The model leans towards generating hyper-real and dramatic images.
+    Vivid,
+
+    /// The model produces more natural, less hyper-real looking images.
+    Natural,
+}
@@ -0,0 +1,146 @@
+use reqwest::multipart::Form;
+use log::warn;
+use crate::{ Result, UploadFile, utils::to_form_text };
+use super::{
+    TextToImageModel,
+    ImageSize,
+    ImageResponseFormat,
+    png::{ PNG_HEADER_LENGTH, validate_png_image },
+};
+
+const MIN_NUM_IMAGES: u32 = 1;
+const MAX_NUM_IMAGES: u32 = 10;
+
+/// Request body of creating a variation of a given image.
+///
+/// It is sent as a multipart form rather than JSON.
+pub struct ImageVariationRequestBody {
+    image: UploadFile,
+    model: Option<TextToImageModel>,
+    n: Option<u32>,
+    size: Option<ImageSize>,
+    response_format: Option<ImageResponseFormat>,
+    user: Option<String>,
+}
+
+impl ImageVariationRequestBody {
+    pub fn builder(image: UploadFile) -> ImageVariationRequestBodyBuilder {
+        ImageVariationRequestBodyBuilder::new(image)
+    }
+
+    /// Validates the image, and converts to a multipart form.
+    pub(crate) async fn into_form(self) -> Result<Form> {
+        // Validate the image
+        let image = self.image.prepare(PNG_HEADER_LENGTH).await?;
+        validate_png_image(&image)?;
+
+        let mut form = Form::new().part("image", image.into_part().mime_str("image/png")?);
+
+        // Add the optional fields
+        if let Some(model) = self.model {
+            form = form.text("model", to_form_text(&model));
+        }
+        if let Some(n) = self.n {
+            form = form.text("n", n.to_string());
+        }
+        if let Some(size) = self.size {
+            form = form.text("size", to_form_text(&size));
+        }
+        if let Some(response_format) = self.response_format {
+            form = form.text("response_format", to_form_text(&response_format));
+        }
+        if let Some(user) = self.user {
+            form = form.text("user", user);
+        }
+
+        Ok(form)
+    }
+}
+
+pub struct ImageVariationRequestBodyBuilder {
+    image: UploadFile,
+    model: Option<TextToImageModel>,
+    n: Option<u32>,
+    size: Option<ImageSize>,
+    response_format: Option<ImageResponseFormat>,
+    user: Option<String>,
+}
+
+impl ImageVariationRequestBodyBuilder {
+    /// Creates a new builder with `None` values for all optional fields.
+    ///
+    /// The image to use as the basis for the variations
+    /// must be a valid PNG file, less than 4MB, and square.
+    pub fn new(image: UploadFile) -> Self {
+        Self {
+            image,
+            model: None,
+            n: None,
+            size: None,
+            response_format: None,
+            user: None,
+        }
+    }
+
+    /// Builds the request body.
+    pub fn build(self) -> ImageVariationRequestBody {
+        ImageVariationRequestBody {
+            image: self.image,
+            model: self.model,
+            n: self.n,
+            size: self.size,
+            response_format: self.response_format,
+            user: self.user,
+        }
+    }
+
+    /// Sets the model to use for image variation.
+    ///
+    /// Only DALL·E 2 is supported at this time.
+    pub fn model(mut self, model: TextToImageModel) -> Self {
+        self.model = Some(model);
+        self
+    }
+
+    /// Sets the number of images to generate.
+    ///
+    /// The input value will be clamped in between 1 and 10.
+    pub fn n(mut self, n: u32) -> Self {
+        // Clamp the value to the valid range
+        let n = if !(MIN_NUM_IMAGES..=MAX_NUM_IMAGES).contains(&n) {
+            // Clamp the value
+            let num_images = n.clamp(MIN_NUM_IMAGES, MAX_NUM_IMAGES);
+
+            // Warn the user
+            warn!("input value of n is {n} which is out of range, it is now clamped to {num_images}");
+
+            num_images
+        } else {
+            n
+        };
+
+        self.n = Some(n);
+        self
+    }
+
+    /// Sets the size of the generated images.
+    ///
+    /// Must be one of `Small`, `Medium` or `Large`.
+    pub fn size(mut self, size: ImageSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the format in which the generated images are returned.
+    pub fn response_format(mut self, response_format: ImageResponseFormat) -> Self {
+        self.response_format = Some(response_format);
+        self
+    }
+
+    /// Sets a unique identifier representing your end-user,
+    /// which can help OpenAI to monitor and detect abuse.
+    pub fn user<S: AsRef<str>>(mut self, user: S) -> Self {
+        self.user = Some(user.as_ref().to_string());
+        self
+    }
+}
@@ -1,13 +1,228 @@
+use std::path::{ Path, PathBuf };
+use base64::{ Engine, engine::general_purpose::STANDARD as BASE64 };
+use bytes::Bytes;
 use serde::Deserialize;
+use crate::{ Result, Error, ImagesApiError, OpenAIClient };
+use super::ImageFormat;
 
-#[derive(Debug, Deserialize)]
+/// A generated image.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
 pub struct Image {
-    pub revised_prompt: String,
+    /// The prompt that was used to generate the image,
+    /// if there was any revision to the prompt.
+    /// Only DALL·E 3 revises the prompt.
+    pub revised_prompt: Option<String>,
+
+    /// Either the URL or the base64-encoded JSON of the image,
+    /// depending on the requested response format.
+    #[serde(flatten)]
     pub image: ImageContent,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone, PartialEq)]
 pub enum ImageContent {
+    #[serde(rename = "url")]
     Url(String),
+
+    #[serde(rename = "b64_json")]
     Base64(String),
 }
+
+impl Image {
+    /// Gets the bytes of the image.
+    /// See [`ImageContent::bytes`].
+    pub async fn bytes(&self, client: &OpenAIClient) -> Result<Bytes> {
+        self.image.bytes(client).await
+    }
+
+    /// Saves the image to the given file path.
+    pub async fn save<P: AsRef<Path>>(&self, client: &OpenAIClient, path: P) -> Result<()> {
+        let bytes = self.bytes(client).await?;
+        write_image(path.as_ref(), &bytes).await
+    }
+
+    /// Saves the image to the given directory, and returns the path of the saved file.
+    ///
+    /// The file name is derived from a hash of the image content,
+    /// and the extension from the detected image format (PNG if unknown).
+    /// Therefore, saving the same image twice results in the same file.
+    pub async fn save_to_dir<P: AsRef<Path>>(
+        &self,
+        client: &OpenAIClient,
+        dir: P
+    ) -> Result<PathBuf> {
+        let bytes = self.bytes(client).await?;
+
+        // Build the file path
+        let path = dir.as_ref().join(image_file_name(&bytes));
+
+        write_image(&path, &bytes).await?;
+
+        Ok(path)
+    }
+}
+
+impl ImageContent {
+    /// Gets the bytes of the image.
+    ///
+    /// The base64-encoded image is decoded, while the image at the URL is downloaded.
+    /// The authorization header is not sent when downloading
+    /// since the URL points to a host other than the API.
+    pub async fn bytes(&self, client: &OpenAIClient) -> Result<Bytes> {
+        match self {
+            ImageContent::Base64(b64_json) =>
+                match BASE64.decode(b64_json) {
+                    Ok(bytes) => Ok(Bytes::from(bytes)),
+                    Err(error) => {
+                        Err(Error::ImagesApi(ImagesApiError::DecodeBase64 { source: error }))
+                    }
+                }
+            ImageContent::Url(url) => {
+                // Send the request without the API key
+                let response = match client.http_client().get(url).send().await {
+                    Ok(response) =>
+                        match response.error_for_status() {
+                            Ok(response) => response,
+                            Err(error) => {
+                                return Err(
+                                    Error::ImagesApi(ImagesApiError::DownloadImage { source: error })
+                                );
+                            }
+                        }
+                    Err(error) => {
+                        return Err(Error::ImagesApi(ImagesApiError::DownloadImage { source: error }));
+                    }
+                };
+
+                // Receive the bytes
+                match response.bytes().await {
+                    Ok(bytes) => Ok(bytes),
+                    Err(error) => {
+                        Err(Error::ImagesApi(ImagesApiError::DownloadImage { source: error }))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Derives a deterministic file name for the image
+/// from the FNV-1a hash of its content and its format.
+fn image_file_name(bytes: &[u8]) -> String {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const FNV_PRIME: u64 = 0x100000001b3;
+
+    let hash = bytes
+        .iter()
+        .fold(FNV_OFFSET_BASIS, |hash, byte| (hash ^ (*byte as u64)).wrapping_mul(FNV_PRIME));
+
+    let extension = ImageFormat::detect(bytes).unwrap_or(ImageFormat::Png).extension();
+
+    format!("{:016x}.{}", hash, extension)
+}
+
+async fn write_image(path: &Path, bytes: &[u8]) -> Result<()> {
+    match tokio::fs::write(path, bytes).await {
+        Ok(_) => Ok(()),
+        Err(error) => {
+            Err(
+                Error::ImagesApi(ImagesApiError::WriteImage {
+                    path: path.to_path_buf(),
+                    source: error,
+                })
+            )
+        }
+    }
+}
+
+/// The response of the images API.
+#[derive(Debug, Deserialize)]
+pub(crate) struct ImagesResponse {
+    pub data: Vec<Image>,
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use super::*;
+
+    #[test]
+    fn deserialize_image() {
+        let json = r#"{
+            "revised_prompt": "A baby sea otter floating on its back.",
+            "url": "https://example.com/otter.png"
+        }"#;
+
+        assert_eq!(
+            serde_json::from_str::<Image>(json).unwrap(),
+            Image {
+                revised_prompt: Some("A baby sea otter floating on its back.".to_string()),
+                image: ImageContent::Url("https://example.com/otter.png".to_string()),
+            }
+        );
+
+        let json = r#"{
+            "b64_json": "aGVsbG8="
+        }"#;
+
+        assert_eq!(
+            serde_json::from_str::<Image>(json).unwrap(),
+            Image {
+                revised_prompt: None,
+                image: ImageContent::Base64("aGVsbG8=".to_string()),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn save_image() -> Result<()> {
+        let png = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+
+        // Start a mock CDN server
+        // The API key must not be sent to it
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/otter.png")
+            .match_header("authorization", Matcher::Missing)
+            .with_body(&png)
+            .create_async().await;
+
+        let client = OpenAIClient::builder().api_key("sk-test").build()?;
+
+        // Download the image
+        let image = Image {
+            revised_prompt: None,
+            image: ImageContent::Url(format!("{}/otter.png", server.url())),
+        };
+        assert_eq!(image.bytes(&client).await?, png);
+        mock.assert_async().await;
+
+        // Decode the base64-encoded image
+        let image = Image {
+            revised_prompt: None,
+            image: ImageContent::Base64(BASE64.encode(&png)),
+        };
+        assert_eq!(image.bytes(&client).await?, png);
+
+        // Save the image to a directory
+        let dir = std::env::temp_dir().join("rustyopenai-save-image");
+        tokio::fs::create_dir_all(&dir).await.unwrap();
+        let path = image.save_to_dir(&client, &dir).await?;
+        assert_eq!(path.extension().unwrap(), "png");
+        assert_eq!(tokio::fs::read(&path).await.unwrap(), png);
+
+        // The file name is deterministic
+        assert_eq!(image.save_to_dir(&client, &dir).await?, path);
+
+        // Invalid base64
+        let image = Image { revised_prompt: None, image: ImageContent::Base64("???".to_string()) };
+        assert!(
+            matches!(
+                image.bytes(&client).await,
+                Err(Error::ImagesApi(ImagesApiError::DecodeBase64 { .. }))
+            )
+        );
+
+        Ok(())
+    }
+}
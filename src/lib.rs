@@ -1,15 +1,80 @@
 mod client;
 pub use client::OpenAIClient;
 
+#[cfg(feature = "env")]
+pub use client::EnvVarNames;
+
+mod credential;
+pub use credential::{
+    CredentialProvider,
+    StaticCredential,
+    EnvCredential,
+    FileCredential,
+    AsyncFetchCredential,
+    DEFAULT_FILE_CHECK_INTERVAL,
+};
+
+mod pool;
+pub use pool::{ CredentialPool, PoolStrategy, KeyHealth };
+
+mod rate_limit;
+pub use rate_limit::{ RateLimiter, RateLimits };
+
+mod azure;
+pub use azure::{ AzureConfig, AzureAuth, DEFAULT_AZURE_API_VERSION };
+
+mod provider;
+pub use provider::{ ProviderProfile, AuthScheme, FieldPolicy, ErrorFormat };
+
+mod response;
+pub use response::{ ListResponse, ListOrder, DeletedObject };
+
+mod meta;
+pub use meta::{ ResponseMeta, WithMeta };
+
+mod upload;
+pub use upload::UploadFile;
+
 mod error;
-pub use error::{ Result, Error, ModelsApiError, ChatApiError };
+pub use error::{
+    Result,
+    Error,
+    ModelsApiError,
+    ChatApiError,
+    ImagesApiError,
+    AudioApiError,
+    ModerationsApiError,
+    FilesApiError,
+    BatchesApiError,
+    FineTuningApiError,
+    AssistantsApiError,
+    VectorStoresApiError,
+    ResponsesApiError,
+    CompletionsApiError,
+};
+
+#[cfg(feature = "realtime")]
+pub use error::RealtimeApiError;
 
 pub mod models;
 pub mod chat;
 pub mod embeddings;
 pub mod images;
+pub mod audio;
+pub mod moderations;
+pub mod files;
+pub mod batches;
+pub mod fine_tuning;
+pub mod assistants;
+pub mod vector_stores;
+pub mod responses;
+pub mod completions;
+
+#[cfg(feature = "realtime")]
+pub mod realtime;
 
 mod utils;
+mod sse;
 
 pub mod prelude;
 
@@ -0,0 +1,148 @@
+use std::time::Duration;
+use reqwest::header::HeaderMap;
+
+/// Rate limit and processing information sent in the headers of a response.
+///
+/// A field is `None` if the header is absent or malformed,
+/// e.g., in responses of OpenAI-compatible providers.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ResponseMeta {
+    /// The `x-request-id` header, which identifies the request in support inquiries.
+    pub request_id: Option<String>,
+
+    /// The `openai-processing-ms` header.
+    pub processing_time: Option<Duration>,
+
+    /// The `x-ratelimit-limit-requests` header.
+    pub limit_requests: Option<u64>,
+
+    /// The `x-ratelimit-limit-tokens` header.
+    pub limit_tokens: Option<u64>,
+
+    /// The `x-ratelimit-remaining-requests` header.
+    pub remaining_requests: Option<u64>,
+
+    /// The `x-ratelimit-remaining-tokens` header.
+    pub remaining_tokens: Option<u64>,
+
+    /// The `x-ratelimit-reset-requests` header,
+    /// i.e., the time until the request limit is reset.
+    pub reset_requests: Option<Duration>,
+
+    /// The `x-ratelimit-reset-tokens` header,
+    /// i.e., the time until the token limit is reset.
+    pub reset_tokens: Option<Duration>,
+}
+
+impl ResponseMeta {
+    /// Reads the information from the response headers.
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let text = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+        let number = |name: &str| text(name).and_then(|value| value.trim().parse::<u64>().ok());
+
+        Self {
+            request_id: text("x-request-id").map(|request_id| request_id.to_string()),
+            processing_time: text("openai-processing-ms")
+                .and_then(|value| value.trim().parse::<f64>().ok())
+                .filter(|millis| millis.is_finite() && *millis >= 0.0)
+                .and_then(|millis| Duration::try_from_secs_f64(millis / 1000.0).ok()),
+            limit_requests: number("x-ratelimit-limit-requests"),
+            limit_tokens: number("x-ratelimit-limit-tokens"),
+            remaining_requests: number("x-ratelimit-remaining-requests"),
+            remaining_tokens: number("x-ratelimit-remaining-tokens"),
+            reset_requests: text("x-ratelimit-reset-requests").and_then(parse_duration),
+            reset_tokens: text("x-ratelimit-reset-tokens").and_then(parse_duration),
+        }
+    }
+}
+
+/// A parsed response together with the information in its headers.
+#[derive(Debug, Clone)]
+pub struct WithMeta<T> {
+    pub data: T,
+    pub meta: ResponseMeta,
+}
+
+/// Parses a duration in the format of the rate limit headers, e.g., `1s`, `6m0s` or `20ms`.
+fn parse_duration(text: &str) -> Option<Duration> {
+    let mut total = 0.0;
+    let mut rest = text.trim();
+    if rest.is_empty() {
+        return None;
+    }
+
+    while !rest.is_empty() {
+        // Split the number and the unit
+        let unit_start = rest.find(|c: char| !(c.is_ascii_digit() || c == '.'))?;
+        let value: f64 = rest[..unit_start].parse().ok()?;
+        rest = &rest[unit_start..];
+
+        let unit_end = rest.find(|c: char| c.is_ascii_digit() || c == '.').unwrap_or(rest.len());
+        let seconds = match &rest[..unit_end] {
+            "h" => 3600.0,
+            "m" => 60.0,
+            "s" => 1.0,
+            "ms" => 0.001,
+            _ => {
+                return None;
+            }
+        };
+        rest = &rest[unit_end..];
+
+        total += value * seconds;
+    }
+
+    // The duration may overflow
+    Duration::try_from_secs_f64(total).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("1s"), Some(Duration::from_secs(1)));
+        assert_eq!(parse_duration("6m0s"), Some(Duration::from_secs(360)));
+        assert_eq!(parse_duration("20ms"), Some(Duration::from_millis(20)));
+        assert_eq!(parse_duration("1h2m3.5s"), Some(Duration::from_millis(3_723_500)));
+        assert_eq!(parse_duration("soon"), None);
+        assert_eq!(parse_duration(""), None);
+        assert_eq!(parse_duration("99999999999999999999h"), None);
+    }
+
+    #[test]
+    fn test_response_meta_from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-request-id", HeaderValue::from_static("req_123"));
+        headers.insert("openai-processing-ms", HeaderValue::from_static("250"));
+        headers.insert("x-ratelimit-limit-requests", HeaderValue::from_static("60"));
+        headers.insert("x-ratelimit-remaining-tokens", HeaderValue::from_static("149984"));
+        headers.insert("x-ratelimit-reset-requests", HeaderValue::from_static("1s"));
+        headers.insert("x-ratelimit-reset-tokens", HeaderValue::from_static("bad"));
+
+        assert_eq!(ResponseMeta::from_headers(&headers), ResponseMeta {
+            request_id: Some("req_123".to_string()),
+            processing_time: Some(Duration::from_millis(250)),
+            limit_requests: Some(60),
+            remaining_tokens: Some(149984),
+            reset_requests: Some(Duration::from_secs(1)),
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn test_response_meta_from_oversized_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("openai-processing-ms", HeaderValue::from_static("1e30"));
+        headers.insert(
+            "x-ratelimit-reset-tokens",
+            HeaderValue::from_static("99999999999999999999h")
+        );
+
+        let meta = ResponseMeta::from_headers(&headers);
+        assert_eq!(meta.processing_time, None);
+        assert_eq!(meta.reset_tokens, None);
+    }
+}
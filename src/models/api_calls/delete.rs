@@ -0,0 +1,95 @@
+use crate::{
+    Result,
+    Error,
+    ModelsApiError,
+    OpenAIClient,
+    DeletedObject,
+    utils::encode_path_segment,
+};
+use super::super::MODELS_API_PATH;
+
+/// Deletes a fine-tuned model.
+/// You must have the Owner role in your organization to delete a model.
+///
+/// The model list cached in the client is cleared if the model is deleted.
+pub async fn delete_model<S: AsRef<str>>(
+    client: &OpenAIClient,
+    model_name: S
+) -> Result<DeletedObject> {
+    // Send the request
+    let response = match
+        client.send(|client| {
+            client
+                .delete(
+                    client.endpoint(
+                        format!("{}/{}", MODELS_API_PATH, encode_path_segment(model_name.as_ref()))
+                    )
+                )
+        }).await
+    {
+        Ok(response) => response,
+        Err(Error::UnknownStatusCode { status_code: reqwest::StatusCode::NOT_FOUND, .. }) => {
+            return Err(
+                Error::ModelsApi(
+                    ModelsApiError::ModelNotFound(model_name.as_ref().to_string())
+                )
+            );
+        }
+        Err(error) => {
+            return Err(error);
+        }
+    };
+
+    // Deserialize the response
+    let deleted_model = match response.json::<DeletedObject>().await {
+        Ok(deleted_model) => deleted_model,
+        Err(error) => {
+            return Err(Error::ModelsApi(ModelsApiError::ParseToDeletedModel { source: error }));
+        }
+    };
+
+    // The cached model list is outdated
+    if deleted_model.deleted {
+        client.clear_model_cache();
+    }
+
+    Ok(deleted_model)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_delete_model() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("DELETE", "/models/ft:gpt-4o-mini:acemeco:suffix:abc123")
+            .match_header("authorization", "Bearer sk-test")
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "id": "ft:gpt-4o-mini:acemeco:suffix:abc123",
+                    "object": "model",
+                    "deleted": true
+                }).to_string()
+            )
+            .create_async().await;
+        server.mock("DELETE", "/models/missing").with_status(404).create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // Delete the model
+        let deleted_model = delete_model(&client, "ft:gpt-4o-mini:acemeco:suffix:abc123").await?;
+        mock.assert_async().await;
+        assert!(deleted_model.deleted);
+
+        // Delete a missing model
+        let result = delete_model(&client, "missing").await;
+        assert!(matches!(result, Err(Error::ModelsApi(ModelsApiError::ModelNotFound(_)))));
+
+        Ok(())
+    }
+}
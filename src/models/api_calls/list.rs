@@ -1,47 +1,41 @@
-use serde::Deserialize;
-use crate::{ Error, OpenAIClient, Result, ModelsApiError };
-use super::super::{ MODELS_API_ENDPOINT, Model };
+use crate::{ Error, OpenAIClient, Result, ModelsApiError, ListResponse };
+use super::super::{ MODELS_API_PATH, Model };
 
 /// Lists the currently available models, and
 /// provides basic information about each one such as the owner and availability.
 pub async fn list_models(client: &OpenAIClient) -> Result<Vec<Model>> {
     // Send the request
-    let response = match client.get(MODELS_API_ENDPOINT).send().await {
-        Ok(response) =>
-            match response.error_for_status() {
-                Ok(response) => response,
-                Err(error) => {
-                    return Err(Error::from(error));
-                }
-            }
-        Err(error) => {
-            return Err(Error::from(error));
-        }
-    };
+    let response = client.send(|client| client.get(client.endpoint(MODELS_API_PATH))).await?;
 
     // Deserialize the response
-    let response = match response.json::<serde_json::Value>().await {
+    let response = match response.json::<ListResponse<Model>>().await {
         Ok(response) => response,
         Err(error) => {
-            return Err(Error::ModelsApi(ModelsApiError::ParseToJson { source: error }));
+            return Err(Error::ModelsApi(ModelsApiError::ParseToModelList { source: error }));
         }
     };
 
-    // Get the data property
-    let data = match response.get("data") {
-        Some(data) => data.clone(),
-        None => {
-            return Err(Error::ModelsApi(ModelsApiError::MissingDataProperty));
-        }
-    };
+    Ok(response.data)
+}
 
-    // Parse to models
-    let models: Vec<Model> = match serde_json::from_value(data) {
-        Ok(models) => models,
-        Err(error) => {
-            return Err(Error::ModelsApi(ModelsApiError::ParseToModels { source: error }));
-        }
-    };
+/// Lists the currently available models like `list_models`,
+/// but reuses the model list cached in the client until it expires.
+///
+/// The time-to-live of the cache is set by `OpenAIClientBuilder::model_cache_ttl`.
+pub async fn list_models_cached(client: &OpenAIClient) -> Result<Vec<Model>> {
+    // Fetch the models with the same key of the credential pool if any
+    let primary = client.with_primary_member();
+    let client = primary.as_ref().unwrap_or(client);
+
+    // Return the cached models of the account if they have not expired
+    let key = client.account_key();
+    if let Some(models) = client.model_cache().get(&key) {
+        return Ok(models);
+    }
+
+    // Fetch the models and cache them
+    let models = list_models(client).await?;
+    client.model_cache().set(&key, models.clone());
 
     Ok(models)
 }
@@ -62,29 +56,63 @@ pub async fn list_model_names(client: &OpenAIClient) -> Result<Vec<String>> {
     )
 }
 
-#[derive(Debug, Deserialize)]
-pub struct ListModelsResponse {
-    pub object: String,
-    pub data: Vec<ModelInfo>,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct ModelInfo {
-    pub id: String,
-    pub object: String,
-    pub created: u32,
-    pub owned_by: String,
-}
-
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "env")]
     use std::time::Duration;
+    use reqwest::header::{ HeaderMap, HeaderValue };
     use super::*;
 
+    #[tokio::test]
+    async fn test_list_models_cached() -> Result<()> {
+        // Start a mock server
+        // The models should only be requested once for each organization
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/models")
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "object": "list",
+                    "data": [
+                        {
+                            "id": "gpt-4o",
+                            "object": "model",
+                            "created": 1715367049,
+                            "owned_by": "system"
+                        }
+                    ]
+                }).to_string()
+            )
+            .expect(2)
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // List models twice
+        let models = list_models_cached(&client).await?;
+        assert_eq!(models.len(), 1);
+        assert_eq!(models[0].id, "gpt-4o");
+        assert_eq!(list_models_cached(&client).await?, models);
+
+        // The copy of the client for another organization does not reuse the cached models
+        let mut headers = HeaderMap::new();
+        headers.insert("OpenAI-Organization", HeaderValue::from_static("org-other"));
+        let other_client = client.with_headers(headers);
+        assert_eq!(list_models_cached(&other_client).await?, models);
+        assert_eq!(list_models_cached(&other_client).await?, models);
+
+        mock.assert_async().await;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "env")]
     #[tokio::test]
     async fn test_list_models() {
         // Create a client
-        let client = OpenAIClient::new().unwrap();
+        let client = OpenAIClient::from_env().unwrap();
 
         // Get the list of models
         let models = list_models(&client).await.unwrap();
@@ -92,10 +120,11 @@ mod tests {
         println!("{:#?}", models);
     }
 
+    #[cfg(feature = "env")]
     #[tokio::test]
     async fn test_list_model_names() {
         // Create a client
-        let client = OpenAIClient::new().unwrap();
+        let client = OpenAIClient::from_env().unwrap();
 
         // Get the list of models
         let model_names = list_model_names(&client).await.unwrap();
@@ -122,11 +151,16 @@ mod tests {
         eprintln!("{}", error);
     }
 
+    #[cfg(feature = "env")]
     #[tokio::test]
     async fn test_timeout() {
         // Create a client with a short timeout
         // * Normally, no requests can be completed within 1ms
-        let client = OpenAIClient::builder().timeout(Duration::from_millis(1)).build().unwrap();
+        let client = OpenAIClient::builder()
+            .load_env()
+            .timeout(Duration::from_millis(1))
+            .build()
+            .unwrap();
 
         // Send the request
         let response = list_models(&client).await;
@@ -142,10 +176,11 @@ mod tests {
         eprintln!("{}", error);
     }
 
+    #[cfg(feature = "env")]
     #[tokio::test]
     async fn test_no_connection() {
         // Create a client
-        let client = OpenAIClient::new().unwrap();
+        let client = OpenAIClient::from_env().unwrap();
 
         // Send the request
         let response = list_models(&client).await;
@@ -1,5 +1,8 @@
 mod list;
-pub use list::{ list_models, list_model_names };
+pub use list::{ list_models, list_models_cached, list_model_names };
 
 mod retrieve;
 pub use retrieve::retrieve_model;
+
+mod delete;
+pub use delete::delete_model;
@@ -1,29 +1,29 @@
-use crate::{ Result, Error, ModelsApiError, OpenAIClient };
-use super::super::{ MODELS_API_ENDPOINT, Model };
+use crate::{ Result, Error, ModelsApiError, OpenAIClient, utils::encode_path_segment };
+use super::super::{ MODELS_API_PATH, Model };
 
 /// Retrieves a model instance, providing basic information about the model such as the owner and permissioning.
 pub async fn retrieve_model<S: AsRef<str>>(client: &OpenAIClient, model_name: S) -> Result<Model> {
     // Send the request
     let response = match
-        client.get(format!("{}/{}", MODELS_API_ENDPOINT, model_name.as_ref())).send().await
+        client.send(|client| {
+            client
+                .get(
+                    client.endpoint(
+                        format!("{}/{}", MODELS_API_PATH, encode_path_segment(model_name.as_ref()))
+                    )
+                )
+        }).await
     {
-        Ok(response) =>
-            match response.error_for_status() {
-                Ok(response) => response,
-                Err(error) => {
-                    if let Some(reqwest::StatusCode::NOT_FOUND) = error.status() {
-                        return Err(
-                            Error::ModelsApi(
-                                ModelsApiError::ModelNotFound(model_name.as_ref().to_string())
-                            )
-                        );
-                    }
-
-                    return Err(Error::from(error));
-                }
-            }
+        Ok(response) => response,
+        Err(Error::UnknownStatusCode { status_code: reqwest::StatusCode::NOT_FOUND, .. }) => {
+            return Err(
+                Error::ModelsApi(
+                    ModelsApiError::ModelNotFound(model_name.as_ref().to_string())
+                )
+            );
+        }
         Err(error) => {
-            return Err(Error::from(error));
+            return Err(error);
         }
     };
 
@@ -38,14 +38,14 @@ pub async fn retrieve_model<S: AsRef<str>>(client: &OpenAIClient, model_name: S)
     Ok(model)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "env"))]
 mod tests {
     use super::*;
 
     #[tokio::test]
     async fn test_retrieve_model() -> Result<()> {
         // Create a client
-        let client = OpenAIClient::new()?;
+        let client = OpenAIClient::from_env()?;
 
         // Send the request
         let model = retrieve_model(&client, "dall-e-2").await;
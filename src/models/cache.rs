@@ -0,0 +1,86 @@
+use std::{ collections::HashMap, sync::Mutex, time::{ Duration, Instant } };
+use super::Model;
+
+/// The default time-to-live of the cached model list.
+pub(crate) const DEFAULT_MODEL_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// An in-memory cache of the model lists held by the client,
+/// which is shared by its copies and keyed by the account the list is fetched for.
+pub(crate) struct ModelCache {
+    ttl: Duration,
+
+    /// The cached models of each account and the instant they were fetched.
+    entries: Mutex<HashMap<String, (Instant, Vec<Model>)>>,
+}
+
+impl ModelCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self { ttl, entries: Mutex::new(HashMap::new()) }
+    }
+
+    /// Gets the cached models of the account if they have not expired.
+    pub fn get(&self, key: &str) -> Option<Vec<Model>> {
+        let entries = match self.entries.lock() {
+            Ok(entries) => entries,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        match entries.get(key) {
+            Some((fetched_at, models)) if fetched_at.elapsed() < self.ttl => Some(models.clone()),
+            _ => None,
+        }
+    }
+
+    /// Replaces the cached models of the account.
+    pub fn set(&self, key: &str, models: Vec<Model>) {
+        let mut entries = match self.entries.lock() {
+            Ok(entries) => entries,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        entries.insert(key.to_string(), (Instant::now(), models));
+    }
+
+    /// Removes the cached models of all accounts.
+    pub fn clear(&self) {
+        let mut entries = match self.entries.lock() {
+            Ok(entries) => entries,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn model_cache_expiration() {
+        let model = Model {
+            id: "gpt-4o".to_string(),
+            object: "model".to_string(),
+            created: 1715367049,
+            owned_by: "system".to_string(),
+        };
+
+        // The models are cached
+        let cache = ModelCache::new(Duration::from_secs(60));
+        assert_eq!(cache.get("account"), None);
+        cache.set("account", vec![model.clone()]);
+        assert_eq!(cache.get("account"), Some(vec![model.clone()]));
+
+        // The models of another account are not cached
+        assert_eq!(cache.get("other account"), None);
+
+        // The cache is cleared
+        cache.clear();
+        assert_eq!(cache.get("account"), None);
+
+        // The models expire immediately
+        let cache = ModelCache::new(Duration::ZERO);
+        cache.set("account", vec![model]);
+        assert_eq!(cache.get("account"), None);
+    }
+}
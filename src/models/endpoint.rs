@@ -1,2 +1,2 @@
-/// Endpoint of the models API.
-pub const MODELS_API_ENDPOINT: &str = "https://api.openai.com/v1/models";
+/// Path of the models API under the base URL of the client.
+pub const MODELS_API_PATH: &str = "models";
@@ -1,8 +1,14 @@
 mod endpoint;
-use endpoint::MODELS_API_ENDPOINT;
+use endpoint::MODELS_API_PATH;
 
 mod api_calls;
 pub use api_calls::*;
 
 mod response;
 pub use response::Model;
+
+mod registry;
+pub use registry::{ ModelRegistry, ModelCapabilities, ModelPricing };
+
+mod cache;
+pub(crate) use cache::{ ModelCache, DEFAULT_MODEL_CACHE_TTL };
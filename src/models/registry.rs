@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+use crate::chat::ChatCompletionTokenUsage;
+
+/// What a model is capable of, and how much it costs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelCapabilities {
+    /// The maximum number of tokens in the input and output combined.
+    pub context_window: u32,
+
+    /// The maximum number of tokens the model can generate.
+    pub max_output_tokens: u32,
+
+    /// Whether the model accepts image inputs.
+    pub supports_vision: bool,
+
+    /// Whether the model can call tools.
+    pub supports_tools: bool,
+
+    /// Whether the model supports structured outputs with a JSON schema.
+    pub supports_structured_outputs: bool,
+
+    /// The price of the tokens, if known.
+    pub pricing: Option<ModelPricing>,
+}
+
+/// The price of a model in US dollars per one million tokens.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModelPricing {
+    pub input_per_million_tokens: f64,
+    pub output_per_million_tokens: f64,
+}
+
+impl ModelPricing {
+    pub fn new(input_per_million_tokens: f64, output_per_million_tokens: f64) -> Self {
+        Self { input_per_million_tokens, output_per_million_tokens }
+    }
+
+    /// Calculates the cost in US dollars of the tokens used by a chat completion.
+    pub fn cost(&self, usage: &ChatCompletionTokenUsage) -> f64 {
+        ((usage.prompt_tokens as f64) * self.input_per_million_tokens +
+            (usage.completion_tokens as f64) * self.output_per_million_tokens) /
+            1_000_000.0
+    }
+}
+
+/// A registry of model capabilities keyed by model ID.
+///
+/// A model ID is looked up by exact match first.
+/// Otherwise, a snapshot such as `gpt-4o-2024-08-06` or `gpt-4-0613` resolves to its model,
+/// i.e., the registered ID that it extends with a date suffix.
+/// Other variants such as `gpt-4-32k` are not resolved unless they are registered.
+/// Fine-tuned models such as `ft:gpt-4o-mini-2024-07-18:org::id` resolve to their base model.
+///
+/// The default registry contains the built-in capabilities of well-known models,
+/// which may be overridden or extended with `register`.
+#[derive(Debug, Clone)]
+pub struct ModelRegistry {
+    capabilities: HashMap<String, ModelCapabilities>,
+}
+
+impl ModelRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self { capabilities: HashMap::new() }
+    }
+
+    /// Creates a registry with the built-in capabilities of well-known models.
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+
+        for (
+            model_id,
+            context_window,
+            max_output_tokens,
+            vision,
+            tools,
+            structured_outputs,
+            input_price,
+            output_price,
+        ) in [
+            ("gpt-4o", 128_000, 16_384, true, true, true, 2.5, 10.0),
+            ("gpt-4o-2024-05-13", 128_000, 4_096, true, true, false, 5.0, 15.0),
+            ("chatgpt-4o-latest", 128_000, 16_384, true, false, false, 5.0, 15.0),
+            ("gpt-4o-mini", 128_000, 16_384, true, true, true, 0.15, 0.6),
+            ("gpt-4-turbo", 128_000, 4_096, true, true, false, 10.0, 30.0),
+            ("gpt-4-turbo-preview", 128_000, 4_096, false, true, false, 10.0, 30.0),
+            ("gpt-4-0125-preview", 128_000, 4_096, false, true, false, 10.0, 30.0),
+            ("gpt-4-1106-preview", 128_000, 4_096, false, true, false, 10.0, 30.0),
+            ("gpt-4", 8_192, 8_192, false, true, false, 30.0, 60.0),
+            ("gpt-4-32k", 32_768, 32_768, false, true, false, 60.0, 120.0),
+            ("gpt-3.5-turbo", 16_385, 4_096, false, true, false, 0.5, 1.5),
+            ("gpt-3.5-turbo-instruct", 4_096, 4_096, false, false, false, 1.5, 2.0),
+            ("o1", 200_000, 100_000, true, true, true, 15.0, 60.0),
+            ("o1-preview", 128_000, 32_768, false, false, false, 15.0, 60.0),
+            ("o1-mini", 128_000, 65_536, false, false, false, 3.0, 12.0),
+            ("babbage-002", 16_384, 16_384, false, false, false, 0.4, 0.4),
+            ("davinci-002", 16_384, 16_384, false, false, false, 2.0, 2.0),
+        ] {
+            registry.register(model_id, ModelCapabilities {
+                context_window,
+                max_output_tokens,
+                supports_vision: vision,
+                supports_tools: tools,
+                supports_structured_outputs: structured_outputs,
+                pricing: Some(ModelPricing::new(input_price, output_price)),
+            });
+        }
+
+        registry
+    }
+
+    /// Registers the capabilities of a model,
+    /// overriding the existing ones with the same ID.
+    pub fn register<S: AsRef<str>>(&mut self, model_id: S, capabilities: ModelCapabilities) {
+        self.capabilities.insert(model_id.as_ref().to_string(), capabilities);
+    }
+
+    /// Gets the capabilities of a model.
+    /// Returns `None` if the model is unknown.
+    pub fn get<S: AsRef<str>>(&self, model_id: S) -> Option<&ModelCapabilities> {
+        let model_id = model_id.as_ref();
+
+        // Resolve a fine-tuned model to its base model
+        let model_id = match model_id.strip_prefix("ft:") {
+            Some(fine_tuned_model_id) => fine_tuned_model_id.split(':').next().unwrap_or_default(),
+            None => model_id,
+        };
+
+        // Exact match
+        if let Some(capabilities) = self.capabilities.get(model_id) {
+            return Some(capabilities);
+        }
+
+        // Find the registered ID that the model ID extends with a date suffix
+        self.capabilities
+            .iter()
+            .find(|(registered_model_id, _)| {
+                model_id
+                    .strip_prefix(registered_model_id.as_str())
+                    .and_then(|suffix| suffix.strip_prefix('-'))
+                    .is_some_and(is_snapshot_date)
+            })
+            .map(|(_, capabilities)| capabilities)
+    }
+}
+
+/// Checks if the suffix of a model ID is the date of a snapshot,
+/// e.g., `2024-08-06` or `0613`.
+fn is_snapshot_date(suffix: &str) -> bool {
+    let is_number = |text: &str, length: usize| {
+        text.len() == length && text.chars().all(|c| c.is_ascii_digit())
+    };
+
+    match suffix.split('-').collect::<Vec<_>>().as_slice() {
+        [month_day] => is_number(month_day, 4),
+        [year, month, day] => is_number(year, 4) && is_number(month, 2) && is_number(day, 2),
+        _ => false,
+    }
+}
+
+impl Default for ModelRegistry {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_model_ids() {
+        let registry = ModelRegistry::builtin();
+
+        // Exact match
+        assert_eq!(registry.get("gpt-4o").unwrap().max_output_tokens, 16_384);
+
+        // Snapshots
+        assert_eq!(registry.get("gpt-4o-2024-08-06"), registry.get("gpt-4o"));
+        assert_eq!(registry.get("gpt-4o-mini-2024-07-18"), registry.get("gpt-4o-mini"));
+        assert_eq!(registry.get("gpt-4-0613"), registry.get("gpt-4"));
+        assert_eq!(registry.get("gpt-4o-2024-05-13").unwrap().max_output_tokens, 4_096);
+        assert_eq!(registry.get("gpt-4-32k-0613").unwrap().context_window, 32_768);
+
+        // Fine-tuned model
+        assert_eq!(
+            registry.get("ft:gpt-4o-mini-2024-07-18:my-org:custom:abc123"),
+            registry.get("gpt-4o-mini")
+        );
+
+        // Unknown models, including the unregistered variants of known ones
+        assert_eq!(registry.get("gpt-4oo"), None);
+        assert_eq!(registry.get("gpt-4o-audio-preview"), None);
+        assert_eq!(registry.get("gpt-4-1106-vision-preview"), None);
+        assert_eq!(registry.get("llama3"), None);
+    }
+
+    #[test]
+    fn override_capabilities() {
+        let mut registry = ModelRegistry::builtin();
+        registry.register("llama3", ModelCapabilities {
+            context_window: 8_192,
+            max_output_tokens: 2_048,
+            supports_vision: false,
+            supports_tools: false,
+            supports_structured_outputs: false,
+            pricing: None,
+        });
+
+        assert_eq!(registry.get("llama3").unwrap().max_output_tokens, 2_048);
+        assert_eq!(registry.get("llama3-70b"), None);
+    }
+
+    #[test]
+    fn calculate_cost() {
+        let pricing = ModelPricing::new(2.5, 10.0);
+        let usage = ChatCompletionTokenUsage {
+            prompt_tokens: 1_000,
+            completion_tokens: 500,
+            total_tokens: 1_500,
+        };
+
+        assert!((pricing.cost(&usage) - 0.0075).abs() < 1e-12);
+    }
+}
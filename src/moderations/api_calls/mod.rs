@@ -0,0 +1,2 @@
+mod moderation;
+pub use moderation::create_moderation;
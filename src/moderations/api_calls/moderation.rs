@@ -0,0 +1,88 @@
+use crate::{ Result, Error, ModerationsApiError, OpenAIClient };
+use super::super::{ MODERATIONS_API_PATH, ModerationRequestBody, ModerationResponse };
+
+/// Classifies if text and/or image inputs are potentially harmful.
+pub async fn create_moderation(
+    client: &OpenAIClient,
+    request_body: &ModerationRequestBody
+) -> Result<ModerationResponse> {
+    // Send the request
+    let response = client.send(|client| {
+        client.post(client.endpoint(MODERATIONS_API_PATH)).json(request_body)
+    }).await?;
+
+    // Parse the response
+    match response.json::<ModerationResponse>().await {
+        Ok(response) => Ok(response),
+        Err(error) => {
+            Err(Error::ModerationsApi(ModerationsApiError::ParseToModeration { source: error }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use serde_json::json;
+    use crate::moderations::*;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_create_moderation() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/moderations")
+            .match_header("authorization", "Bearer sk-test")
+            .match_body(
+                Matcher::Json(
+                    json!({
+                        "input": ["I want to kill them.", "Hello."],
+                        "model": "text-moderation-stable"
+                    })
+                )
+            )
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "id": "modr-XXXXX",
+                    "model": "text-moderation-007",
+                    "results": [
+                        {
+                            "flagged": true,
+                            "categories": { "violence": true, "hate": false },
+                            "category_scores": { "violence": 0.97, "hate": 0.01 }
+                        },
+                        {
+                            "flagged": false,
+                            "categories": { "violence": false, "hate": false },
+                            "category_scores": { "violence": 0.0001, "hate": 0.0001 }
+                        }
+                    ]
+                }).to_string()
+            )
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // Build the request body
+        let request_body = ModerationRequestBody::builder(
+            ModerationInput::Texts(vec!["I want to kill them.".to_string(), "Hello.".to_string()])
+        )
+            .model("text-moderation-stable")
+            .build();
+
+        let response = create_moderation(&client, &request_body).await?;
+
+        assert_eq!(response.results.len(), 2);
+        assert!(response.results[0].flagged);
+        assert!(response.results[0].categories.violence);
+        assert!(!response.results[1].flagged);
+        assert!(response.results[0].category_applied_input_types.is_none());
+
+        mock.assert_async().await;
+
+        Ok(())
+    }
+}
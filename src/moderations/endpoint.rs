@@ -0,0 +1,2 @@
+/// Path of the moderations API under the base URL of the client.
+pub const MODERATIONS_API_PATH: &str = "moderations";
@@ -0,0 +1,347 @@
+use std::{ collections::HashMap, fmt };
+use log::warn;
+use crate::{
+    Result,
+    Error,
+    ModerationsApiError,
+    OpenAIClient,
+    chat::{ ChatRequestBody, ChatRequestMessage, ChatCompletion, create_chat_completion },
+};
+use super::{
+    ModerationRequestBody,
+    ModerationInput,
+    ModerationResult,
+    ModerationCategory,
+    create_moderation,
+};
+
+const MIN_THRESHOLD: f64 = 0.0;
+const MAX_THRESHOLD: f64 = 1.0;
+
+/// Which side of a chat completion the flagged content comes from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ModeratedContent {
+    /// The user messages of the request.
+    Input,
+
+    /// The messages generated by the model.
+    Output,
+}
+
+impl fmt::Display for ModeratedContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Input => write!(f, "input"),
+            Self::Output => write!(f, "output"),
+        }
+    }
+}
+
+/// A guard that runs moderation on the input and/or output of chat completions.
+///
+/// A category is flagged if its score reaches the threshold set for it,
+/// or the default threshold if there is none.
+/// Without any threshold, the categories flagged by the API are used.
+#[derive(Debug, Clone)]
+pub struct ModerationGuard {
+    model: Option<String>,
+    thresholds: HashMap<ModerationCategory, f64>,
+    default_threshold: Option<f64>,
+    check_input: bool,
+    check_output: bool,
+}
+
+impl ModerationGuard {
+    pub fn builder() -> ModerationGuardBuilder {
+        ModerationGuardBuilder::new()
+    }
+
+    /// Gets the flagged categories of a moderation result.
+    pub fn flagged_categories(&self, result: &ModerationResult) -> Vec<ModerationCategory> {
+        result.category_scores
+            .iter()
+            .filter(|(category, score)| {
+                match self.thresholds.get(category).or(self.default_threshold.as_ref()) {
+                    Some(threshold) => **score >= *threshold,
+                    None => *result.categories.get(*category),
+                }
+            })
+            .map(|(category, _)| category)
+            .collect()
+    }
+
+    /// Runs moderation on the user messages of the request body,
+    /// and returns an error if any of them is flagged.
+    pub async fn check_request(
+        &self,
+        client: &OpenAIClient,
+        request_body: &ChatRequestBody
+    ) -> Result<()> {
+        if !self.check_input {
+            return Ok(());
+        }
+
+        // Collect the content of the user messages
+        let texts = request_body
+            .messages()
+            .iter()
+            .filter_map(|message| {
+                match message {
+                    ChatRequestMessage::User(message) => Some(message.content().to_string()),
+                    _ => None,
+                }
+            })
+            .collect();
+
+        self.check(client, texts, ModeratedContent::Input).await
+    }
+
+    /// Runs moderation on the messages of the chat completion,
+    /// and returns an error if any of them is flagged.
+    pub async fn check_completion(
+        &self,
+        client: &OpenAIClient,
+        completion: &ChatCompletion
+    ) -> Result<()> {
+        if !self.check_output {
+            return Ok(());
+        }
+
+        // Collect the content of the generated messages
+        let texts = completion.choices
+            .iter()
+            .filter_map(|choice| choice.message.content.clone())
+            .collect();
+
+        self.check(client, texts, ModeratedContent::Output).await
+    }
+
+    /// Creates a chat completion, running moderation on its input before
+    /// and on its output after, as configured.
+    pub async fn create_chat_completion(
+        &self,
+        client: &OpenAIClient,
+        request_body: &ChatRequestBody
+    ) -> Result<ChatCompletion> {
+        self.check_request(client, request_body).await?;
+
+        let completion = create_chat_completion(client, request_body).await?;
+
+        self.check_completion(client, &completion).await?;
+
+        Ok(completion)
+    }
+
+    async fn check(
+        &self,
+        client: &OpenAIClient,
+        texts: Vec<String>,
+        content: ModeratedContent
+    ) -> Result<()> {
+        if texts.is_empty() {
+            return Ok(());
+        }
+
+        // Build the request body
+        let mut builder = ModerationRequestBody::builder(ModerationInput::Texts(texts));
+        if let Some(model) = &self.model {
+            builder = builder.model(model);
+        }
+
+        // Collect the flagged categories of all the results
+        let mut categories: Vec<ModerationCategory> = vec![];
+        for result in create_moderation(client, &builder.build()).await?.results {
+            for category in self.flagged_categories(&result) {
+                if !categories.contains(&category) {
+                    categories.push(category);
+                }
+            }
+        }
+
+        if categories.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::ModerationsApi(ModerationsApiError::ContentFlagged { content, categories }))
+        }
+    }
+}
+
+pub struct ModerationGuardBuilder {
+    model: Option<String>,
+    thresholds: HashMap<ModerationCategory, f64>,
+    default_threshold: Option<f64>,
+    check_input: bool,
+    check_output: bool,
+}
+
+impl ModerationGuardBuilder {
+    /// Creates a new builder that checks both the input and the output
+    /// with the categories flagged by the API.
+    pub fn new() -> Self {
+        Self {
+            model: None,
+            thresholds: HashMap::new(),
+            default_threshold: None,
+            check_input: true,
+            check_output: true,
+        }
+    }
+
+    /// Builds the guard.
+    pub fn build(self) -> ModerationGuard {
+        ModerationGuard {
+            model: self.model,
+            thresholds: self.thresholds,
+            default_threshold: self.default_threshold,
+            check_input: self.check_input,
+            check_output: self.check_output,
+        }
+    }
+
+    /// Sets the moderation model, e.g., `omni-moderation-latest`.
+    pub fn model<S: AsRef<str>>(mut self, model: S) -> Self {
+        self.model = Some(model.as_ref().to_string());
+        self
+    }
+
+    /// Sets the score threshold of a category.
+    ///
+    /// The input value will be clamped in between 0.0 and 1.0.
+    pub fn threshold(mut self, category: ModerationCategory, threshold: f64) -> Self {
+        self.thresholds.insert(category, clamp_threshold(threshold));
+        self
+    }
+
+    /// Sets the score threshold of the categories without their own threshold.
+    ///
+    /// The input value will be clamped in between 0.0 and 1.0.
+    pub fn default_threshold(mut self, threshold: f64) -> Self {
+        self.default_threshold = Some(clamp_threshold(threshold));
+        self
+    }
+
+    /// Sets whether to check the user messages of the request.
+    pub fn check_input(mut self, check_input: bool) -> Self {
+        self.check_input = check_input;
+        self
+    }
+
+    /// Sets whether to check the messages generated by the model.
+    pub fn check_output(mut self, check_output: bool) -> Self {
+        self.check_output = check_output;
+        self
+    }
+}
+
+impl Default for ModerationGuardBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn clamp_threshold(threshold: f64) -> f64 {
+    if !(MIN_THRESHOLD..=MAX_THRESHOLD).contains(&threshold) {
+        // Clamp the value
+        let t = threshold.clamp(MIN_THRESHOLD, MAX_THRESHOLD);
+
+        // Warn the user
+        warn!(
+            "input value of threshold is {threshold} which is out of range, it is now clamped to {t}"
+        );
+
+        t
+    } else {
+        threshold
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use serde_json::json;
+    use crate::chat::UserMessage;
+    use super::*;
+
+    fn moderation_result() -> ModerationResult {
+        serde_json
+            ::from_value(
+                json!({
+                    "flagged": true,
+                    "categories": { "violence": true, "harassment": false },
+                    "category_scores": { "violence": 0.6, "harassment": 0.3 }
+                })
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn flag_categories() {
+        let result = moderation_result();
+
+        // Fall back to the categories flagged by the API
+        let guard = ModerationGuard::builder().build();
+        assert_eq!(guard.flagged_categories(&result), vec![ModerationCategory::Violence]);
+
+        // Use the thresholds
+        let guard = ModerationGuard::builder()
+            .threshold(ModerationCategory::Violence, 0.7)
+            .threshold(ModerationCategory::Harassment, 0.2)
+            .build();
+        assert_eq!(guard.flagged_categories(&result), vec![ModerationCategory::Harassment]);
+
+        // Use the default threshold
+        let guard = ModerationGuard::builder()
+            .threshold(ModerationCategory::Violence, 0.9)
+            .default_threshold(0.25)
+            .build();
+        assert_eq!(guard.flagged_categories(&result), vec![ModerationCategory::Harassment]);
+    }
+
+    #[tokio::test]
+    async fn check_flagged_request() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/moderations")
+            .match_body(Matcher::Json(json!({ "input": ["I want to hurt them."] })))
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "id": "modr-XXXXX",
+                    "model": "omni-moderation-latest",
+                    "results": [
+                        {
+                            "flagged": true,
+                            "categories": { "violence": true },
+                            "category_scores": { "violence": 0.8 }
+                        }
+                    ]
+                }).to_string()
+            )
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        let request_body = ChatRequestBody::builder(
+            "gpt-4o-mini",
+            vec![ChatRequestMessage::User(UserMessage::new("I want to hurt them."))]
+        ).build();
+
+        // The chat API is never called as the input is flagged
+        let guard = ModerationGuard::builder().build();
+        match guard.create_chat_completion(&client, &request_body).await {
+            Err(
+                Error::ModerationsApi(ModerationsApiError::ContentFlagged { content, categories }),
+            ) => {
+                assert_eq!(content, ModeratedContent::Input);
+                assert_eq!(categories, vec![ModerationCategory::Violence]);
+            }
+            other => panic!("expected flagged content, got {other:?}"),
+        }
+
+        mock.assert_async().await;
+
+        Ok(())
+    }
+}
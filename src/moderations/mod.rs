@@ -0,0 +1,14 @@
+mod endpoint;
+use endpoint::MODERATIONS_API_PATH;
+
+mod request;
+pub use request::*;
+
+mod response;
+pub use response::*;
+
+mod api_calls;
+pub use api_calls::*;
+
+mod guard;
+pub use guard::*;
@@ -0,0 +1,119 @@
+use serde::{ Serialize, Serializer, ser::SerializeMap };
+use serde_json::json;
+
+/// Request body of classifying if text and/or image inputs are potentially harmful.
+#[derive(Debug, Serialize)]
+pub struct ModerationRequestBody {
+    input: ModerationInput,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model: Option<String>,
+}
+
+impl ModerationRequestBody {
+    pub fn builder(input: ModerationInput) -> ModerationRequestBodyBuilder {
+        ModerationRequestBodyBuilder::new(input)
+    }
+}
+
+pub struct ModerationRequestBodyBuilder {
+    input: ModerationInput,
+    model: Option<String>,
+}
+
+impl ModerationRequestBodyBuilder {
+    /// Creates a new builder with `None` values for all optional fields.
+    pub fn new(input: ModerationInput) -> Self {
+        Self { input, model: None }
+    }
+
+    /// Builds the request body.
+    pub fn build(self) -> ModerationRequestBody {
+        ModerationRequestBody { input: self.input, model: self.model }
+    }
+
+    /// Sets the moderation model, e.g., `omni-moderation-latest`.
+    ///
+    /// Only the omni moderation models accept image inputs.
+    pub fn model<S: AsRef<str>>(mut self, model: S) -> Self {
+        self.model = Some(model.as_ref().to_string());
+        self
+    }
+}
+
+/// Input to classify.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum ModerationInput {
+    /// A single string.
+    Text(String),
+
+    /// An array of strings, each of which is classified separately.
+    Texts(Vec<String>),
+
+    /// An array of text and image inputs, which are classified together.
+    MultiModal(Vec<ModerationInputItem>),
+}
+
+impl ModerationInput {
+    pub fn text<S: AsRef<str>>(text: S) -> Self {
+        Self::Text(text.as_ref().to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ModerationInputItem {
+    Text(String),
+
+    /// An image URL or a base64-encoded data URL.
+    ImageUrl(String),
+}
+
+impl Serialize for ModerationInputItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        // Initialize a map with two entries
+        let mut map = serializer.serialize_map(Some(2))?;
+
+        match self {
+            ModerationInputItem::Text(text) => {
+                map.serialize_entry("type", "text")?;
+                map.serialize_entry("text", text)?;
+            }
+            ModerationInputItem::ImageUrl(url) => {
+                map.serialize_entry("type", "image_url")?;
+                map.serialize_entry("image_url", &json!({"url": url}))?;
+            }
+        }
+
+        // End serializing
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_moderation_request_body() {
+        let body = ModerationRequestBody::builder(ModerationInput::text("I want to kill them.")).build();
+        let json = serde_json::to_string(&body).unwrap();
+        assert_eq!(json, r#"{"input":"I want to kill them."}"#);
+
+        let body = ModerationRequestBody::builder(
+            ModerationInput::MultiModal(
+                vec![
+                    ModerationInputItem::Text("...text to classify goes here...".to_string()),
+                    ModerationInputItem::ImageUrl("https://example.com/image.png".to_string())
+                ]
+            )
+        )
+            .model("omni-moderation-latest")
+            .build();
+        let json = serde_json::to_string(&body).unwrap();
+        assert_eq!(
+            json,
+            r#"{"input":[{"type":"text","text":"...text to classify goes here..."},{"type":"image_url","image_url":{"url":"https://example.com/image.png"}}],"model":"omni-moderation-latest"}"#
+        );
+    }
+}
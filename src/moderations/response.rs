@@ -0,0 +1,240 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ModerationResponse {
+    pub id: String,
+    pub model: String,
+
+    /// A list of moderation results, one for each classified input.
+    pub results: Vec<ModerationResult>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ModerationResult {
+    /// Whether any of the categories are flagged.
+    pub flagged: bool,
+
+    /// Whether each category is flagged.
+    pub categories: ModerationCategories<bool>,
+
+    /// The scores of each category predicted by the model.
+    pub category_scores: ModerationCategories<f64>,
+
+    /// The input types that the score applies to for each category.
+    /// It is only present in the results of the omni moderation models.
+    pub category_applied_input_types: Option<ModerationCategories<Vec<ModerationInputType>>>,
+}
+
+/// A category of potentially harmful content.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModerationCategory {
+    #[serde(rename = "harassment")]
+    Harassment,
+
+    #[serde(rename = "harassment/threatening")]
+    HarassmentThreatening,
+
+    #[serde(rename = "hate")]
+    Hate,
+
+    #[serde(rename = "hate/threatening")]
+    HateThreatening,
+
+    #[serde(rename = "illicit")]
+    Illicit,
+
+    #[serde(rename = "illicit/violent")]
+    IllicitViolent,
+
+    #[serde(rename = "self-harm")]
+    SelfHarm,
+
+    #[serde(rename = "self-harm/intent")]
+    SelfHarmIntent,
+
+    #[serde(rename = "self-harm/instructions")]
+    SelfHarmInstructions,
+
+    #[serde(rename = "sexual")]
+    Sexual,
+
+    #[serde(rename = "sexual/minors")]
+    SexualMinors,
+
+    #[serde(rename = "violence")]
+    Violence,
+
+    #[serde(rename = "violence/graphic")]
+    ViolenceGraphic,
+}
+
+/// A value for each moderation category.
+///
+/// Categories missing in the response, e.g., `illicit` in the results of
+/// the legacy text moderation models, take the default value.
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct ModerationCategories<T: Default> {
+    #[serde(default)]
+    pub harassment: T,
+
+    #[serde(default, rename = "harassment/threatening")]
+    pub harassment_threatening: T,
+
+    #[serde(default)]
+    pub hate: T,
+
+    #[serde(default, rename = "hate/threatening")]
+    pub hate_threatening: T,
+
+    #[serde(default)]
+    pub illicit: T,
+
+    #[serde(default, rename = "illicit/violent")]
+    pub illicit_violent: T,
+
+    #[serde(default, rename = "self-harm")]
+    pub self_harm: T,
+
+    #[serde(default, rename = "self-harm/intent")]
+    pub self_harm_intent: T,
+
+    #[serde(default, rename = "self-harm/instructions")]
+    pub self_harm_instructions: T,
+
+    #[serde(default)]
+    pub sexual: T,
+
+    #[serde(default, rename = "sexual/minors")]
+    pub sexual_minors: T,
+
+    #[serde(default)]
+    pub violence: T,
+
+    #[serde(default, rename = "violence/graphic")]
+    pub violence_graphic: T,
+}
+
+impl<T: Default> ModerationCategories<T> {
+    /// Gets the value of each category.
+    pub fn iter(&self) -> impl Iterator<Item = (ModerationCategory, &T)> {
+        [
+            (ModerationCategory::Harassment, &self.harassment),
+            (ModerationCategory::HarassmentThreatening, &self.harassment_threatening),
+            (ModerationCategory::Hate, &self.hate),
+            (ModerationCategory::HateThreatening, &self.hate_threatening),
+            (ModerationCategory::Illicit, &self.illicit),
+            (ModerationCategory::IllicitViolent, &self.illicit_violent),
+            (ModerationCategory::SelfHarm, &self.self_harm),
+            (ModerationCategory::SelfHarmIntent, &self.self_harm_intent),
+            (ModerationCategory::SelfHarmInstructions, &self.self_harm_instructions),
+            (ModerationCategory::Sexual, &self.sexual),
+            (ModerationCategory::SexualMinors, &self.sexual_minors),
+            (ModerationCategory::Violence, &self.violence),
+            (ModerationCategory::ViolenceGraphic, &self.violence_graphic),
+        ].into_iter()
+    }
+
+    /// Gets the value of a category.
+    pub fn get(&self, category: ModerationCategory) -> &T {
+        match category {
+            ModerationCategory::Harassment => &self.harassment,
+            ModerationCategory::HarassmentThreatening => &self.harassment_threatening,
+            ModerationCategory::Hate => &self.hate,
+            ModerationCategory::HateThreatening => &self.hate_threatening,
+            ModerationCategory::Illicit => &self.illicit,
+            ModerationCategory::IllicitViolent => &self.illicit_violent,
+            ModerationCategory::SelfHarm => &self.self_harm,
+            ModerationCategory::SelfHarmIntent => &self.self_harm_intent,
+            ModerationCategory::SelfHarmInstructions => &self.self_harm_instructions,
+            ModerationCategory::Sexual => &self.sexual,
+            ModerationCategory::SexualMinors => &self.sexual_minors,
+            ModerationCategory::Violence => &self.violence,
+            ModerationCategory::ViolenceGraphic => &self.violence_graphic,
+        }
+    }
+}
+
+/// The type of input that a category score applies to.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ModerationInputType {
+    Text,
+    Image,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_moderation_response() {
+        let json =
+            r#"{
+            "id": "modr-0d9740456c391e43c445bf0f010940c7",
+            "model": "omni-moderation-latest",
+            "results": [
+                {
+                    "flagged": true,
+                    "categories": {
+                        "harassment": true,
+                        "harassment/threatening": true,
+                        "sexual": false,
+                        "hate": false,
+                        "hate/threatening": false,
+                        "illicit": false,
+                        "illicit/violent": false,
+                        "self-harm/intent": false,
+                        "self-harm/instructions": false,
+                        "self-harm": false,
+                        "sexual/minors": false,
+                        "violence": true,
+                        "violence/graphic": true
+                    },
+                    "category_scores": {
+                        "harassment": 0.8189,
+                        "harassment/threatening": 0.8047,
+                        "sexual": 0.0001,
+                        "hate": 0.0045,
+                        "hate/threatening": 0.0007,
+                        "illicit": 0.0309,
+                        "illicit/violent": 0.0102,
+                        "self-harm/intent": 0.0002,
+                        "self-harm/instructions": 0.0002,
+                        "self-harm": 0.0120,
+                        "sexual/minors": 0.0000,
+                        "violence": 0.9223,
+                        "violence/graphic": 0.3610
+                    },
+                    "category_applied_input_types": {
+                        "harassment": ["text"],
+                        "violence": ["text", "image"]
+                    }
+                }
+            ]
+        }"#;
+
+        let response: ModerationResponse = serde_json::from_str(json).unwrap();
+        let result = &response.results[0];
+
+        assert!(result.flagged);
+        assert!(*result.categories.get(ModerationCategory::HarassmentThreatening));
+        assert_eq!(*result.category_scores.get(ModerationCategory::Violence), 0.9223);
+        assert_eq!(
+            result.category_applied_input_types.as_ref().unwrap().violence,
+            vec![ModerationInputType::Text, ModerationInputType::Image]
+        );
+        assert_eq!(
+            result.categories
+                .iter()
+                .filter(|(_, flagged)| **flagged)
+                .map(|(category, _)| category)
+                .collect::<Vec<ModerationCategory>>(),
+            vec![
+                ModerationCategory::Harassment,
+                ModerationCategory::HarassmentThreatening,
+                ModerationCategory::Violence,
+                ModerationCategory::ViolenceGraphic
+            ]
+        );
+    }
+}
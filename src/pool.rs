@@ -0,0 +1,478 @@
+use std::{
+    sync::{ Arc, Mutex, atomic::{ AtomicU64, AtomicUsize, Ordering } },
+    time::{ Duration, Instant },
+};
+use reqwest::StatusCode;
+use log::warn;
+use crate::CredentialProvider;
+
+/// The default time a key is benched after being rate limited or rejected.
+const DEFAULT_BENCH_DURATION: Duration = Duration::from_secs(60);
+
+/// How requests are spread across the keys of a pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoolStrategy {
+    /// Use the keys in turn.
+    RoundRobin,
+
+    /// Use the key with the fewest requests awaiting a response.
+    LeastLoaded,
+}
+
+/// A key of the pool and its usage.
+struct PoolMember {
+    label: String,
+    credential: Arc<dyn CredentialProvider>,
+    base_url: Option<String>,
+
+    /// Number of requests whose response is not fully received.
+    in_flight: AtomicUsize,
+
+    requests: AtomicU64,
+    rate_limited: AtomicU64,
+    unauthorized: AtomicU64,
+    benched_until: Mutex<Option<Instant>>,
+}
+
+impl PoolMember {
+    fn benched_until(&self) -> Option<Instant> {
+        let benched_until = match self.benched_until.lock() {
+            Ok(benched_until) => *benched_until,
+            Err(poisoned) => *poisoned.into_inner(),
+        };
+
+        benched_until.filter(|benched_until| *benched_until > Instant::now())
+    }
+
+    fn bench(&self, duration: Duration) {
+        let mut benched_until = match self.benched_until.lock() {
+            Ok(benched_until) => benched_until,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        *benched_until = Some(Instant::now() + duration);
+    }
+}
+
+/// Health of a key of the pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyHealth {
+    pub label: String,
+
+    /// Time left until the key is used again, if it is benched.
+    pub benched_for: Option<Duration>,
+
+    pub in_flight: usize,
+    pub requests: u64,
+
+    /// Number of responses with the status 429.
+    pub rate_limited: u64,
+
+    /// Number of responses with the status 401.
+    pub unauthorized: u64,
+}
+
+/// A pool of credentials, possibly of different accounts or base URLs,
+/// across which the chat completion requests of a client are spread.
+///
+/// A key is benched for a while after a 429 or 401 response,
+/// and the chat completion request is then retried with another key.
+/// Other requests use the first key that is not benched, and they are not retried.
+pub struct CredentialPool {
+    members: Vec<PoolMember>,
+    strategy: PoolStrategy,
+    bench_duration: Duration,
+
+    /// The position of the round robin.
+    next: AtomicUsize,
+}
+
+impl CredentialPool {
+    /// Creates an empty pool.
+    pub fn new(strategy: PoolStrategy) -> Self {
+        Self {
+            members: vec![],
+            strategy,
+            bench_duration: DEFAULT_BENCH_DURATION,
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    /// Adds a key used with the base URL of the client.
+    pub fn member<S: AsRef<str>, C: CredentialProvider + 'static>(
+        self,
+        label: S,
+        credential: C
+    ) -> Self {
+        self.add_member(label.as_ref(), Arc::new(credential), None)
+    }
+
+    /// Adds a key used with its own base URL.
+    pub fn member_with_base_url<S: AsRef<str>, C: CredentialProvider + 'static, T: AsRef<str>>(
+        self,
+        label: S,
+        credential: C,
+        base_url: T
+    ) -> Self {
+        let base_url = base_url.as_ref().trim_end_matches('/').to_string();
+        self.add_member(label.as_ref(), Arc::new(credential), Some(base_url))
+    }
+
+    fn add_member(
+        mut self,
+        label: &str,
+        credential: Arc<dyn CredentialProvider>,
+        base_url: Option<String>
+    ) -> Self {
+        self.members.push(PoolMember {
+            label: label.to_string(),
+            credential,
+            base_url,
+            in_flight: AtomicUsize::new(0),
+            requests: AtomicU64::new(0),
+            rate_limited: AtomicU64::new(0),
+            unauthorized: AtomicU64::new(0),
+            benched_until: Mutex::new(None),
+        });
+        self
+    }
+
+    /// Sets how long a key is benched after a 429 or 401 response.
+    ///
+    /// It defaults to one minute.
+    pub fn bench_duration(mut self, duration: Duration) -> Self {
+        self.bench_duration = duration;
+        self
+    }
+
+    /// Checks if the pool has no keys.
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Reports the health of each key.
+    pub fn health(&self) -> Vec<KeyHealth> {
+        let now = Instant::now();
+
+        self.members
+            .iter()
+            .map(|member| KeyHealth {
+                label: member.label.clone(),
+                benched_for: member.benched_until().map(|benched_until| benched_until - now),
+                in_flight: member.in_flight.load(Ordering::SeqCst),
+                requests: member.requests.load(Ordering::SeqCst),
+                rate_limited: member.rate_limited.load(Ordering::SeqCst),
+                unauthorized: member.unauthorized.load(Ordering::SeqCst),
+            })
+            .collect()
+    }
+
+    /// Selects a key that is not benched and not tried yet for the request.
+    ///
+    /// If all keys are benched before the first try,
+    /// the one that will be back the soonest is selected.
+    pub(crate) fn select(&self, tried: &[usize]) -> Option<usize> {
+        let candidates: Vec<usize> = (0..self.members.len())
+            .filter(|index| !tried.contains(index))
+            .filter(|index| self.members[*index].benched_until().is_none())
+            .collect();
+
+        if candidates.is_empty() {
+            if !tried.is_empty() {
+                return None;
+            }
+
+            return (0..self.members.len()).min_by_key(|index| self.members[*index].benched_until());
+        }
+
+        match self.strategy {
+            PoolStrategy::RoundRobin => {
+                let start = self.next.fetch_add(1, Ordering::SeqCst) % self.members.len();
+                (0..self.members.len())
+                    .map(|offset| (start + offset) % self.members.len())
+                    .find(|index| candidates.contains(index))
+            }
+            PoolStrategy::LeastLoaded => {
+                candidates
+                    .into_iter()
+                    .min_by_key(|index| self.members[*index].in_flight.load(Ordering::SeqCst))
+            }
+        }
+    }
+
+    /// Selects the key for requests other than chat completions,
+    /// i.e., the first one not benched.
+    pub(crate) fn primary(&self) -> Option<usize> {
+        (0..self.members.len())
+            .find(|index| self.members[*index].benched_until().is_none())
+            .or(if self.members.is_empty() { None } else { Some(0) })
+    }
+
+    pub(crate) fn credential(&self, index: usize) -> &Arc<dyn CredentialProvider> {
+        &self.members[index].credential
+    }
+
+    pub(crate) fn base_url(&self, index: usize) -> Option<&str> {
+        self.members[index].base_url.as_deref()
+    }
+
+    /// Marks a request sent with the key as in flight until the guard is dropped.
+    ///
+    /// The guard owns a reference to the pool,
+    /// so that it can be kept until the body of the response is received, e.g., by a stream.
+    pub(crate) fn dispatch(self: &Arc<Self>, index: usize) -> InFlightGuard {
+        let member = &self.members[index];
+        member.requests.fetch_add(1, Ordering::SeqCst);
+        member.in_flight.fetch_add(1, Ordering::SeqCst);
+
+        InFlightGuard { pool: Arc::clone(self), index }
+    }
+
+    /// Records the status of a response to a request sent with the key,
+    /// and returns whether the key is benched so that the request should be retried.
+    pub(crate) fn report(&self, index: usize, status: StatusCode) -> bool {
+        let member = &self.members[index];
+
+        let counter = match status {
+            StatusCode::TOO_MANY_REQUESTS => &member.rate_limited,
+            StatusCode::UNAUTHORIZED => &member.unauthorized,
+            _ => {
+                return false;
+            }
+        };
+        counter.fetch_add(1, Ordering::SeqCst);
+
+        // Warn the user
+        warn!(
+            "key {} received the status {}, it is benched for {:?}",
+            member.label,
+            status,
+            self.bench_duration
+        );
+        member.bench(self.bench_duration);
+
+        true
+    }
+}
+
+/// Decrements the in-flight count of a key when dropped.
+pub(crate) struct InFlightGuard {
+    pool: Arc<CredentialPool>,
+    index: usize,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.pool.members[self.index].in_flight.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use serde_json::json;
+    use crate::{ OpenAIClient, Result, StaticCredential, chat::*, user_message };
+    use crate::chat::fixtures::chat_completion_body;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_round_robin_with_failover() -> Result<()> {
+        // Start mock servers, the second of which serves another account
+        let mut server = mockito::Server::new_async().await;
+        let mut other_server = mockito::Server::new_async().await;
+        let first_mock = server
+            .mock("POST", "/chat/completions")
+            .match_header("authorization", "Bearer sk-first")
+            .with_header("content-type", "application/json")
+            .with_body(chat_completion_body())
+            .expect(1)
+            .create_async().await;
+        let second_mock = other_server
+            .mock("POST", "/chat/completions")
+            .match_header("authorization", "Bearer sk-second")
+            .with_header("content-type", "application/json")
+            .with_body(chat_completion_body())
+            .expect(2)
+            .create_async().await;
+
+        let pool = Arc::new(
+            CredentialPool::new(PoolStrategy::RoundRobin)
+                .member("first", StaticCredential::new("sk-first"))
+                .member_with_base_url(
+                    "second",
+                    StaticCredential::new("sk-second"),
+                    other_server.url()
+                )
+        );
+        let client = OpenAIClient::builder()
+            .base_url(server.url())
+            .credential_pool(Arc::clone(&pool))
+            .build()?;
+
+        // The keys are used in turn
+        let request_body = ChatRequestBody::builder("gpt-4o", vec![user_message!("Hi")]).build();
+        create_chat_completion(&client, &request_body).await?;
+        create_chat_completion(&client, &request_body).await?;
+        first_mock.assert_async().await;
+
+        // The first key is rate limited, so the request fails over to the second key
+        let rate_limited_mock = server
+            .mock("POST", "/chat/completions")
+            .match_header("authorization", "Bearer sk-first")
+            .with_status(429)
+            .create_async().await;
+        let chat_completion = create_chat_completion(&client, &request_body).await?;
+        assert_eq!(chat_completion.choices[0].message.content.as_deref(), Some("Hello!"));
+        rate_limited_mock.assert_async().await;
+        second_mock.assert_async().await;
+
+        let health = pool.health();
+        assert_eq!(health[0].requests, 2);
+        assert_eq!(health[0].rate_limited, 1);
+        assert!(health[0].benched_for.is_some());
+        assert_eq!(health[1].requests, 2);
+        assert_eq!(health[1].benched_for, None);
+        assert_eq!(health[1].in_flight, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_benched_keys() {
+        let pool = Arc::new(
+            CredentialPool::new(PoolStrategy::LeastLoaded)
+                .member("first", StaticCredential::new("sk-first"))
+                .member("second", StaticCredential::new("sk-second"))
+        );
+
+        // The least loaded key is selected
+        let _guard = pool.dispatch(0);
+        assert_eq!(pool.select(&[]), Some(1));
+
+        // No other key is left after both are benched
+        assert!(pool.report(1, StatusCode::UNAUTHORIZED));
+        assert!(!pool.report(0, StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(pool.report(0, StatusCode::TOO_MANY_REQUESTS));
+        assert_eq!(pool.select(&[0]), None);
+
+        // The key back the soonest is still selected for a new request
+        assert_eq!(pool.select(&[]), Some(1));
+        assert_eq!(pool.primary(), Some(0));
+    }
+
+    #[tokio::test]
+    async fn test_primary_key_and_base_url_match() -> Result<()> {
+        // Start mock servers for two accounts
+        let mut server = mockito::Server::new_async().await;
+        let other_server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/models")
+            .match_header("authorization", "Bearer sk-first")
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "object": "list", "data": [] }).to_string())
+            .create_async().await;
+
+        let pool = Arc::new(
+            CredentialPool::new(PoolStrategy::RoundRobin)
+                .member_with_base_url("first", StaticCredential::new("sk-first"), server.url())
+                .member_with_base_url(
+                    "second",
+                    StaticCredential::new("sk-second"),
+                    other_server.url()
+                )
+        );
+        let client = OpenAIClient::builder().credential_pool(Arc::clone(&pool)).build()?;
+
+        // The primary key is benched between building the URL and setting the key,
+        // but the request still uses the key of the first member
+        client.send(|client| {
+            let url = client.endpoint("models");
+            pool.report(0, StatusCode::TOO_MANY_REQUESTS);
+            client.get(url)
+        }).await?;
+        mock.assert_async().await;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_primary_key_benched_after_rejection() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let rejected_mock = server
+            .mock("GET", "/models")
+            .match_header("authorization", "Bearer sk-first")
+            .with_status(401)
+            .create_async().await;
+        let mock = server
+            .mock("GET", "/models")
+            .match_header("authorization", "Bearer sk-second")
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "object": "list", "data": [] }).to_string())
+            .create_async().await;
+
+        let pool = Arc::new(
+            CredentialPool::new(PoolStrategy::RoundRobin)
+                .member("first", StaticCredential::new("sk-first"))
+                .member("second", StaticCredential::new("sk-second"))
+        );
+        let client = OpenAIClient::builder()
+            .base_url(server.url())
+            .credential_pool(Arc::clone(&pool))
+            .build()?;
+
+        // The request is not retried, but the rejected key is benched
+        let result = crate::models::list_models(&client).await;
+        assert!(matches!(result, Err(crate::Error::Authentication)));
+        rejected_mock.assert_async().await;
+
+        let health = pool.health();
+        assert_eq!(health[0].requests, 1);
+        assert_eq!(health[0].unauthorized, 1);
+        assert!(health[0].benched_for.is_some());
+        assert_eq!(health[0].in_flight, 0);
+
+        // The next request uses the second key
+        crate::models::list_models(&client).await?;
+        mock.assert_async().await;
+        assert_eq!(pool.health()[1].requests, 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_stream_keeps_request_in_flight() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/chat/completions")
+            .with_header("content-type", "text/event-stream")
+            .with_body("data: [DONE]\n\n")
+            .create_async().await;
+
+        let pool = Arc::new(
+            CredentialPool::new(PoolStrategy::LeastLoaded).member(
+                "first",
+                StaticCredential::new("sk-first")
+            )
+        );
+        let client = OpenAIClient::builder()
+            .base_url(server.url())
+            .credential_pool(Arc::clone(&pool))
+            .build()?;
+
+        // The request is in flight until the stream is dropped
+        let request_body = ChatRequestBody::builder("gpt-4o", vec![user_message!("Hi")]).build();
+        let mut stream = create_chat_completion_stream(&client, &request_body, false).await?;
+        assert_eq!(pool.health()[0].in_flight, 1);
+
+        assert!(stream.next().await.is_none());
+        assert_eq!(pool.health()[0].in_flight, 1);
+
+        drop(stream);
+        assert_eq!(pool.health()[0].in_flight, 0);
+        mock.assert_async().await;
+
+        Ok(())
+    }
+}
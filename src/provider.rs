@@ -0,0 +1,291 @@
+use std::collections::HashMap;
+use serde_json::Value;
+
+/// How the API key is sent to the provider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthScheme {
+    /// The key is sent in the `Authorization: Bearer` header.
+    Bearer,
+
+    /// No credentials are sent, e.g., to a local server.
+    None,
+}
+
+/// How the provider reports an error in the response body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorFormat {
+    /// The error is mapped by the status code as with the OpenAI API.
+    OpenAI,
+
+    /// The error is `Error::Provider` with the message in the body,
+    /// i.e., `{"error": {"message": ...}}`, `{"error": ...}` or `{"message": ...}`,
+    /// or the raw text of the body otherwise.
+    Body,
+}
+
+impl ErrorFormat {
+    /// Gets the message of the error in the response body.
+    pub(crate) fn message(body: &str) -> String {
+        let value = match serde_json::from_str::<Value>(body) {
+            Ok(value) => value,
+            Err(_) => {
+                return body.to_string();
+            }
+        };
+
+        let message = match value.get("error") {
+            Some(Value::Object(error)) => error.get("message"),
+            Some(error) => Some(error),
+            None => value.get("message"),
+        };
+
+        match message {
+            Some(Value::String(message)) => message.to_string(),
+            _ => body.to_string(),
+        }
+    }
+}
+
+/// A policy that strips or renames the fields of chat request bodies
+/// which an OpenAI-compatible provider does not support.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FieldPolicy {
+    stripped: Vec<String>,
+    renamed: Vec<(String, String)>,
+}
+
+impl FieldPolicy {
+    /// Creates a policy that keeps all fields as they are.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Removes the field from request bodies.
+    pub fn strip<S: AsRef<str>>(mut self, field: S) -> Self {
+        self.stripped.push(field.as_ref().to_string());
+        self
+    }
+
+    /// Sends the field under another name.
+    pub fn rename<S: AsRef<str>, T: AsRef<str>>(mut self, from: S, to: T) -> Self {
+        self.renamed.push((from.as_ref().to_string(), to.as_ref().to_string()));
+        self
+    }
+
+    /// Applies the policy to a request body in place.
+    pub(crate) fn apply(&self, request_body: &mut HashMap<String, Value>) {
+        for field in self.stripped.iter() {
+            request_body.remove(field);
+        }
+
+        for (from, to) in self.renamed.iter() {
+            if let Some(value) = request_body.remove(from) {
+                request_body.insert(to.to_string(), value);
+            }
+        }
+    }
+}
+
+/// Settings of an OpenAI-compatible provider,
+/// i.e., its base URL, auth scheme, the fields it does not support and its error format.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderProfile {
+    base_url: String,
+    auth_scheme: AuthScheme,
+    field_policy: FieldPolicy,
+    error_format: ErrorFormat,
+}
+
+impl ProviderProfile {
+    /// Creates a profile of a custom provider,
+    /// which uses bearer authentication, keeps all fields
+    /// and reports errors as the OpenAI API does by default.
+    pub fn new<S: AsRef<str>>(base_url: S) -> Self {
+        Self {
+            base_url: base_url.as_ref().trim_end_matches('/').to_string(),
+            auth_scheme: AuthScheme::Bearer,
+            field_policy: FieldPolicy::new(),
+            error_format: ErrorFormat::OpenAI,
+        }
+    }
+
+    /// Sets the auth scheme.
+    pub fn auth_scheme(mut self, auth_scheme: AuthScheme) -> Self {
+        self.auth_scheme = auth_scheme;
+        self
+    }
+
+    /// Sets the field compatibility policy.
+    pub fn field_policy(mut self, field_policy: FieldPolicy) -> Self {
+        self.field_policy = field_policy;
+        self
+    }
+
+    /// Sets how the provider reports errors.
+    pub fn error_format(mut self, error_format: ErrorFormat) -> Self {
+        self.error_format = error_format;
+        self
+    }
+
+    /// The OpenAI API itself.
+    pub fn openai() -> Self {
+        Self::new("https://api.openai.com/v1")
+    }
+
+    /// A local Ollama server, which ignores credentials
+    /// and rejects streaming options.
+    pub fn ollama() -> Self {
+        Self::new("http://localhost:11434/v1")
+            .auth_scheme(AuthScheme::None)
+            .field_policy(FieldPolicy::new().strip("stream_options"))
+            .error_format(ErrorFormat::Body)
+    }
+
+    /// A local vLLM server started without `--api-key`.
+    pub fn vllm() -> Self {
+        Self::new("http://localhost:8000/v1")
+            .auth_scheme(AuthScheme::None)
+            .error_format(ErrorFormat::Body)
+    }
+
+    /// A local LM Studio server, which does not support
+    /// multiple choices or streaming options.
+    pub fn lm_studio() -> Self {
+        Self::new("http://localhost:1234/v1")
+            .auth_scheme(AuthScheme::None)
+            .field_policy(FieldPolicy::new().strip("n").strip("stream_options"))
+            .error_format(ErrorFormat::Body)
+    }
+
+    /// The Together AI API.
+    pub fn together() -> Self {
+        Self::new("https://api.together.xyz/v1")
+    }
+
+    /// The Groq API, which only supports a single choice.
+    pub fn groq() -> Self {
+        Self::new("https://api.groq.com/openai/v1").field_policy(FieldPolicy::new().strip("n"))
+    }
+
+    /// Splits the profile into the settings kept by the client.
+    pub(crate) fn into_parts(self) -> (String, AuthScheme, FieldPolicy, ErrorFormat) {
+        (self.base_url, self.auth_scheme, self.field_policy, self.error_format)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use mockito::Matcher;
+    use serde_json::json;
+    use crate::{ OpenAIClient, Result, Error, chat::*, user_message };
+    use crate::chat::fixtures::chat_completion_chunk_body;
+    use super::*;
+
+    #[test]
+    fn test_apply_field_policy() {
+        let mut request_body: HashMap<String, Value> = serde_json
+            ::from_value(json!({ "model": "llama3", "n": 2, "max_tokens": 10 }))
+            .unwrap();
+
+        FieldPolicy::new().strip("n").rename("max_tokens", "num_predict").apply(&mut request_body);
+
+        assert_eq!(
+            serde_json::to_value(request_body).unwrap(),
+            json!({ "model": "llama3", "num_predict": 10 })
+        );
+    }
+
+    #[test]
+    fn test_error_message() {
+        // OpenAI and vLLM
+        assert_eq!(
+            ErrorFormat::message(r#"{"error":{"message":"Invalid model","type":"invalid"}}"#),
+            "Invalid model"
+        );
+        assert_eq!(
+            ErrorFormat::message(r#"{"object":"error","message":"Invalid model","code":400}"#),
+            "Invalid model"
+        );
+
+        // Ollama
+        assert_eq!(ErrorFormat::message(r#"{"error":"model not found"}"#), "model not found");
+
+        // Any other body
+        assert_eq!(ErrorFormat::message("Bad Gateway"), "Bad Gateway");
+        assert_eq!(ErrorFormat::message(r#"{"detail":"Not Found"}"#), r#"{"detail":"Not Found"}"#);
+    }
+
+    #[tokio::test]
+    async fn test_provider_error_keeps_message() -> Result<()> {
+        // Start a mock server standing in for a local Ollama server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/chat/completions")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"error":"model \"llama3\" not found, try pulling it first"}"#)
+            .create_async().await;
+
+        let client = OpenAIClient::builder()
+            .provider(ProviderProfile::ollama())
+            .base_url(server.url())
+            .build()?;
+
+        let request_body = ChatRequestBody::builder("llama3", vec![user_message!("Hi")]).build();
+        let error = create_chat_completion(&client, &request_body).await.unwrap_err();
+        mock.assert_async().await;
+
+        match error {
+            Error::Provider { status_code, message } => {
+                assert_eq!(status_code, reqwest::StatusCode::NOT_FOUND);
+                assert_eq!(message, r#"model "llama3" not found, try pulling it first"#);
+            }
+            error => panic!("unexpected error: {:?}", error),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_local_provider_without_auth() -> Result<()> {
+        // Start a mock server standing in for a local Ollama server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/chat/completions")
+            .match_header("authorization", Matcher::Missing)
+            .match_body(
+                Matcher::Json(
+                    json!({
+                        "model": "llama3",
+                        "messages": [{ "role": "user", "content": "Hi" }],
+                        "stream": true
+                    })
+                )
+            )
+            .with_header("content-type", "text/event-stream")
+            .with_body(
+                format!(
+                    "data: {}\n\ndata: [DONE]\n\n",
+                    chat_completion_chunk_body("Hello")
+                )
+            )
+            .create_async().await;
+
+        // No API key is needed
+        let client = OpenAIClient::builder()
+            .provider(ProviderProfile::ollama())
+            .base_url(server.url())
+            .build()?;
+
+        let request_body = ChatRequestBody::builder("llama3", vec![user_message!("Hi")]).build();
+        let mut stream = create_chat_completion_stream(&client, &request_body, true).await?;
+
+        let chunk = stream.next().await.unwrap()?;
+        assert_eq!(chunk.choices[0].delta.content.as_deref(), Some("Hello"));
+        assert!(stream.next().await.is_none());
+        mock.assert_async().await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,473 @@
+use std::{ collections::HashMap, sync::{ Arc, Mutex }, time::Duration };
+use reqwest::header::{ HeaderMap, RETRY_AFTER };
+use tokio::time::Instant;
+use serde_json::Value;
+use log::debug;
+use crate::ResponseMeta;
+
+/// The header of the number of requests left in the current window.
+const REMAINING_REQUESTS_HEADER: &str = "x-ratelimit-remaining-requests";
+
+/// The header of the number of tokens left in the current window.
+const REMAINING_TOKENS_HEADER: &str = "x-ratelimit-remaining-tokens";
+
+/// How long a model is paused after a 429 response telling no time to wait.
+const DEFAULT_RATE_LIMITED_PAUSE: Duration = Duration::from_secs(1);
+
+/// How many times a rate limited request is retried after the pause.
+pub(crate) const MAX_RATE_LIMITED_RETRIES: usize = 3;
+
+/// Rough number of characters per token used to estimate the tokens of a request.
+const CHARS_PER_TOKEN: usize = 4;
+
+/// Requests and tokens allowed per minute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimits {
+    pub requests_per_minute: u32,
+    pub tokens_per_minute: u32,
+}
+
+impl RateLimits {
+    pub fn new(requests_per_minute: u32, tokens_per_minute: u32) -> Self {
+        Self { requests_per_minute, tokens_per_minute }
+    }
+}
+
+/// A bucket refilled continuously at its capacity per minute.
+#[derive(Debug)]
+struct TokenBucket {
+    capacity: f64,
+    available: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(capacity: u32) -> Self {
+        Self {
+            capacity: capacity.max(1) as f64,
+            available: capacity.max(1) as f64,
+            refilled_at: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.refilled_at).as_secs_f64();
+        self.available = (self.available + (elapsed * self.capacity) / 60.0).min(self.capacity);
+        self.refilled_at = now;
+    }
+
+    /// Time to wait until the amount is available.
+    fn wait_for(&self, amount: f64) -> Duration {
+        let deficit = amount - self.available;
+        if deficit <= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64((deficit * 60.0) / self.capacity)
+        }
+    }
+}
+
+/// The request and token buckets of a model.
+#[derive(Debug)]
+struct ModelBuckets {
+    requests: TokenBucket,
+    tokens: TokenBucket,
+
+    /// Until when no request is sent after a 429 response.
+    paused_until: Option<Instant>,
+}
+
+/// A client-side rate limiter tracking the requests and tokens per minute of each model,
+/// which makes chat completion calls wait for capacity
+/// instead of failing with `Error::ExceedRateLimitOrQuota`.
+/// Other calls, e.g., of the legacy completions and responses APIs,
+/// neither wait nor count toward the limits.
+///
+/// The tokens of a request are estimated before it is sent
+/// and reconciled with the reported usage afterward.
+/// The remaining capacity is also lowered to that reported
+/// in the `x-ratelimit-remaining-*` response headers.
+/// After a 429 response, the model is paused for the time told by the response headers
+/// and the request is retried up to three times.
+#[derive(Debug)]
+pub struct RateLimiter {
+    default_limits: RateLimits,
+    model_limits: HashMap<String, RateLimits>,
+    buckets: Mutex<HashMap<String, ModelBuckets>>,
+}
+
+impl RateLimiter {
+    /// Creates a limiter applying the limits to each model.
+    pub fn new(default_limits: RateLimits) -> Self {
+        Self { default_limits, model_limits: HashMap::new(), buckets: Mutex::new(HashMap::new()) }
+    }
+
+    /// Sets the limits of a model.
+    pub fn model_limits<S: AsRef<str>>(mut self, model: S, limits: RateLimits) -> Self {
+        self.model_limits.insert(model.as_ref().to_string(), limits);
+        self
+    }
+
+    /// Runs the closure on the buckets of the model.
+    fn with_buckets<T, F: FnOnce(&mut ModelBuckets) -> T>(&self, model: &str, f: F) -> T {
+        let mut buckets = match self.buckets.lock() {
+            Ok(buckets) => buckets,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        let limits = self.model_limits.get(model).copied().unwrap_or(self.default_limits);
+        let model_buckets = buckets.entry(model.to_string()).or_insert_with(|| ModelBuckets {
+            requests: TokenBucket::new(limits.requests_per_minute),
+            tokens: TokenBucket::new(limits.tokens_per_minute),
+            paused_until: None,
+        });
+        model_buckets.requests.refill();
+        model_buckets.tokens.refill();
+
+        f(model_buckets)
+    }
+
+    /// Waits until a request with the estimated tokens can be sent, and takes the capacity.
+    ///
+    /// It returns the number of tokens taken,
+    /// which is capped at the limit so that large requests do not wait forever.
+    pub(crate) async fn acquire(&self, model: &str, estimated_tokens: u32) -> u32 {
+        loop {
+            let result = self.with_buckets(model, |buckets| {
+                let tokens = (estimated_tokens as f64).min(buckets.tokens.capacity);
+                let paused_for = buckets.paused_until
+                    .map(|paused_until| paused_until.saturating_duration_since(Instant::now()))
+                    .unwrap_or_default();
+                let wait = buckets.requests
+                    .wait_for(1.0)
+                    .max(buckets.tokens.wait_for(tokens))
+                    .max(paused_for);
+                if wait.is_zero() {
+                    buckets.requests.available -= 1.0;
+                    buckets.tokens.available -= tokens;
+                    Ok(tokens as u32)
+                } else {
+                    Err(wait)
+                }
+            });
+
+            match result {
+                Ok(tokens) => {
+                    return tokens;
+                }
+                Err(wait) => {
+                    debug!("rate limit of {} reached, waiting for {:?}", model, wait);
+                    tokio::time::sleep(wait).await;
+                }
+            }
+        }
+    }
+
+    /// Gives back the tokens overestimated for a request, or takes those underestimated.
+    pub(crate) fn reconcile(&self, model: &str, estimated_tokens: u32, actual_tokens: u32) {
+        self.with_buckets(model, |buckets| {
+            let difference = (estimated_tokens as f64) - (actual_tokens as f64);
+            buckets.tokens.available = (buckets.tokens.available + difference).min(
+                buckets.tokens.capacity
+            );
+        })
+    }
+
+    /// Lowers the available capacity to the remaining requests and tokens reported by the API.
+    pub(crate) fn update_from_headers(&self, model: &str, headers: &HeaderMap) {
+        let remaining = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<f64>().ok())
+        };
+        let remaining_requests = remaining(REMAINING_REQUESTS_HEADER);
+        let remaining_tokens = remaining(REMAINING_TOKENS_HEADER);
+
+        self.with_buckets(model, |buckets| {
+            if let Some(remaining_requests) = remaining_requests {
+                buckets.requests.available = buckets.requests.available.min(remaining_requests);
+            }
+            if let Some(remaining_tokens) = remaining_tokens {
+                buckets.tokens.available = buckets.tokens.available.min(remaining_tokens);
+            }
+        })
+    }
+
+    /// Pauses the model after a 429 response until the time told by the API,
+    /// i.e., the `retry-after` header in seconds,
+    /// or else the longest of the `x-ratelimit-reset-*` headers.
+    pub(crate) fn pause_from_headers(&self, model: &str, headers: &HeaderMap) {
+        let meta = ResponseMeta::from_headers(headers);
+        let retry_after = headers
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<f64>().ok())
+            .and_then(|secs| Duration::try_from_secs_f64(secs).ok());
+        let pause = retry_after
+            .or(meta.reset_requests.max(meta.reset_tokens))
+            .unwrap_or(DEFAULT_RATE_LIMITED_PAUSE);
+
+        debug!("rate limited by the API, pausing {} for {:?}", model, pause);
+        self.update_from_headers(model, headers);
+        self.with_buckets(model, |buckets| {
+            buckets.paused_until = Some(Instant::now() + pause);
+        })
+    }
+
+    /// Gets the requests and tokens available for the model now.
+    pub fn available(&self, model: &str) -> (u32, u32) {
+        self.with_buckets(model, |buckets| {
+            (buckets.requests.available.max(0.0) as u32, buckets.tokens.available.max(0.0) as u32)
+        })
+    }
+}
+
+/// The tokens taken for a streamed request,
+/// which are reconciled when the usage arrives in the last chunk.
+///
+/// The estimated tokens are kept if the stream ends without the usage.
+pub(crate) struct PendingUsage {
+    rate_limiter: Arc<RateLimiter>,
+    model: String,
+    estimated_tokens: u32,
+}
+
+impl PendingUsage {
+    pub(crate) fn new(
+        rate_limiter: Arc<RateLimiter>,
+        model: String,
+        estimated_tokens: u32
+    ) -> Self {
+        Self { rate_limiter, model, estimated_tokens }
+    }
+
+    /// Replaces the estimated tokens with the used ones.
+    pub(crate) fn reconcile(self, actual_tokens: u32) {
+        self.rate_limiter.reconcile(&self.model, self.estimated_tokens, actual_tokens);
+    }
+}
+
+/// Estimates the tokens of a chat request body,
+/// i.e., those of the messages and the maximum number of completion tokens.
+pub(crate) fn estimate_chat_tokens(request_body: &HashMap<String, Value>) -> u32 {
+    let prompt_tokens = request_body
+        .get("messages")
+        .map(|messages| messages.to_string().len().div_ceil(CHARS_PER_TOKEN))
+        .unwrap_or(0);
+    let completion_tokens = request_body
+        .get("max_tokens")
+        .and_then(Value::as_u64)
+        .unwrap_or(0) as usize;
+
+    (prompt_tokens + completion_tokens).min(u32::MAX as usize) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use reqwest::header::HeaderValue;
+    use serde_json::json;
+    use crate::{ OpenAIClient, Result, Error, chat::*, user_message };
+    use crate::chat::fixtures::chat_completion_body;
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_acquire_waits_for_capacity() {
+        let limiter = RateLimiter::new(RateLimits::new(600, 60_000)).model_limits(
+            "gpt-4o",
+            RateLimits::new(1200, 1000)
+        );
+
+        // The model has its own limits
+        assert_eq!(limiter.acquire("gpt-4o", 1500).await, 1000);
+        assert_eq!(limiter.available("gpt-4o").1, 0);
+
+        // With the 60 overestimated tokens given back, 40 more tokens are needed,
+        // which are refilled in 2.4 seconds at 1000 tokens per minute
+        let started_at = Instant::now();
+        limiter.reconcile("gpt-4o", 1000, 940);
+        limiter.acquire("gpt-4o", 100).await;
+        let elapsed = started_at.elapsed();
+        assert!(elapsed >= Duration::from_millis(2400));
+        assert!(elapsed < Duration::from_millis(2500));
+
+        // Other models have the default limits
+        assert_eq!(limiter.available("gpt-4o-mini"), (600, 60_000));
+    }
+
+    #[test]
+    fn test_update_from_headers() {
+        let limiter = RateLimiter::new(RateLimits::new(500, 30_000));
+
+        let mut headers = HeaderMap::new();
+        headers.insert(REMAINING_REQUESTS_HEADER, HeaderValue::from_static("12"));
+        headers.insert(REMAINING_TOKENS_HEADER, HeaderValue::from_static("2000"));
+        limiter.update_from_headers("gpt-4o", &headers);
+
+        assert_eq!(limiter.available("gpt-4o"), (12, 2000));
+    }
+
+    #[tokio::test]
+    async fn test_chat_completion_with_rate_limiter() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/chat/completions")
+            .with_header("content-type", "application/json")
+            .with_header(REMAINING_REQUESTS_HEADER, "99")
+            .with_header(REMAINING_TOKENS_HEADER, "9000")
+            .with_body(chat_completion_body())
+            .create_async().await;
+
+        let limiter = Arc::new(RateLimiter::new(RateLimits::new(100, 10_000)));
+        let client = OpenAIClient::builder()
+            .api_key("sk-test")
+            .base_url(server.url())
+            .rate_limiter(Arc::clone(&limiter))
+            .build()?;
+
+        let request_body = ChatRequestBody::builder("gpt-4o", vec![user_message!("Hi")])
+            .max_tokens(100)
+            .build();
+        create_chat_completion(&client, &request_body).await?;
+        mock.assert_async().await;
+
+        // The capacity is lowered to the remaining tokens reported
+        // after the estimated tokens are replaced by the 11 tokens used
+        assert_eq!(limiter.available("gpt-4o"), (99, 9000));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_failed_chat_completion_gives_back_tokens() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server.mock("POST", "/chat/completions").with_status(500).create_async().await;
+
+        let limiter = Arc::new(RateLimiter::new(RateLimits::new(100, 100)));
+        let client = OpenAIClient::builder()
+            .api_key("sk-test")
+            .base_url(server.url())
+            .rate_limiter(Arc::clone(&limiter))
+            .build()?;
+
+        let request_body = ChatRequestBody::builder("gpt-4o", vec![user_message!("Hi")]).build();
+        assert!(create_chat_completion(&client, &request_body).await.is_err());
+        mock.assert_async().await;
+
+        // The request is counted but no tokens are used
+        assert_eq!(limiter.available("gpt-4o"), (99, 100));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_chat_completion_stream_reconciles_usage() -> Result<()> {
+        // Start a mock server sending the usage in the last chunk
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/chat/completions")
+            .with_header("content-type", "text/event-stream")
+            .with_body(
+                format!(
+                    "data: {}\n\ndata: [DONE]\n\n",
+                    json!({
+                        "id": "chatcmpl-123",
+                        "object": "chat.completion.chunk",
+                        "created": 1677652288,
+                        "model": "gpt-4o",
+                        "choices": [],
+                        "usage": { "prompt_tokens": 9, "completion_tokens": 41, "total_tokens": 50 }
+                    })
+                )
+            )
+            .create_async().await;
+
+        let limiter = Arc::new(RateLimiter::new(RateLimits::new(100, 100)));
+        let client = OpenAIClient::builder()
+            .api_key("sk-test")
+            .base_url(server.url())
+            .rate_limiter(Arc::clone(&limiter))
+            .build()?;
+
+        let request_body = ChatRequestBody::builder("gpt-4o", vec![user_message!("Hi")]).build();
+        let mut stream = create_chat_completion_stream(&client, &request_body, true).await?;
+        while let Some(chunk) = stream.next().await {
+            chunk?;
+        }
+        mock.assert_async().await;
+
+        // The estimated tokens are replaced by the 50 tokens used
+        assert_eq!(limiter.available("gpt-4o"), (99, 50));
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limited_chat_completion_is_retried() -> Result<()> {
+        // Start a mock server rate limiting the first request
+        let mut server = mockito::Server::new_async().await;
+        let rate_limited_mock = server
+            .mock("POST", "/chat/completions")
+            .with_status(429)
+            .with_header("retry-after", "2")
+            .with_header(REMAINING_REQUESTS_HEADER, "0")
+            .expect(1)
+            .create_async().await;
+        let mock = server
+            .mock("POST", "/chat/completions")
+            .with_header("content-type", "application/json")
+            .with_body(chat_completion_body())
+            .create_async().await;
+
+        let limiter = Arc::new(RateLimiter::new(RateLimits::new(100, 10_000)));
+        let client = OpenAIClient::builder()
+            .api_key("sk-test")
+            .base_url(server.url())
+            .rate_limiter(Arc::clone(&limiter))
+            .build()?;
+
+        // The request is retried after the time told by the API
+        let started_at = Instant::now();
+        let request_body = ChatRequestBody::builder("gpt-4o", vec![user_message!("Hi")]).build();
+        let chat_completion = create_chat_completion(&client, &request_body).await?;
+        assert!(started_at.elapsed() >= Duration::from_secs(2));
+        assert_eq!(chat_completion.usage.total_tokens, 11);
+        rate_limited_mock.assert_async().await;
+        mock.assert_async().await;
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limited_retries_are_bounded() -> Result<()> {
+        // Start a mock server always rate limiting the requests
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/chat/completions")
+            .with_status(429)
+            .with_header("x-ratelimit-reset-requests", "1s")
+            .with_header("x-ratelimit-reset-tokens", "500ms")
+            .expect(MAX_RATE_LIMITED_RETRIES + 1)
+            .create_async().await;
+
+        let client = OpenAIClient::builder()
+            .api_key("sk-test")
+            .base_url(server.url())
+            .rate_limiter(Arc::new(RateLimiter::new(RateLimits::new(100, 100))))
+            .build()?;
+
+        // The error is returned after the last retry, each paused for the longest reset
+        let started_at = Instant::now();
+        let request_body = ChatRequestBody::builder("gpt-4o", vec![user_message!("Hi")]).build();
+        let result = create_chat_completion(&client, &request_body).await;
+        assert!(matches!(result, Err(Error::ExceedRateLimitOrQuota)));
+        assert!(started_at.elapsed() >= Duration::from_secs(3));
+        mock.assert_async().await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+use serde::Serialize;
+use serde_json::Value;
+use base64::{ Engine, engine::general_purpose::STANDARD };
+use crate::assistants::FunctionDefinition;
+
+/// An event sent by the client in a realtime session.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum RealtimeClientEvent {
+    /// Updates the configuration of the session.
+    #[serde(rename = "session.update")] SessionUpdate {
+        session: SessionConfig,
+    },
+
+    /// Appends base64-encoded audio to the input audio buffer.
+    #[serde(rename = "input_audio_buffer.append")] InputAudioBufferAppend {
+        audio: String,
+    },
+
+    /// Commits the input audio buffer as a user message,
+    /// which is only needed without server voice activity detection.
+    #[serde(rename = "input_audio_buffer.commit")]
+    InputAudioBufferCommit,
+
+    #[serde(rename = "input_audio_buffer.clear")]
+    InputAudioBufferClear,
+
+    /// Adds an item to the conversation.
+    #[serde(rename = "conversation.item.create")] ConversationItemCreate {
+        item: ConversationItem,
+    },
+
+    /// Asks the model to generate a response.
+    #[serde(rename = "response.create")] ResponseCreate {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        response: Option<ResponseConfig>,
+    },
+
+    /// Cancels the response in progress.
+    #[serde(rename = "response.cancel")]
+    ResponseCancel,
+}
+
+impl RealtimeClientEvent {
+    /// Creates an event appending raw audio bytes, e.g., 16-bit PCM at 24kHz,
+    /// to the input audio buffer.
+    pub fn append_audio(audio: &[u8]) -> Self {
+        Self::InputAudioBufferAppend { audio: STANDARD.encode(audio) }
+    }
+
+    /// Creates an event asking for a response with the configuration of the session.
+    pub fn create_response() -> Self {
+        Self::ResponseCreate { response: None }
+    }
+}
+
+/// Configuration of a realtime session, in which only the fields to change are set.
+#[derive(Debug, Serialize, Clone, PartialEq, Default)]
+pub struct SessionConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modalities: Option<Vec<Modality>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+
+    /// The voice of the model, e.g., `alloy`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_audio_format: Option<AudioFormat>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_audio_format: Option<AudioFormat>,
+
+    /// Voice activity detection, which is turned off if set to `None` explicitly
+    /// with `Some(None)`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub turn_detection: Option<Option<TurnDetection>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<RealtimeTool>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Modality {
+    Text,
+    Audio,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AudioFormat {
+    Pcm16,
+    G711Ulaw,
+    G711Alaw,
+}
+
+/// Server voice activity detection, which commits the audio buffer
+/// and creates a response when the user stops speaking.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TurnDetection {
+    ServerVad {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        threshold: Option<f32>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        silence_duration_ms: Option<u32>,
+    },
+}
+
+/// A tool that the model may call in a realtime session.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RealtimeTool {
+    Function(FunctionDefinition),
+}
+
+/// An item of the conversation of a realtime session.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ConversationItem {
+    Message {
+        role: ConversationRole,
+        content: Vec<ConversationContent>,
+    },
+
+    /// The output of a function call made by the model.
+    FunctionCallOutput {
+        call_id: String,
+        output: String,
+    },
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversationRole {
+    User,
+    Assistant,
+    System,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ConversationContent {
+    InputText {
+        text: String,
+    },
+
+    /// Base64-encoded input audio.
+    InputAudio {
+        audio: String,
+    },
+
+    Text {
+        text: String,
+    },
+}
+
+impl ConversationItem {
+    /// Creates a user message with a text.
+    pub fn user_text<S: AsRef<str>>(text: S) -> Self {
+        Self::Message {
+            role: ConversationRole::User,
+            content: vec![ConversationContent::InputText { text: text.as_ref().to_string() }],
+        }
+    }
+
+    /// Creates the output of a function call.
+    pub fn function_call_output<S: AsRef<str>, T: AsRef<str>>(call_id: S, output: T) -> Self {
+        Self::FunctionCallOutput {
+            call_id: call_id.as_ref().to_string(),
+            output: output.as_ref().to_string(),
+        }
+    }
+}
+
+/// Configuration of a single response overriding that of the session.
+#[derive(Debug, Serialize, Clone, PartialEq, Default)]
+pub struct ResponseConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modalities: Option<Vec<Modality>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn serialize_client_events() {
+        let event = RealtimeClientEvent::SessionUpdate {
+            session: SessionConfig {
+                modalities: Some(vec![Modality::Text]),
+                turn_detection: Some(None),
+                ..Default::default()
+            },
+        };
+        assert_eq!(
+            serde_json::to_value(&event).unwrap(),
+            json!({
+                "type": "session.update",
+                "session": { "modalities": ["text"], "turn_detection": null }
+            })
+        );
+
+        assert_eq!(
+            serde_json::to_value(RealtimeClientEvent::append_audio(&[0, 1, 2])).unwrap(),
+            json!({ "type": "input_audio_buffer.append", "audio": "AAEC" })
+        );
+        assert_eq!(
+            serde_json::to_value(RealtimeClientEvent::create_response()).unwrap(),
+            json!({ "type": "response.create" })
+        );
+    }
+}
@@ -0,0 +1,2 @@
+/// Path of the Realtime API under the base URL of the client.
+pub const REALTIME_API_PATH: &str = "realtime";
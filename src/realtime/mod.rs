@@ -0,0 +1,25 @@
+//! A client of the Realtime API over a WebSocket session,
+//! which is available with the `realtime` feature.
+
+mod endpoint;
+use endpoint::REALTIME_API_PATH;
+
+mod client_event;
+pub use client_event::{
+    RealtimeClientEvent,
+    SessionConfig,
+    Modality,
+    AudioFormat,
+    TurnDetection,
+    RealtimeTool,
+    ConversationItem,
+    ConversationRole,
+    ConversationContent,
+    ResponseConfig,
+};
+
+mod server_event;
+pub use server_event::{ RealtimeServerEvent, RealtimeError, RealtimeResponse };
+
+mod session;
+pub use session::RealtimeSession;
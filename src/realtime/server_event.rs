@@ -0,0 +1,130 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+/// An event sent by the server in a realtime session.
+///
+/// The sessions and conversation items are left as JSON values, as their shapes vary by type.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "type")]
+pub enum RealtimeServerEvent {
+    #[serde(rename = "error")] Error {
+        error: RealtimeError,
+    },
+
+    #[serde(rename = "session.created")] SessionCreated {
+        session: Value,
+    },
+
+    #[serde(rename = "session.updated")] SessionUpdated {
+        session: Value,
+    },
+
+    #[serde(rename = "conversation.item.created")] ConversationItemCreated {
+        previous_item_id: Option<String>,
+        item: Value,
+    },
+
+    #[serde(rename = "input_audio_buffer.committed")] InputAudioBufferCommitted {
+        item_id: String,
+    },
+
+    #[serde(rename = "input_audio_buffer.speech_started")] InputAudioBufferSpeechStarted {
+        item_id: String,
+        audio_start_ms: u64,
+    },
+
+    #[serde(rename = "input_audio_buffer.speech_stopped")] InputAudioBufferSpeechStopped {
+        item_id: String,
+        audio_end_ms: u64,
+    },
+
+    #[serde(rename = "response.created")] ResponseCreated {
+        response: RealtimeResponse,
+    },
+
+    #[serde(rename = "response.done")] ResponseDone {
+        response: RealtimeResponse,
+    },
+
+    #[serde(rename = "response.text.delta")] ResponseTextDelta {
+        response_id: String,
+        item_id: String,
+        delta: String,
+    },
+
+    #[serde(rename = "response.text.done")] ResponseTextDone {
+        response_id: String,
+        item_id: String,
+        text: String,
+    },
+
+    /// A fragment of base64-encoded output audio.
+    #[serde(rename = "response.audio.delta")] ResponseAudioDelta {
+        response_id: String,
+        item_id: String,
+        delta: String,
+    },
+
+    #[serde(rename = "response.audio.done")] ResponseAudioDone {
+        response_id: String,
+        item_id: String,
+    },
+
+    #[serde(rename = "response.audio_transcript.delta")] ResponseAudioTranscriptDelta {
+        response_id: String,
+        item_id: String,
+        delta: String,
+    },
+
+    #[serde(rename = "response.audio_transcript.done")] ResponseAudioTranscriptDone {
+        response_id: String,
+        item_id: String,
+        transcript: String,
+    },
+
+    #[serde(rename = "response.function_call_arguments.delta")] ResponseFunctionCallArgumentsDelta {
+        response_id: String,
+        item_id: String,
+        call_id: String,
+        delta: String,
+    },
+
+    #[serde(rename = "response.function_call_arguments.done")] ResponseFunctionCallArgumentsDone {
+        response_id: String,
+        item_id: String,
+        call_id: String,
+        name: Option<String>,
+        arguments: String,
+    },
+
+    /// An event unknown to this crate, e.g., rate limit updates.
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct RealtimeError {
+    #[serde(rename = "type")]
+    pub error_type: String,
+
+    pub code: Option<String>,
+    pub message: String,
+    pub param: Option<String>,
+
+    /// ID of the client event that caused the error, if any.
+    pub event_id: Option<String>,
+}
+
+/// A response generated in a realtime session.
+#[derive(Debug, Deserialize, Clone)]
+pub struct RealtimeResponse {
+    pub id: String,
+
+    /// The status of the response, e.g., `in_progress`, `completed` or `cancelled`.
+    pub status: String,
+
+    #[serde(default)]
+    pub output: Vec<Value>,
+
+    pub usage: Option<Value>,
+}
@@ -0,0 +1,300 @@
+use std::{ pin::Pin, task::{ Context, Poll } };
+use futures::{ Stream, SinkExt, StreamExt };
+use tokio::net::TcpStream;
+use tokio_tungstenite::{
+    connect_async,
+    MaybeTlsStream,
+    WebSocketStream,
+    tungstenite::{ self, Message, client::IntoClientRequest, http::{ self, HeaderValue } },
+};
+use crate::{ OpenAIClient, Result, Error, RealtimeApiError, utils::encode_query_value };
+use super::{ REALTIME_API_PATH, RealtimeClientEvent, RealtimeServerEvent };
+
+/// A WebSocket session with the Realtime API.
+///
+/// Client events are sent with `send`,
+/// and the server events are received by polling the session as a stream,
+/// which ends when the server closes the connection.
+pub struct RealtimeSession {
+    socket: WebSocketStream<MaybeTlsStream<TcpStream>>,
+}
+
+impl RealtimeSession {
+    /// Opens a session with the given model,
+    /// using the credentials and base URL of the client.
+    pub async fn connect<S: AsRef<str>>(client: &OpenAIClient, model: S) -> Result<Self> {
+        // Take the API key and base URL from the same key of the credential pool if any
+        let primary = client.with_primary_member();
+        let client = primary.as_ref().unwrap_or(client);
+
+        // Switch the scheme of the endpoint to that of WebSocket
+        let url = client.endpoint(
+            format!("{}?model={}", REALTIME_API_PATH, encode_query_value(model.as_ref()))
+        );
+        let url = match url.strip_prefix("https://") {
+            Some(rest) => format!("wss://{}", rest),
+            None =>
+                match url.strip_prefix("http://") {
+                    Some(rest) => format!("ws://{}", rest),
+                    None => url,
+                }
+        };
+
+        let mut request = url
+            .into_client_request()
+            .map_err(|error| {
+                Error::RealtimeApi(RealtimeApiError::Connect { source: Box::new(error) })
+            })?;
+
+        // Set the default, authorization and beta headers
+        let headers = request.headers_mut();
+        for (name, value) in client.headers().iter() {
+            headers.insert(name, value.clone());
+        }
+        if let Some((name, value)) = client.auth_header() {
+            let mut value = HeaderValue::from_str(&value).map_err(|error| {
+                let error = tungstenite::Error::from(http::Error::from(error));
+                Error::RealtimeApi(RealtimeApiError::Connect { source: Box::new(error) })
+            })?;
+            value.set_sensitive(true);
+            headers.insert(name, value);
+        }
+        headers.insert("OpenAI-Beta", HeaderValue::from_static("realtime=v1"));
+
+        match connect_async(request).await {
+            Ok((socket, _)) => Ok(Self { socket }),
+            Err(error) =>
+                Err(Error::RealtimeApi(RealtimeApiError::Connect { source: Box::new(error) })),
+        }
+    }
+
+    /// Sends a client event to the server.
+    pub async fn send(&mut self, event: &RealtimeClientEvent) -> Result<()> {
+        let text = serde_json
+            ::to_string(event)
+            .map_err(|source| {
+                Error::RealtimeApi(RealtimeApiError::SerializeClientEvent { source })
+            })?;
+
+        self.socket
+            .send(Message::Text(text)).await
+            .map_err(|error| {
+                Error::RealtimeApi(RealtimeApiError::SendClientEvent { source: Box::new(error) })
+            })
+    }
+
+    /// Closes the session.
+    pub async fn close(mut self) -> Result<()> {
+        self.socket
+            .close(None).await
+            .map_err(|error| {
+                Error::RealtimeApi(RealtimeApiError::SendClientEvent { source: Box::new(error) })
+            })
+    }
+}
+
+impl Stream for RealtimeSession {
+    type Item = Result<RealtimeServerEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match self.socket.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(Message::Text(text)))) => {
+                    return Poll::Ready(
+                        Some(
+                            serde_json
+                                ::from_str::<RealtimeServerEvent>(&text)
+                                .map_err(|source| {
+                                    Error::RealtimeApi(RealtimeApiError::ParseServerEvent {
+                                        source,
+                                    })
+                                })
+                        )
+                    );
+                }
+
+                // The session ends when the server closes the connection
+                Poll::Ready(Some(Ok(Message::Close(_)))) | Poll::Ready(None) => {
+                    return Poll::Ready(None);
+                }
+
+                // Skip pings, pongs and binary frames
+                Poll::Ready(Some(Ok(_))) => {
+                    continue;
+                }
+
+                Poll::Ready(Some(Err(error))) => {
+                    return Poll::Ready(
+                        Some(
+                            Err(
+                                Error::RealtimeApi(RealtimeApiError::ReceiveServerEvent {
+                                    source: Box::new(error),
+                                })
+                            )
+                        )
+                    );
+                }
+
+                Poll::Pending => {
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{ json, Value };
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::accept_hdr_async;
+    use tokio_tungstenite::tungstenite::handshake::server::{ Request, Response };
+    use crate::{ AzureConfig, realtime::ConversationItem };
+    use super::*;
+
+    // The handshake callback must return the error response of `tungstenite` as is
+    #[allow(clippy::result_large_err)]
+    #[tokio::test]
+    async fn exchange_events_with_stand_in_server() -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = accept_hdr_async(stream, |request: &Request, response: Response| {
+                assert_eq!(request.uri().path(), "/v1/realtime");
+                assert_eq!(request.uri().query(), Some("model=gpt-4o-realtime-preview"));
+                assert_eq!(request.headers()["Authorization"], "Bearer sk-test");
+                assert_eq!(request.headers()["OpenAI-Beta"], "realtime=v1");
+                Ok(response)
+            }).await.unwrap();
+
+            socket
+                .send(
+                    Message::Text(
+                        json!({
+                            "type": "session.created",
+                            "session": { "id": "sess_123" }
+                        }).to_string()
+                    )
+                ).await
+                .unwrap();
+
+            // Expect a user message and a request of response
+            let mut received = vec![];
+            for _ in 0..2 {
+                if let Some(Ok(Message::Text(text))) = socket.next().await {
+                    received.push(serde_json::from_str::<Value>(&text).unwrap());
+                }
+            }
+
+            for event in [
+                json!({
+                    "type": "response.text.delta",
+                    "response_id": "resp_123",
+                    "item_id": "item_123",
+                    "delta": "Hi"
+                }),
+                json!({ "type": "rate_limits.updated", "rate_limits": [] }),
+                json!({
+                    "type": "response.done",
+                    "response": { "id": "resp_123", "status": "completed", "output": [] }
+                }),
+            ] {
+                socket.send(Message::Text(event.to_string())).await.unwrap();
+            }
+            socket.close(None).await.unwrap();
+
+            received
+        });
+
+        let client = OpenAIClient::builder()
+            .api_key("sk-test")
+            .base_url(format!("http://{}/v1", address))
+            .build()?;
+
+        let mut session = RealtimeSession::connect(&client, "gpt-4o-realtime-preview").await?;
+
+        let event = session.next().await.unwrap()?;
+        assert!(matches!(event, RealtimeServerEvent::SessionCreated { .. }));
+
+        session.send(
+            &(RealtimeClientEvent::ConversationItemCreate {
+                item: ConversationItem::user_text("Hello"),
+            })
+        ).await?;
+        session.send(&RealtimeClientEvent::create_response()).await?;
+
+        let mut events = vec![];
+        while let Some(event) = session.next().await {
+            events.push(event?);
+        }
+
+        assert!(
+            matches!(
+                &events[0],
+                RealtimeServerEvent::ResponseTextDelta { delta, .. } if delta == "Hi"
+            )
+        );
+        assert!(matches!(events[1], RealtimeServerEvent::Other));
+        assert!(
+            matches!(
+                &events[2],
+                RealtimeServerEvent::ResponseDone { response } if response.status == "completed"
+            )
+        );
+
+        let received = server.await?;
+        assert_eq!(
+            received,
+            vec![
+                json!({
+                    "type": "conversation.item.create",
+                    "item": {
+                        "type": "message",
+                        "role": "user",
+                        "content": [{ "type": "input_text", "text": "Hello" }]
+                    }
+                }),
+                json!({ "type": "response.create" })
+            ]
+        );
+
+        Ok(())
+    }
+
+    // The handshake callback must return the error response of `tungstenite` as is
+    #[allow(clippy::result_large_err)]
+    #[tokio::test]
+    async fn connect_with_azure_api_key() -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = accept_hdr_async(stream, |request: &Request, response: Response| {
+                assert_eq!(request.uri().path(), "/openai/realtime");
+                assert_eq!(
+                    request.uri().query(),
+                    Some("model=my%20realtime%26x%3D1&api-version=2024-10-21")
+                );
+                assert_eq!(request.headers()["api-key"], "azure-key");
+                assert!(request.headers().get("Authorization").is_none());
+                Ok(response)
+            }).await.unwrap();
+            socket.close(None).await.unwrap();
+        });
+
+        let client = OpenAIClient::builder()
+            .api_key("azure-key")
+            .azure(AzureConfig::new(format!("http://{}", address), "my-deployment"))
+            .build()?;
+
+        // The model name is encoded in the query
+        let mut session = RealtimeSession::connect(&client, "my realtime&x=1").await?;
+        assert!(session.next().await.is_none());
+        server.await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use serde::{ Serialize, Deserialize };
+
+/// A list of objects returned by the list endpoints of the API.
+///
+/// The pagination fields are only present in the responses of paginated endpoints.
+#[derive(Debug, Deserialize, Clone)]
+pub struct ListResponse<T> {
+    pub object: String,
+    pub data: Vec<T>,
+
+    /// Whether there are more objects after this page.
+    #[serde(default)]
+    pub has_more: bool,
+
+    /// ID of the first object in this page.
+    pub first_id: Option<String>,
+
+    /// ID of the last object in this page,
+    /// which can be used as the cursor to fetch the next page.
+    pub last_id: Option<String>,
+}
+
+/// The sort order of a paginated list by the creation time of the objects.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ListOrder {
+    Asc,
+    Desc,
+}
+
+/// The status of a deleted object.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct DeletedObject {
+    pub id: String,
+    pub object: String,
+    pub deleted: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_list_response() {
+        // Without pagination fields
+        let json = r#"{
+            "object": "list",
+            "data": [1, 2, 3]
+        }"#;
+        let list: ListResponse<u32> = serde_json::from_str(json).unwrap();
+        assert_eq!(list.data, vec![1, 2, 3]);
+        assert!(!list.has_more);
+        assert_eq!(list.last_id, None);
+
+        // With pagination fields
+        let json = r#"{
+            "object": "list",
+            "data": [1, 2],
+            "has_more": true,
+            "first_id": "a",
+            "last_id": "b"
+        }"#;
+        let list: ListResponse<u32> = serde_json::from_str(json).unwrap();
+        assert!(list.has_more);
+        assert_eq!(list.first_id.as_deref(), Some("a"));
+        assert_eq!(list.last_id.as_deref(), Some("b"));
+    }
+}
@@ -0,0 +1,210 @@
+use crate::{
+    Result,
+    Error,
+    ResponsesApiError,
+    OpenAIClient,
+    utils::StreamingRequestBody,
+};
+use super::super::{ RESPONSES_API_PATH, ResponseRequestBody, Response, ResponseStream };
+
+/// Creates a response.
+pub async fn create_response(
+    client: &OpenAIClient,
+    request_body: &ResponseRequestBody
+) -> Result<Response> {
+    // Send the request
+    let response = client.send(|client| {
+        client.post(client.endpoint(RESPONSES_API_PATH)).json(request_body)
+    }).await?;
+
+    // Parse the response
+    match response.json::<Response>().await {
+        Ok(response) => Ok(response),
+        Err(error) => {
+            Err(Error::ResponsesApi(ResponsesApiError::ParseToResponse { source: error }))
+        }
+    }
+}
+
+/// Creates a response, streaming its semantic events.
+pub async fn create_response_stream(
+    client: &OpenAIClient,
+    request_body: &ResponseRequestBody
+) -> Result<ResponseStream> {
+    // Send the request
+    let response = client.send(|client| {
+        client
+            .post(client.endpoint(RESPONSES_API_PATH))
+            .json(&StreamingRequestBody::new(request_body))
+    }).await?;
+
+    // Wrap the bytes stream in a ResponseStream
+    Ok(ResponseStream::new(response.bytes_stream()))
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use mockito::Matcher;
+    use serde_json::json;
+    use crate::responses::*;
+    use super::*;
+
+    fn response_json(status: &str, output: serde_json::Value) -> serde_json::Value {
+        json!({
+            "id": "resp_abc123",
+            "object": "response",
+            "created_at": 1741476542,
+            "status": status,
+            "error": null,
+            "incomplete_details": null,
+            "model": "gpt-4.1",
+            "output": output,
+            "previous_response_id": null,
+            "usage": {
+                "input_tokens": 36,
+                "output_tokens": 87,
+                "total_tokens": 123,
+                "input_tokens_details": { "cached_tokens": 0 },
+                "output_tokens_details": { "reasoning_tokens": 64 }
+            },
+            "metadata": {}
+        })
+    }
+
+    #[tokio::test]
+    async fn test_create_response() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/responses")
+            .match_header("authorization", "Bearer sk-test")
+            .match_body(
+                Matcher::Json(
+                    json!({
+                        "model": "gpt-4.1",
+                        "input": "What is the weather in Tokyo?",
+                        "instructions": "Use the tools when needed."
+                    })
+                )
+            )
+            .with_header("content-type", "application/json")
+            .with_body(
+                response_json(
+                    "completed",
+                    json!([
+                        { "type": "reasoning", "id": "rs_abc123", "summary": [] },
+                        {
+                            "type": "function_call",
+                            "id": "fc_abc123",
+                            "call_id": "call_abc123",
+                            "name": "get_weather",
+                            "arguments": "{\"location\":\"Tokyo\"}",
+                            "status": "completed"
+                        },
+                        {
+                            "type": "message",
+                            "id": "msg_abc123",
+                            "role": "assistant",
+                            "status": "completed",
+                            "content": [
+                                {
+                                    "type": "output_text",
+                                    "text": "Let me check.",
+                                    "annotations": []
+                                }
+                            ]
+                        },
+                        { "type": "image_generation_call", "id": "ig_abc123" }
+                    ])
+                ).to_string()
+            )
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // Create the response
+        let request_body = ResponseRequestBody::builder("gpt-4.1", "What is the weather in Tokyo?")
+            .instructions("Use the tools when needed.")
+            .build();
+        let response = create_response(&client, &request_body).await?;
+
+        mock.assert_async().await;
+        assert_eq!(response.status, ResponseStatus::Completed);
+        assert_eq!(response.output_text(), "Let me check.");
+        assert_eq!(response.output[3], OutputItem::Other);
+        match response.function_calls()[..] {
+            [OutputItem::FunctionCall { call_id, name, .. }] => {
+                assert_eq!(call_id, "call_abc123");
+                assert_eq!(name, "get_weather");
+            }
+            ref other => panic!("expected a function call, got {other:?}"),
+        }
+        assert_eq!(response.usage.unwrap().output_tokens_details.unwrap().reasoning_tokens, 64);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_response_stream() -> Result<()> {
+        let created = json!({
+            "type": "response.created",
+            "sequence_number": 0,
+            "response": response_json("in_progress", json!([]))
+        });
+        let delta = json!({
+            "type": "response.output_text.delta",
+            "sequence_number": 1,
+            "item_id": "msg_abc123",
+            "output_index": 0,
+            "content_index": 0,
+            "delta": "Hi"
+        });
+        let unknown = json!({ "type": "response.web_search_call.searching", "sequence_number": 2 });
+
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/responses")
+            .match_body(Matcher::Json(json!({ "model": "gpt-4.1", "input": "Hi", "stream": true })))
+            .with_header("content-type", "text/event-stream")
+            .with_body(
+                [
+                    format!("event: response.created\ndata: {created}\n\n"),
+                    format!("event: response.output_text.delta\ndata: {delta}\n\n"),
+                    format!("event: response.web_search_call.searching\ndata: {unknown}\n\n"),
+                ].concat()
+            )
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // Stream the response
+        let request_body = ResponseRequestBody::builder("gpt-4.1", "Hi").build();
+        let mut stream = create_response_stream(&client, &request_body).await?;
+        let mut events = vec![];
+        while let Some(event) = stream.next().await {
+            events.push(event?);
+        }
+
+        mock.assert_async().await;
+        assert_eq!(events.len(), 3);
+        assert!(
+            matches!(
+                &events[0],
+                ResponseStreamEvent::Created { response } if response.id == "resp_abc123"
+            )
+        );
+        assert!(
+            matches!(
+                &events[1],
+                ResponseStreamEvent::OutputTextDelta { delta, .. } if delta == "Hi"
+            )
+        );
+        assert!(matches!(events[2], ResponseStreamEvent::Other));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{
+    Result,
+    Error,
+    ResponsesApiError,
+    OpenAIClient,
+    DeletedObject,
+    utils::encode_path_segment,
+};
+use super::super::RESPONSES_API_PATH;
+
+/// Deletes a stored response.
+pub async fn delete_response<S: AsRef<str>>(
+    client: &OpenAIClient,
+    response_id: S
+) -> Result<DeletedObject> {
+    // Send the request
+    let response = match
+        client.send(|client| {
+            client.delete(
+                client.endpoint(
+                    format!("{}/{}", RESPONSES_API_PATH, encode_path_segment(response_id.as_ref()))
+                )
+            )
+        }).await
+    {
+        Ok(response) => response,
+        Err(Error::UnknownStatusCode { status_code: reqwest::StatusCode::NOT_FOUND, .. }) => {
+            let response_id = response_id.as_ref().to_string();
+            return Err(Error::ResponsesApi(ResponsesApiError::ResponseNotFound(response_id)));
+        }
+        Err(error) => {
+            return Err(error);
+        }
+    };
+    // Parse the response
+    match response.json::<DeletedObject>().await {
+        Ok(deleted_response) => Ok(deleted_response),
+        Err(error) => {
+            Err(Error::ResponsesApi(ResponsesApiError::ParseToDeletedResponse { source: error }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::responses::retrieve_response;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_delete_response() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("DELETE", "/responses/resp_abc123")
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"id":"resp_abc123","object":"response","deleted":true}"#)
+            .create_async().await;
+        server.mock("GET", "/responses/resp_abc123").with_status(404).create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        let deleted_response = delete_response(&client, "resp_abc123").await?;
+        mock.assert_async().await;
+        assert!(deleted_response.deleted);
+
+        // The deleted response is not found
+        match retrieve_response(&client, "resp_abc123").await {
+            Err(Error::ResponsesApi(ResponsesApiError::ResponseNotFound(id))) => {
+                assert_eq!(id, "resp_abc123");
+            }
+            other => panic!("expected a missing response, got {other:?}"),
+        }
+
+        Ok(())
+    }
+}
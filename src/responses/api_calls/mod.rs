@@ -0,0 +1,8 @@
+mod create;
+pub use create::{ create_response, create_response_stream };
+
+mod retrieve;
+pub use retrieve::retrieve_response;
+
+mod delete;
+pub use delete::delete_response;
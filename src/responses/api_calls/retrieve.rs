@@ -0,0 +1,35 @@
+use crate::{ Result, Error, ResponsesApiError, OpenAIClient, utils::encode_path_segment };
+use super::super::{ RESPONSES_API_PATH, Response };
+
+/// Retrieves a stored response.
+pub async fn retrieve_response<S: AsRef<str>>(
+    client: &OpenAIClient,
+    response_id: S
+) -> Result<Response> {
+    // Send the request
+    let response = match
+        client.send(|client| {
+            client.get(
+                client.endpoint(
+                    format!("{}/{}", RESPONSES_API_PATH, encode_path_segment(response_id.as_ref()))
+                )
+            )
+        }).await
+    {
+        Ok(response) => response,
+        Err(Error::UnknownStatusCode { status_code: reqwest::StatusCode::NOT_FOUND, .. }) => {
+            let response_id = response_id.as_ref().to_string();
+            return Err(Error::ResponsesApi(ResponsesApiError::ResponseNotFound(response_id)));
+        }
+        Err(error) => {
+            return Err(error);
+        }
+    };
+    // Parse the response
+    match response.json::<Response>().await {
+        Ok(response) => Ok(response),
+        Err(error) => {
+            Err(Error::ResponsesApi(ResponsesApiError::ParseToResponse { source: error }))
+        }
+    }
+}
@@ -0,0 +1,2 @@
+/// Path of the responses API under the base URL of the client.
+pub const RESPONSES_API_PATH: &str = "responses";
@@ -0,0 +1,172 @@
+use serde::Serialize;
+
+/// The input of a response, either a text or a list of items.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum ResponseInput {
+    /// A text, which is equivalent to a user message.
+    Text(String),
+
+    Items(Vec<InputItem>),
+}
+
+impl From<&str> for ResponseInput {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<String> for ResponseInput {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<Vec<InputItem>> for ResponseInput {
+    fn from(items: Vec<InputItem>) -> Self {
+        Self::Items(items)
+    }
+}
+
+/// An item of the input of a response.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InputItem {
+    Message {
+        role: InputRole,
+        content: InputContent,
+    },
+
+    /// The output of a function call made by the model.
+    FunctionCallOutput {
+        call_id: String,
+        output: String,
+    },
+
+    /// A reference to an item of a previous response by its ID,
+    /// e.g., a function call or a reasoning item.
+    ItemReference {
+        id: String,
+    },
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum InputRole {
+    User,
+    Assistant,
+    System,
+    Developer,
+}
+
+/// The content of an input message, either a text or a list of parts.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum InputContent {
+    Text(String),
+    Parts(Vec<InputContentPart>),
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InputContentPart {
+    InputText {
+        text: String,
+    },
+
+    /// An image by its URL, which can be a data URL, or by the ID of an uploaded file.
+    InputImage {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        image_url: Option<String>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        file_id: Option<String>,
+
+        detail: ImageDetail,
+    },
+
+    /// A file by the ID of an uploaded file.
+    InputFile {
+        file_id: String,
+    },
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageDetail {
+    Auto,
+    Low,
+    High,
+}
+
+impl InputItem {
+    pub fn message<C: Into<InputContent>>(role: InputRole, content: C) -> Self {
+        Self::Message { role, content: content.into() }
+    }
+
+    pub fn user<C: Into<InputContent>>(content: C) -> Self {
+        Self::message(InputRole::User, content)
+    }
+
+    pub fn assistant<C: Into<InputContent>>(content: C) -> Self {
+        Self::message(InputRole::Assistant, content)
+    }
+
+    pub fn system<C: Into<InputContent>>(content: C) -> Self {
+        Self::message(InputRole::System, content)
+    }
+
+    /// Creates a developer message, whose instructions take precedence over user messages.
+    pub fn developer<C: Into<InputContent>>(content: C) -> Self {
+        Self::message(InputRole::Developer, content)
+    }
+
+    /// Creates the output of a function call.
+    pub fn function_call_output<S: AsRef<str>, T: AsRef<str>>(call_id: S, output: T) -> Self {
+        Self::FunctionCallOutput {
+            call_id: call_id.as_ref().to_string(),
+            output: output.as_ref().to_string(),
+        }
+    }
+
+    /// Creates a reference to an item of a previous response.
+    pub fn item_reference<S: AsRef<str>>(id: S) -> Self {
+        Self::ItemReference { id: id.as_ref().to_string() }
+    }
+}
+
+impl From<&str> for InputContent {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<String> for InputContent {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<Vec<InputContentPart>> for InputContent {
+    fn from(parts: Vec<InputContentPart>) -> Self {
+        Self::Parts(parts)
+    }
+}
+
+impl InputContentPart {
+    pub fn text<S: AsRef<str>>(text: S) -> Self {
+        Self::InputText { text: text.as_ref().to_string() }
+    }
+
+    pub fn image_url<S: AsRef<str>>(image_url: S, detail: ImageDetail) -> Self {
+        Self::InputImage { image_url: Some(image_url.as_ref().to_string()), file_id: None, detail }
+    }
+
+    pub fn image_file<S: AsRef<str>>(file_id: S, detail: ImageDetail) -> Self {
+        Self::InputImage { image_url: None, file_id: Some(file_id.as_ref().to_string()), detail }
+    }
+
+    pub fn file<S: AsRef<str>>(file_id: S) -> Self {
+        Self::InputFile { file_id: file_id.as_ref().to_string() }
+    }
+}
@@ -0,0 +1,24 @@
+mod input;
+pub use input::{
+    ResponseInput,
+    InputItem,
+    InputRole,
+    InputContent,
+    InputContentPart,
+    ImageDetail,
+};
+
+mod tool;
+pub use tool::{
+    ResponseTool,
+    SearchContextSize,
+    ResponseToolChoice,
+    ToolChoiceMode,
+    FunctionToolType,
+};
+
+mod text;
+pub use text::{ TextConfig, TextFormat, ReasoningConfig, ReasoningEffort, ReasoningSummary };
+
+mod response_request_body;
+pub use response_request_body::{ ResponseRequestBody, ResponseRequestBodyBuilder };
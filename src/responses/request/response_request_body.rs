@@ -0,0 +1,322 @@
+use std::collections::HashMap;
+use serde::Serialize;
+use log::warn;
+use super::{
+    ResponseInput,
+    ResponseTool,
+    ResponseToolChoice,
+    TextConfig,
+    TextFormat,
+    ReasoningConfig,
+    ReasoningEffort,
+    ReasoningSummary,
+};
+
+const MIN_TEMPERATURE: f32 = 0.0;
+const MAX_TEMPERATURE: f32 = 2.0;
+const MIN_TOP_P: f32 = 0.0;
+const MAX_TOP_P: f32 = 1.0;
+
+/// Request body of creating a response.
+#[derive(Debug, Serialize)]
+pub struct ResponseRequestBody {
+    model: String,
+    input: ResponseInput,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instructions: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    previous_response_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<ResponseTool>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_choice: Option<ResponseToolChoice>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parallel_tool_calls: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<TextConfig>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reasoning: Option<ReasoningConfig>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_output_tokens: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<HashMap<String, String>>,
+}
+
+impl ResponseRequestBody {
+    pub fn builder<S: AsRef<str>, I: Into<ResponseInput>>(
+        model: S,
+        input: I
+    ) -> ResponseRequestBodyBuilder {
+        ResponseRequestBodyBuilder::new(model, input)
+    }
+}
+
+pub struct ResponseRequestBodyBuilder {
+    model: String,
+    input: ResponseInput,
+    instructions: Option<String>,
+    previous_response_id: Option<String>,
+    tools: Option<Vec<ResponseTool>>,
+    tool_choice: Option<ResponseToolChoice>,
+    parallel_tool_calls: Option<bool>,
+    text: Option<TextConfig>,
+    reasoning: Option<ReasoningConfig>,
+    max_output_tokens: Option<u32>,
+    temperature: Option<f32>,
+    top_p: Option<f32>,
+    store: Option<bool>,
+    metadata: Option<HashMap<String, String>>,
+}
+
+impl ResponseRequestBodyBuilder {
+    /// Creates a new builder with `None` values for all optional fields.
+    pub fn new<S: AsRef<str>, I: Into<ResponseInput>>(model: S, input: I) -> Self {
+        Self {
+            model: model.as_ref().to_string(),
+            input: input.into(),
+            instructions: None,
+            previous_response_id: None,
+            tools: None,
+            tool_choice: None,
+            parallel_tool_calls: None,
+            text: None,
+            reasoning: None,
+            max_output_tokens: None,
+            temperature: None,
+            top_p: None,
+            store: None,
+            metadata: None,
+        }
+    }
+
+    /// Builds the request body.
+    pub fn build(self) -> ResponseRequestBody {
+        ResponseRequestBody {
+            model: self.model,
+            input: self.input,
+            instructions: self.instructions,
+            previous_response_id: self.previous_response_id,
+            tools: self.tools,
+            tool_choice: self.tool_choice,
+            parallel_tool_calls: self.parallel_tool_calls,
+            text: self.text,
+            reasoning: self.reasoning,
+            max_output_tokens: self.max_output_tokens,
+            temperature: self.temperature,
+            top_p: self.top_p,
+            store: self.store,
+            metadata: self.metadata,
+        }
+    }
+
+    /// Sets the system instructions, which are not carried over by `previous_response_id`.
+    pub fn instructions<S: AsRef<str>>(mut self, instructions: S) -> Self {
+        self.instructions = Some(instructions.as_ref().to_string());
+        self
+    }
+
+    /// Continues the conversation of a previous stored response.
+    pub fn previous_response_id<S: AsRef<str>>(mut self, previous_response_id: S) -> Self {
+        self.previous_response_id = Some(previous_response_id.as_ref().to_string());
+        self
+    }
+
+    /// Sets the tools that the model may use.
+    pub fn tools(mut self, tools: Vec<ResponseTool>) -> Self {
+        self.tools = Some(tools);
+        self
+    }
+
+    pub fn tool_choice(mut self, tool_choice: ResponseToolChoice) -> Self {
+        self.tool_choice = Some(tool_choice);
+        self
+    }
+
+    /// Sets whether the model may call tools in parallel.
+    pub fn parallel_tool_calls(mut self, parallel_tool_calls: bool) -> Self {
+        self.parallel_tool_calls = Some(parallel_tool_calls);
+        self
+    }
+
+    /// Sets the format of the text output.
+    pub fn text_format(mut self, format: TextFormat) -> Self {
+        self.text = Some(TextConfig { format });
+        self
+    }
+
+    /// Sets the reasoning effort of reasoning models.
+    pub fn reasoning_effort(mut self, effort: ReasoningEffort) -> Self {
+        self.reasoning.get_or_insert_with(ReasoningConfig::default).effort = Some(effort);
+        self
+    }
+
+    /// Requests a summary of the reasoning of reasoning models.
+    pub fn reasoning_summary(mut self, summary: ReasoningSummary) -> Self {
+        self.reasoning.get_or_insert_with(ReasoningConfig::default).summary = Some(summary);
+        self
+    }
+
+    /// Sets the maximum number of output tokens, including reasoning tokens.
+    pub fn max_output_tokens(mut self, max_output_tokens: u32) -> Self {
+        self.max_output_tokens = Some(max_output_tokens);
+        self
+    }
+
+    /// Sets the sampling temperature.
+    ///
+    /// The input value will be clamped in between 0.0 and 2.0.
+    pub fn temperature(mut self, temperature: f32) -> Self {
+        // Clamp the value to the valid range
+        let temperature = if !(MIN_TEMPERATURE..=MAX_TEMPERATURE).contains(&temperature) {
+            // Clamp the value
+            let t = temperature.clamp(MIN_TEMPERATURE, MAX_TEMPERATURE);
+
+            // Warn the user
+            warn!(
+                "input value of temperature is {temperature} which is out of range, it is now clamped to {t}"
+            );
+
+            t
+        } else {
+            temperature
+        };
+
+        self.temperature = Some(temperature);
+        self
+    }
+
+    /// Sets the nucleus sampling probability mass.
+    ///
+    /// The input value will be clamped in between 0.0 and 1.0.
+    pub fn top_p(mut self, top_p: f32) -> Self {
+        // Clamp the value to the valid range
+        let top_p = if !(MIN_TOP_P..=MAX_TOP_P).contains(&top_p) {
+            // Clamp the value
+            let p = top_p.clamp(MIN_TOP_P, MAX_TOP_P);
+
+            // Warn the user
+            warn!("input value of top_p is {top_p} which is out of range, it is now clamped to {p}");
+
+            p
+        } else {
+            top_p
+        };
+
+        self.top_p = Some(top_p);
+        self
+    }
+
+    /// Sets whether to store the response, so that it can be retrieved later.
+    pub fn store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Adds a key-value pair to the metadata of the response.
+    pub fn metadata<K: AsRef<str>, V: AsRef<str>>(mut self, key: K, value: V) -> Self {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use crate::assistants::FunctionDefinition;
+    use super::super::*;
+
+    #[test]
+    fn serialize_response_request_body() {
+        let request_body = ResponseRequestBody::builder(
+            "o4-mini",
+            vec![
+                InputItem::developer("Answer in French."),
+                InputItem::user(
+                    vec![
+                        InputContentPart::text("What is in this image?"),
+                        InputContentPart::image_file("file-abc123", ImageDetail::Low)
+                    ]
+                ),
+                InputItem::function_call_output("call_abc123", "{\"temperature\":22}")
+            ]
+        )
+            .previous_response_id("resp_abc123")
+            .tools(
+                vec![
+                    ResponseTool::function(FunctionDefinition {
+                        name: "get_weather".to_string(),
+                        description: None,
+                        parameters: Some(json!({ "type": "object" })),
+                        strict: None,
+                    }),
+                    ResponseTool::web_search()
+                ]
+            )
+            .tool_choice(ResponseToolChoice::Mode(ToolChoiceMode::Auto))
+            .text_format(TextFormat::json_schema("answer", json!({ "type": "object" })))
+            .reasoning_effort(ReasoningEffort::Low)
+            .build();
+
+        assert_eq!(
+            serde_json::to_value(&request_body).unwrap(),
+            json!({
+                "model": "o4-mini",
+                "input": [
+                    { "type": "message", "role": "developer", "content": "Answer in French." },
+                    {
+                        "type": "message",
+                        "role": "user",
+                        "content": [
+                            { "type": "input_text", "text": "What is in this image?" },
+                            { "type": "input_image", "file_id": "file-abc123", "detail": "low" }
+                        ]
+                    },
+                    {
+                        "type": "function_call_output",
+                        "call_id": "call_abc123",
+                        "output": "{\"temperature\":22}"
+                    }
+                ],
+                "previous_response_id": "resp_abc123",
+                "tools": [
+                    {
+                        "type": "function",
+                        "name": "get_weather",
+                        "parameters": { "type": "object" }
+                    },
+                    { "type": "web_search_preview" }
+                ],
+                "tool_choice": "auto",
+                "text": {
+                    "format": {
+                        "type": "json_schema",
+                        "name": "answer",
+                        "schema": { "type": "object" },
+                        "strict": true
+                    }
+                },
+                "reasoning": { "effort": "low" }
+            })
+        );
+    }
+}
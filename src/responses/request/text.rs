@@ -0,0 +1,71 @@
+use serde::Serialize;
+use serde_json::Value;
+
+/// Configuration of the text output of a response.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct TextConfig {
+    pub format: TextFormat,
+}
+
+/// The format of the text output.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TextFormat {
+    Text,
+
+    /// JSON without a schema, which requires the instructions to ask for JSON.
+    JsonObject,
+
+    /// JSON following the schema, i.e., structured outputs.
+    JsonSchema {
+        name: String,
+        schema: Value,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        strict: Option<bool>,
+    },
+}
+
+impl TextFormat {
+    /// Creates a JSON schema format that the output strictly follows.
+    pub fn json_schema<S: AsRef<str>>(name: S, schema: Value) -> Self {
+        Self::JsonSchema {
+            name: name.as_ref().to_string(),
+            schema,
+            description: None,
+            strict: Some(true),
+        }
+    }
+}
+
+/// Configuration of reasoning models.
+#[derive(Debug, Serialize, Clone, PartialEq, Default)]
+pub struct ReasoningConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effort: Option<ReasoningEffort>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<ReasoningSummary>,
+}
+
+/// How much the model reasons before responding.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReasoningEffort {
+    Minimal,
+    Low,
+    Medium,
+    High,
+}
+
+/// The verbosity of the summary of the reasoning.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReasoningSummary {
+    Auto,
+    Concise,
+    Detailed,
+}
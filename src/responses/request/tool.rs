@@ -0,0 +1,99 @@
+use serde::Serialize;
+use crate::{ assistants::FunctionDefinition, vector_stores::{ SearchFilter, RankingOptions } };
+
+/// A tool that the model may use to generate a response.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResponseTool {
+    /// A function defined by the application, which the model calls with arguments.
+    Function(FunctionDefinition),
+
+    /// A built-in tool searching the files in vector stores.
+    FileSearch {
+        vector_store_ids: Vec<String>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        max_num_results: Option<u32>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        filters: Option<SearchFilter>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ranking_options: Option<RankingOptions>,
+    },
+
+    /// A built-in tool searching the web.
+    WebSearchPreview {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        search_context_size: Option<SearchContextSize>,
+    },
+}
+
+/// The amount of context retrieved from the web for a search.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchContextSize {
+    Low,
+    Medium,
+    High,
+}
+
+impl ResponseTool {
+    /// Creates a function tool.
+    pub fn function<F: Into<FunctionDefinition>>(function: F) -> Self {
+        Self::Function(function.into())
+    }
+
+    /// Creates a file search tool over the vector stores with the default settings.
+    pub fn file_search<S: AsRef<str>>(vector_store_ids: &[S]) -> Self {
+        Self::FileSearch {
+            vector_store_ids: vector_store_ids
+                .iter()
+                .map(|vector_store_id| vector_store_id.as_ref().to_string())
+                .collect(),
+            max_num_results: None,
+            filters: None,
+            ranking_options: None,
+        }
+    }
+
+    /// Creates a web search tool with the default settings.
+    pub fn web_search() -> Self {
+        Self::WebSearchPreview { search_context_size: None }
+    }
+}
+
+/// Whether and which tool the model should use.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum ResponseToolChoice {
+    Mode(ToolChoiceMode),
+
+    /// Forces the model to call the function.
+    Function {
+        #[serde(rename = "type")]
+        tool_type: FunctionToolType,
+        name: String,
+    },
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolChoiceMode {
+    None,
+    Auto,
+    Required,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FunctionToolType {
+    Function,
+}
+
+impl ResponseToolChoice {
+    /// Forces the model to call the function with the name.
+    pub fn function<S: AsRef<str>>(name: S) -> Self {
+        Self::Function { tool_type: FunctionToolType::Function, name: name.as_ref().to_string() }
+    }
+}
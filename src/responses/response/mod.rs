@@ -0,0 +1,19 @@
+mod response_object;
+pub use response_object::{
+    Response,
+    ResponseStatus,
+    ResponseError,
+    IncompleteDetails,
+    ResponseUsage,
+    InputTokensDetails,
+    OutputTokensDetails,
+};
+
+mod output;
+pub use output::{ OutputItem, OutputItemStatus, OutputContent, ReasoningSummaryText };
+
+mod stream_event;
+pub use stream_event::ResponseStreamEvent;
+
+mod stream;
+pub use stream::ResponseStream;
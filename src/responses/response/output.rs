@@ -0,0 +1,81 @@
+use serde::Deserialize;
+
+/// An item generated by the model.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OutputItem {
+    Message {
+        id: String,
+        role: String,
+        status: Option<OutputItemStatus>,
+        content: Vec<OutputContent>,
+    },
+
+    /// A call of a function tool, whose output is sent back
+    /// with the call ID in a `function_call_output` input item.
+    FunctionCall {
+        id: Option<String>,
+        call_id: String,
+        name: String,
+        arguments: String,
+        status: Option<OutputItemStatus>,
+    },
+
+    /// The reasoning of a reasoning model, with its summary if requested.
+    Reasoning {
+        id: String,
+
+        #[serde(default)]
+        summary: Vec<ReasoningSummaryText>,
+    },
+
+    FileSearchCall {
+        id: String,
+        status: Option<String>,
+
+        #[serde(default)]
+        queries: Vec<String>,
+    },
+
+    WebSearchCall {
+        id: String,
+        status: Option<String>,
+    },
+
+    /// An item unknown to this crate.
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputItemStatus {
+    InProgress,
+    Completed,
+    Incomplete,
+}
+
+/// A part of the content of an output message.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OutputContent {
+    OutputText {
+        text: String,
+
+        /// Citations of files and web pages in the text.
+        #[serde(default)]
+        annotations: Vec<serde_json::Value>,
+    },
+
+    Refusal {
+        refusal: String,
+    },
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReasoningSummaryText {
+    SummaryText {
+        text: String,
+    },
+}
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+use super::{ OutputItem, OutputContent };
+
+/// A response generated by a model.
+///
+/// The timestamps are Unix timestamps in seconds.
+#[derive(Debug, Deserialize, Clone)]
+pub struct Response {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+    pub status: ResponseStatus,
+    pub error: Option<ResponseError>,
+    pub incomplete_details: Option<IncompleteDetails>,
+    pub model: String,
+    pub instructions: Option<String>,
+
+    /// The items generated by the model, in order.
+    pub output: Vec<OutputItem>,
+
+    pub previous_response_id: Option<String>,
+    pub usage: Option<ResponseUsage>,
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl Response {
+    /// Gets the text output by joining the text parts of the output messages.
+    pub fn output_text(&self) -> String {
+        self.output
+            .iter()
+            .filter_map(|item| {
+                match item {
+                    OutputItem::Message { content, .. } => Some(content),
+                    _ => None,
+                }
+            })
+            .flatten()
+            .filter_map(|content| {
+                match content {
+                    OutputContent::OutputText { text, .. } => Some(text.as_str()),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    /// Gets the function calls that the model made, whose outputs are expected in the next input.
+    pub fn function_calls(&self) -> Vec<&OutputItem> {
+        self.output
+            .iter()
+            .filter(|item| matches!(item, OutputItem::FunctionCall { .. }))
+            .collect()
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseStatus {
+    Queued,
+    InProgress,
+    Completed,
+    Incomplete,
+    Failed,
+    Cancelled,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ResponseError {
+    pub code: String,
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct IncompleteDetails {
+    /// The reason why the response is incomplete, e.g., `max_output_tokens`.
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ResponseUsage {
+    pub input_tokens: u32,
+    pub output_tokens: u32,
+    pub total_tokens: u32,
+    pub input_tokens_details: Option<InputTokensDetails>,
+    pub output_tokens_details: Option<OutputTokensDetails>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct InputTokensDetails {
+    pub cached_tokens: u32,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct OutputTokensDetails {
+    pub reasoning_tokens: u32,
+}
@@ -0,0 +1,55 @@
+use std::{ pin::Pin, task::{ Context, Poll } };
+use bytes::Bytes;
+use futures::{ Stream, StreamExt };
+use crate::{ Result, Error, ResponsesApiError, sse::EventStream };
+use super::ResponseStreamEvent;
+
+/// A stream of the semantic events of a response, ending when the API closes the connection.
+pub struct ResponseStream {
+    stream: EventStream,
+}
+
+impl ResponseStream {
+    pub fn new<S: 'static + Stream<Item = reqwest::Result<Bytes>>>(stream: S) -> Self {
+        Self { stream: EventStream::new(stream) }
+    }
+}
+
+impl Stream for ResponseStream {
+    type Item = Result<ResponseStreamEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.stream.poll_next_unpin(cx) {
+            Poll::Ready(Some(Ok(frame))) => {
+                // Parse the data, which is tagged with the type of the event
+                match serde_json::from_str(&frame.data) {
+                    Ok(event) => Poll::Ready(Some(Ok(event))),
+                    Err(error) => {
+                        Poll::Ready(
+                            Some(
+                                Err(
+                                    Error::ResponsesApi(ResponsesApiError::ParseStreamEvent {
+                                        source: error,
+                                    })
+                                )
+                            )
+                        )
+                    }
+                }
+            }
+            Poll::Ready(Some(Err(error))) => {
+                Poll::Ready(
+                    Some(
+                        Err(
+                            Error::ResponsesApi(ResponsesApiError::ReceiveStreamedBytes {
+                                source: error,
+                            })
+                        )
+                    )
+                )
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
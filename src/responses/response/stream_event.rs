@@ -0,0 +1,117 @@
+use serde::Deserialize;
+use super::{ Response, OutputItem, OutputContent };
+
+/// A semantic event streamed while a response is generated.
+///
+/// The name of each variant is the `type` of the event without the `response.` prefix.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "type")]
+pub enum ResponseStreamEvent {
+    #[serde(rename = "response.created")] Created {
+        response: Response,
+    },
+
+    #[serde(rename = "response.in_progress")] InProgress {
+        response: Response,
+    },
+
+    #[serde(rename = "response.completed")] Completed {
+        response: Response,
+    },
+
+    #[serde(rename = "response.incomplete")] Incomplete {
+        response: Response,
+    },
+
+    #[serde(rename = "response.failed")] Failed {
+        response: Response,
+    },
+
+    #[serde(rename = "response.output_item.added")] OutputItemAdded {
+        output_index: usize,
+        item: OutputItem,
+    },
+
+    #[serde(rename = "response.output_item.done")] OutputItemDone {
+        output_index: usize,
+        item: OutputItem,
+    },
+
+    #[serde(rename = "response.content_part.added")] ContentPartAdded {
+        item_id: String,
+        output_index: usize,
+        content_index: usize,
+        part: OutputContent,
+    },
+
+    #[serde(rename = "response.content_part.done")] ContentPartDone {
+        item_id: String,
+        output_index: usize,
+        content_index: usize,
+        part: OutputContent,
+    },
+
+    #[serde(rename = "response.output_text.delta")] OutputTextDelta {
+        item_id: String,
+        output_index: usize,
+        content_index: usize,
+        delta: String,
+    },
+
+    #[serde(rename = "response.output_text.done")] OutputTextDone {
+        item_id: String,
+        output_index: usize,
+        content_index: usize,
+        text: String,
+    },
+
+    #[serde(rename = "response.refusal.delta")] RefusalDelta {
+        item_id: String,
+        output_index: usize,
+        content_index: usize,
+        delta: String,
+    },
+
+    #[serde(rename = "response.refusal.done")] RefusalDone {
+        item_id: String,
+        output_index: usize,
+        content_index: usize,
+        refusal: String,
+    },
+
+    #[serde(rename = "response.function_call_arguments.delta")] FunctionCallArgumentsDelta {
+        item_id: String,
+        output_index: usize,
+        delta: String,
+    },
+
+    #[serde(rename = "response.function_call_arguments.done")] FunctionCallArgumentsDone {
+        item_id: String,
+        output_index: usize,
+        arguments: String,
+    },
+
+    #[serde(rename = "response.reasoning_summary_text.delta")] ReasoningSummaryTextDelta {
+        item_id: String,
+        output_index: usize,
+        summary_index: usize,
+        delta: String,
+    },
+
+    #[serde(rename = "response.reasoning_summary_text.done")] ReasoningSummaryTextDone {
+        item_id: String,
+        output_index: usize,
+        summary_index: usize,
+        text: String,
+    },
+
+    #[serde(rename = "error")] Error {
+        code: Option<String>,
+        message: String,
+        param: Option<String>,
+    },
+
+    /// An event unknown to this crate, e.g., the progress of built-in tools.
+    #[serde(other)]
+    Other,
+}
@@ -0,0 +1,221 @@
+use std::{ pin::Pin, task::{ Context, Poll } };
+use bytes::{ Bytes, BytesMut };
+use futures::{ Stream, StreamExt };
+
+/// The content of the termination data chunk,
+/// indicating the end of the stream.
+const TERMINATION_DATA_CHUNK: &str = "[DONE]";
+
+/// A server-sent event, i.e., the `event:` and `data:` fields of a frame.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ServerSentEvent {
+    /// The name of the event if specified.
+    pub event: Option<String>,
+
+    /// The data lines of the event joined with `\n`.
+    pub data: String,
+}
+
+/// A stream of server-sent events decoded from a stream of bytes.
+///
+/// Lines may end with `\n`, `\r\n` or `\r`.
+/// Comment lines starting with `:`, e.g., keep-alive pings, and frames without data are skipped.
+pub(crate) struct EventStream {
+    stream: Pin<Box<dyn Stream<Item = reqwest::Result<Bytes>>>>,
+    buffer: BytesMut,
+
+    /// The fields of the frame being decoded.
+    event: Option<String>,
+    data: Option<String>,
+
+    /// Whether the bytes stream has ended.
+    ended: bool,
+}
+
+impl EventStream {
+    pub fn new<S: 'static + Stream<Item = reqwest::Result<Bytes>>>(stream: S) -> Self {
+        Self {
+            stream: Box::pin(stream),
+            buffer: BytesMut::new(),
+            event: None,
+            data: None,
+            ended: false,
+        }
+    }
+
+    /// Takes the first complete line from the buffer without its line ending.
+    fn take_line(&mut self) -> Option<String> {
+        let end = self.buffer.iter().position(|byte| *byte == b'\n' || *byte == b'\r')?;
+
+        // A trailing `\r` may be followed by `\n` in the next bytes
+        let line_ending_len = match (self.buffer[end], self.buffer.get(end + 1)) {
+            (b'\r', Some(b'\n')) => 2,
+            (b'\r', None) if !self.ended => {
+                return None;
+            }
+            _ => 1,
+        };
+
+        let line = self.buffer.split_to(end + line_ending_len);
+        Some(String::from_utf8_lossy(&line[..end]).into_owned())
+    }
+
+    /// Decodes the lines in the buffer until an event is complete.
+    fn extract_first_event(&mut self) -> Option<ServerSentEvent> {
+        while let Some(line) = self.take_line() {
+            // An empty line dispatches the event
+            if line.is_empty() {
+                let event = self.event.take();
+                if let Some(data) = self.data.take() {
+                    return Some(ServerSentEvent { event, data });
+                }
+                continue;
+            }
+
+            // Skip comments
+            if line.starts_with(':') {
+                continue;
+            }
+
+            // Split the field and the value, which may start with a space
+            let (field, value) = match line.split_once(':') {
+                Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+                None => (line.as_str(), ""),
+            };
+
+            match field {
+                "event" => {
+                    self.event = Some(value.to_string());
+                }
+                "data" => {
+                    match self.data.as_mut() {
+                        Some(data) => {
+                            data.push('\n');
+                            data.push_str(value);
+                        }
+                        None => {
+                            self.data = Some(value.to_string());
+                        }
+                    }
+                }
+
+                // Ignore `id`, `retry` and unknown fields
+                _ => {}
+            }
+        }
+
+        None
+    }
+}
+
+impl Stream for EventStream {
+    type Item = reqwest::Result<ServerSentEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            // Extract the first event from the buffer
+            if let Some(event) = self.extract_first_event() {
+                return Poll::Ready(Some(Ok(event)));
+            }
+
+            // An incomplete event at the end of the stream is discarded
+            if self.ended {
+                return Poll::Ready(None);
+            }
+
+            match self.stream.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(bytes))) => {
+                    // Add newly received bytes to the buffer,
+                    // and extract the first event from the extended buffer
+                    self.buffer.extend(&bytes);
+                }
+                Poll::Ready(Some(Err(error))) => {
+                    return Poll::Ready(Some(Err(error)));
+                }
+                Poll::Ready(None) => {
+                    self.ended = true;
+                }
+                Poll::Pending => {
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
+
+/// A stream of the data chunks of server-sent events,
+/// ending at the termination data chunk.
+pub(crate) struct DataChunkStream {
+    stream: EventStream,
+    terminated: bool,
+}
+
+impl DataChunkStream {
+    pub fn new<S: 'static + Stream<Item = reqwest::Result<Bytes>>>(stream: S) -> Self {
+        Self { stream: EventStream::new(stream), terminated: false }
+    }
+}
+
+impl Stream for DataChunkStream {
+    type Item = reqwest::Result<String>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.terminated {
+            return Poll::Ready(None);
+        }
+
+        match self.stream.poll_next_unpin(cx) {
+            Poll::Ready(Some(Ok(event))) => {
+                // Check if the data chunk is the termination data chunk
+                if event.data == TERMINATION_DATA_CHUNK {
+                    self.terminated = true;
+                    return Poll::Ready(None);
+                }
+
+                Poll::Ready(Some(Ok(event.data)))
+            }
+            Poll::Ready(Some(Err(error))) => Poll::Ready(Some(Err(error))),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream;
+    use super::*;
+
+    #[tokio::test]
+    async fn decode_data_chunks() {
+        // Two chunks arrive in the same bytes, and one spans two bytes
+        let chunks = vec![
+            Ok(Bytes::from("data: {\"a\":1}\n\ndata: {\"b\"")),
+            Ok(Bytes::from(":2}\n\ndata: [DONE]\n\n")),
+            Ok(Bytes::from("data: {\"c\":3}\n\n"))
+        ];
+        let stream = DataChunkStream::new(stream::iter(chunks));
+
+        let data_chunks: Vec<String> = stream
+            .map(|data_chunk| data_chunk.unwrap())
+            .collect().await;
+        assert_eq!(data_chunks, vec!["{\"a\":1}", "{\"b\":2}"]);
+    }
+
+    #[tokio::test]
+    async fn decode_events_with_comments_and_crlf() {
+        // A keep-alive comment, a `\r\n` split across bytes and a multi-line data field
+        let chunks = vec![
+            Ok(Bytes::from(": ping\n\nevent: thread.created\r")),
+            Ok(Bytes::from("\ndata: {}\r\n\r\nretry: 1000\ndata:first\ndata: second\n\n")),
+            Ok(Bytes::from("data: incomplete"))
+        ];
+        let stream = EventStream::new(stream::iter(chunks));
+
+        let events: Vec<ServerSentEvent> = stream.map(|event| event.unwrap()).collect().await;
+        assert_eq!(events, vec![
+            ServerSentEvent { event: Some("thread.created".to_string()), data: "{}".to_string() },
+            ServerSentEvent { event: None, data: "first\nsecond".to_string() }
+        ]);
+    }
+}
@@ -0,0 +1,199 @@
+use std::{ io::Cursor, path::{ Path, PathBuf } };
+use bytes::Bytes;
+use reqwest::{ Body, multipart::Part };
+use tokio::io::{ AsyncRead, AsyncReadExt };
+use tokio_util::io::ReaderStream;
+use crate::{ Result, Error };
+
+/// A file to upload in a multipart/form-data request.
+///
+/// The content may come from a file on disk, some bytes in memory, or an async reader.
+/// Files on disk and async readers are streamed to the API
+/// without being fully loaded into memory.
+pub struct UploadFile {
+    file_name: String,
+    source: UploadSource,
+}
+
+enum UploadSource {
+    Path(PathBuf),
+    Bytes(Bytes),
+    Reader {
+        reader: Box<dyn AsyncRead + Send + Sync + Unpin>,
+        length: u64,
+    },
+}
+
+impl UploadFile {
+    /// Creates an upload file from a path on disk.
+    /// The file name is taken from the last component of the path.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Self {
+        let path = path.as_ref().to_path_buf();
+
+        // Get the file name from the path
+        let file_name = path
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        Self { file_name, source: UploadSource::Path(path) }
+    }
+
+    /// Creates an upload file from bytes in memory.
+    ///
+    /// The file name is sent to the API,
+    /// which may use its extension to infer the file format.
+    pub fn from_bytes<S: AsRef<str>, B: Into<Bytes>>(file_name: S, bytes: B) -> Self {
+        Self {
+            file_name: file_name.as_ref().to_string(),
+            source: UploadSource::Bytes(bytes.into()),
+        }
+    }
+
+    /// Creates an upload file from an async reader.
+    ///
+    /// The length in bytes of the content must be provided
+    /// so that it can be validated before uploading.
+    pub fn from_reader<S, R>(file_name: S, reader: R, length: u64) -> Self
+        where S: AsRef<str>, R: AsyncRead + Send + Sync + Unpin + 'static
+    {
+        Self {
+            file_name: file_name.as_ref().to_string(),
+            source: UploadSource::Reader { reader: Box::new(reader), length },
+        }
+    }
+
+    /// Gets the file name.
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    /// Reads the first `header_length` bytes of the content and
+    /// gets the total length so that they can be validated before uploading.
+    /// Content not yet read is kept as a stream.
+    pub(crate) async fn prepare(self, header_length: usize) -> Result<PreparedUpload> {
+        let file_name = self.file_name;
+
+        match self.source {
+            UploadSource::Path(path) => {
+                // Open the file
+                let mut file = match tokio::fs::File::open(&path).await {
+                    Ok(file) => file,
+                    Err(error) => {
+                        return Err(Error::ReadUploadFile { file_name, source: error });
+                    }
+                };
+
+                // Get the file size
+                let length = match file.metadata().await {
+                    Ok(metadata) => metadata.len(),
+                    Err(error) => {
+                        return Err(Error::ReadUploadFile { file_name, source: error });
+                    }
+                };
+
+                // Read the header
+                let header = match read_header(&mut file, header_length).await {
+                    Ok(header) => header,
+                    Err(error) => {
+                        return Err(Error::ReadUploadFile { file_name, source: error });
+                    }
+                };
+
+                Ok(PreparedUpload::from_reader(file_name, header, file, length))
+            }
+            UploadSource::Bytes(bytes) => {
+                let header = bytes[..header_length.min(bytes.len())].to_vec();
+                let length = bytes.len() as u64;
+
+                Ok(PreparedUpload { file_name, length, header, body: Body::from(bytes) })
+            }
+            UploadSource::Reader { mut reader, length } => {
+                // Read the header
+                let header = match read_header(&mut reader, header_length).await {
+                    Ok(header) => header,
+                    Err(error) => {
+                        return Err(Error::ReadUploadFile { file_name, source: error });
+                    }
+                };
+
+                Ok(PreparedUpload::from_reader(file_name, header, reader, length))
+            }
+        }
+    }
+}
+
+/// An upload file whose leading bytes have been read so that
+/// the content can be validated before uploading.
+pub(crate) struct PreparedUpload {
+    pub file_name: String,
+
+    /// Length of the entire content in bytes.
+    pub length: u64,
+
+    /// The leading bytes of the content.
+    pub header: Vec<u8>,
+
+    body: Body,
+}
+
+impl PreparedUpload {
+    fn from_reader<R: AsyncRead + Send + Sync + Unpin + 'static>(
+        file_name: String,
+        header: Vec<u8>,
+        reader: R,
+        length: u64
+    ) -> Self {
+        // Put the header back in front of the rest of the content
+        let reader = Cursor::new(header.clone()).chain(reader);
+
+        Self {
+            file_name,
+            length,
+            header,
+            body: Body::wrap_stream(ReaderStream::new(reader)),
+        }
+    }
+
+    /// Converts to a part of a multipart form.
+    pub fn into_part(self) -> Part {
+        Part::stream_with_length(self.body, self.length).file_name(self.file_name)
+    }
+}
+
+/// Reads at most `length` bytes from the start of the reader.
+async fn read_header<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    length: usize
+) -> std::io::Result<Vec<u8>> {
+    let mut header = Vec::with_capacity(length);
+    reader.take(length as u64).read_to_end(&mut header).await?;
+
+    Ok(header)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn prepare_upload_file() -> Result<()> {
+        // From bytes
+        let upload = UploadFile::from_bytes("hello.txt", "hello, world").prepare(5).await?;
+        assert_eq!(upload.file_name, "hello.txt");
+        assert_eq!(upload.length, 12);
+        assert_eq!(upload.header, b"hello");
+
+        // From a reader which is shorter than the header
+        let upload = UploadFile::from_reader("hi.txt", Cursor::new(b"hi".to_vec()), 2)
+            .prepare(5).await?;
+        assert_eq!(upload.length, 2);
+        assert_eq!(upload.header, b"hi");
+
+        // From a missing file
+        let result = UploadFile::from_path("/path/to/missing.png").prepare(5).await;
+        assert!(matches!(result, Err(Error::ReadUploadFile { .. })));
+
+        Ok(())
+    }
+}
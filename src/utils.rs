@@ -1,4 +1,81 @@
+use percent_encoding::{ utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC };
+
+#[cfg(test)]
 #[allow(unused)]
 pub fn init_test_logger() {
     let _ = env_logger::builder().filter_level(log::LevelFilter::Debug).is_test(true).try_init();
 }
+
+/// The characters escaped in a path segment,
+/// i.e., all but the unreserved ones and those in IDs of fine-tuned models such as `:`.
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~')
+    .remove(b':')
+    .remove(b'@');
+
+/// Percent-encodes a caller-supplied ID, e.g., of a file, so that it is a single path segment.
+pub(crate) fn encode_path_segment(segment: &str) -> String {
+    utf8_percent_encode(segment, PATH_SEGMENT).to_string()
+}
+
+/// The characters escaped in a query value, i.e., all but the unreserved ones.
+#[cfg(feature = "realtime")]
+const QUERY_VALUE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Percent-encodes a caller-supplied value of a query parameter, e.g., a model name.
+#[cfg(feature = "realtime")]
+pub(crate) fn encode_query_value(value: &str) -> String {
+    utf8_percent_encode(value, QUERY_VALUE).to_string()
+}
+
+/// Converts a value that serializes to a JSON string, e.g., a unit enum variant,
+/// to the text of a multipart form field.
+pub(crate) fn to_form_text<T: serde::Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(text)) => text,
+        Ok(value) => value.to_string(),
+        Err(_) => String::new(),
+    }
+}
+
+/// A request body with the `stream` field set, so that the API responds with server-sent events.
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct StreamingRequestBody<'a, T: serde::Serialize> {
+    #[serde(flatten)]
+    body: &'a T,
+
+    stream: bool,
+}
+
+impl<'a, T: serde::Serialize> StreamingRequestBody<'a, T> {
+    pub fn new(body: &'a T) -> Self {
+        Self { body, stream: true }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_path_segment() {
+        assert_eq!(encode_path_segment("file-abc123"), "file-abc123");
+        assert_eq!(encode_path_segment("ft:gpt-4o-mini:org::id"), "ft:gpt-4o-mini:org::id");
+        assert_eq!(encode_path_segment("my file"), "my%20file");
+        assert_eq!(encode_path_segment("../models?x=1#y"), "..%2Fmodels%3Fx%3D1%23y");
+    }
+
+    #[cfg(feature = "realtime")]
+    #[test]
+    fn test_encode_query_value() {
+        assert_eq!(encode_query_value("gpt-4o-realtime-preview"), "gpt-4o-realtime-preview");
+        assert_eq!(encode_query_value("a&b=c d"), "a%26b%3Dc%20d");
+    }
+}
@@ -0,0 +1,230 @@
+use std::time::Duration;
+use crate::{ Result, OpenAIClient, ListResponse, utils::encode_path_segment };
+use super::{
+    super::{
+        VECTOR_STORES_API_PATH,
+        FileBatchRequestBody,
+        VectorStoresListQuery,
+        VectorStoreFile,
+        VectorStoreFileBatch,
+    },
+    send::send_request,
+};
+
+/// Attaches a batch of files to a vector store, which starts their ingestion.
+pub async fn create_vector_store_file_batch<S: AsRef<str>>(
+    client: &OpenAIClient,
+    vector_store_id: S,
+    request_body: &FileBatchRequestBody
+) -> Result<VectorStoreFileBatch> {
+    let vector_store_id = vector_store_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client
+                .post(
+                    client.endpoint(
+                        format!(
+                            "{}/{}/file_batches",
+                            VECTOR_STORES_API_PATH,
+                            encode_path_segment(vector_store_id)
+                        )
+                    )
+                )
+                .json(request_body)
+        },
+        Some(vector_store_id),
+        "vector store file batch"
+    ).await
+}
+
+/// Retrieves a batch of files in a vector store.
+pub async fn retrieve_vector_store_file_batch<S: AsRef<str>, T: AsRef<str>>(
+    client: &OpenAIClient,
+    vector_store_id: S,
+    batch_id: T
+) -> Result<VectorStoreFileBatch> {
+    let batch_id = batch_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client.get(
+                client.endpoint(
+                    format!(
+                        "{}/{}/file_batches/{}",
+                        VECTOR_STORES_API_PATH,
+                        encode_path_segment(vector_store_id.as_ref()),
+                        encode_path_segment(batch_id)
+                    )
+                )
+            )
+        },
+        Some(batch_id),
+        "vector store file batch"
+    ).await
+}
+
+/// Cancels the ingestion of the files in a batch that are still in progress.
+pub async fn cancel_vector_store_file_batch<S: AsRef<str>, T: AsRef<str>>(
+    client: &OpenAIClient,
+    vector_store_id: S,
+    batch_id: T
+) -> Result<VectorStoreFileBatch> {
+    let batch_id = batch_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client.post(
+                client.endpoint(
+                    format!(
+                        "{}/{}/file_batches/{}/cancel",
+                        VECTOR_STORES_API_PATH,
+                        encode_path_segment(vector_store_id.as_ref()),
+                        encode_path_segment(batch_id)
+                    )
+                )
+            )
+        },
+        Some(batch_id),
+        "vector store file batch"
+    ).await
+}
+
+/// Lists a page of the files in a batch.
+pub async fn list_vector_store_file_batch_files<S: AsRef<str>, T: AsRef<str>>(
+    client: &OpenAIClient,
+    vector_store_id: S,
+    batch_id: T,
+    query: &VectorStoresListQuery
+) -> Result<ListResponse<VectorStoreFile>> {
+    let batch_id = batch_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client
+                .get(
+                    client.endpoint(
+                        format!(
+                            "{}/{}/file_batches/{}/files",
+                            VECTOR_STORES_API_PATH,
+                            encode_path_segment(vector_store_id.as_ref()),
+                            encode_path_segment(batch_id)
+                        )
+                    )
+                )
+                .query(query)
+        },
+        Some(batch_id),
+        "list of vector store files"
+    ).await
+}
+
+/// Polls a batch of files until its ingestion is no longer in progress,
+/// sleeping for the interval between retrievals.
+pub async fn poll_vector_store_file_batch<S: AsRef<str>, T: AsRef<str>>(
+    client: &OpenAIClient,
+    vector_store_id: S,
+    batch_id: T,
+    interval: Duration
+) -> Result<VectorStoreFileBatch> {
+    loop {
+        let batch = retrieve_vector_store_file_batch(
+            client,
+            vector_store_id.as_ref(),
+            batch_id.as_ref()
+        ).await?;
+
+        if batch.status.is_terminal() {
+            return Ok(batch);
+        }
+
+        tokio::time::sleep(interval).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use serde_json::json;
+    use crate::vector_stores::*;
+    use super::*;
+
+    fn batch_json(status: &str, completed: u32) -> String {
+        json!({
+            "id": "vsfb_abc123",
+            "object": "vector_store.file_batch",
+            "created_at": 1699061776,
+            "vector_store_id": "vs_abc123",
+            "status": status,
+            "file_counts": {
+                "in_progress": 2 - completed,
+                "completed": completed,
+                "failed": 0,
+                "cancelled": 0,
+                "total": 2
+            }
+        }).to_string()
+    }
+
+    #[tokio::test]
+    async fn test_poll_vector_store_file_batch() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let create = server
+            .mock("POST", "/vector_stores/vs_abc123/file_batches")
+            .match_body(
+                Matcher::Json(
+                    json!({
+                        "file_ids": ["file-abc123", "file-def456"],
+                        "attributes": { "team": "support" },
+                        "chunking_strategy": {
+                            "type": "static",
+                            "static": { "max_chunk_size_tokens": 400, "chunk_overlap_tokens": 100 }
+                        }
+                    })
+                )
+            )
+            .with_header("content-type", "application/json")
+            .with_body(batch_json("in_progress", 0))
+            .create_async().await;
+        let in_progress = server
+            .mock("GET", "/vector_stores/vs_abc123/file_batches/vsfb_abc123")
+            .with_header("content-type", "application/json")
+            .with_body(batch_json("in_progress", 1))
+            .expect(1)
+            .create_async().await;
+        let completed = server
+            .mock("GET", "/vector_stores/vs_abc123/file_batches/vsfb_abc123")
+            .with_header("content-type", "application/json")
+            .with_body(batch_json("completed", 2))
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // Attach the files and wait for their ingestion
+        let request_body = FileBatchRequestBody::builder(&["file-abc123", "file-def456"])
+            .attribute("team", "support")
+            .chunking_strategy(ChunkingStrategy::fixed(400, 100))
+            .build();
+        let batch = create_vector_store_file_batch(&client, "vs_abc123", &request_body).await?;
+        let batch = poll_vector_store_file_batch(
+            &client,
+            "vs_abc123",
+            &batch.id,
+            Duration::from_millis(10)
+        ).await?;
+
+        create.assert_async().await;
+        in_progress.assert_async().await;
+        completed.assert_async().await;
+        assert_eq!(batch.status, VectorStoreFileStatus::Completed);
+        assert_eq!(batch.file_counts.completed, 2);
+
+        Ok(())
+    }
+}
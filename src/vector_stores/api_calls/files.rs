@@ -0,0 +1,176 @@
+use std::{ collections::HashMap, time::Duration };
+use crate::{ Result, OpenAIClient, ListResponse, DeletedObject, utils::encode_path_segment };
+use super::{
+    super::{
+        VECTOR_STORES_API_PATH,
+        VectorStoreFileRequestBody,
+        FileAttributesRequestBody,
+        AttributeValue,
+        VectorStoresListQuery,
+        VectorStoreFile,
+    },
+    send::send_request,
+};
+
+/// Attaches a file to a vector store, which starts its ingestion.
+pub async fn create_vector_store_file<S: AsRef<str>>(
+    client: &OpenAIClient,
+    vector_store_id: S,
+    request_body: &VectorStoreFileRequestBody
+) -> Result<VectorStoreFile> {
+    let vector_store_id = vector_store_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client
+                .post(
+                    client.endpoint(
+                        format!(
+                            "{}/{}/files",
+                            VECTOR_STORES_API_PATH,
+                            encode_path_segment(vector_store_id)
+                        )
+                    )
+                )
+                .json(request_body)
+        },
+        Some(vector_store_id),
+        "vector store file"
+    ).await
+}
+
+/// Retrieves a file in a vector store.
+pub async fn retrieve_vector_store_file<S: AsRef<str>, T: AsRef<str>>(
+    client: &OpenAIClient,
+    vector_store_id: S,
+    file_id: T
+) -> Result<VectorStoreFile> {
+    let file_id = file_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client.get(
+                client.endpoint(
+                    format!(
+                        "{}/{}/files/{}",
+                        VECTOR_STORES_API_PATH,
+                        encode_path_segment(vector_store_id.as_ref()),
+                        encode_path_segment(file_id)
+                    )
+                )
+            )
+        },
+        Some(file_id),
+        "vector store file"
+    ).await
+}
+
+/// Replaces the attributes of a file in a vector store.
+pub async fn update_vector_store_file_attributes<S: AsRef<str>, T: AsRef<str>>(
+    client: &OpenAIClient,
+    vector_store_id: S,
+    file_id: T,
+    attributes: &HashMap<String, AttributeValue>
+) -> Result<VectorStoreFile> {
+    let file_id = file_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client
+                .post(
+                    client.endpoint(
+                        format!(
+                            "{}/{}/files/{}",
+                            VECTOR_STORES_API_PATH,
+                            encode_path_segment(vector_store_id.as_ref()),
+                            encode_path_segment(file_id)
+                        )
+                    )
+                )
+                .json(&(FileAttributesRequestBody { attributes }))
+        },
+        Some(file_id),
+        "vector store file"
+    ).await
+}
+
+/// Lists a page of the files in a vector store.
+pub async fn list_vector_store_files<S: AsRef<str>>(
+    client: &OpenAIClient,
+    vector_store_id: S,
+    query: &VectorStoresListQuery
+) -> Result<ListResponse<VectorStoreFile>> {
+    let vector_store_id = vector_store_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client
+                .get(
+                    client.endpoint(
+                        format!(
+                            "{}/{}/files",
+                            VECTOR_STORES_API_PATH,
+                            encode_path_segment(vector_store_id)
+                        )
+                    )
+                )
+                .query(query)
+        },
+        Some(vector_store_id),
+        "list of vector store files"
+    ).await
+}
+
+/// Removes a file from a vector store, without deleting the file itself.
+pub async fn delete_vector_store_file<S: AsRef<str>, T: AsRef<str>>(
+    client: &OpenAIClient,
+    vector_store_id: S,
+    file_id: T
+) -> Result<DeletedObject> {
+    let file_id = file_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client.delete(
+                client.endpoint(
+                    format!(
+                        "{}/{}/files/{}",
+                        VECTOR_STORES_API_PATH,
+                        encode_path_segment(vector_store_id.as_ref()),
+                        encode_path_segment(file_id)
+                    )
+                )
+            )
+        },
+        Some(file_id),
+        "deleted vector store file"
+    ).await
+}
+
+/// Polls a file in a vector store until its ingestion is no longer in progress,
+/// sleeping for the interval between retrievals.
+pub async fn poll_vector_store_file<S: AsRef<str>, T: AsRef<str>>(
+    client: &OpenAIClient,
+    vector_store_id: S,
+    file_id: T,
+    interval: Duration
+) -> Result<VectorStoreFile> {
+    loop {
+        let file = retrieve_vector_store_file(
+            client,
+            vector_store_id.as_ref(),
+            file_id.as_ref()
+        ).await?;
+
+        if file.status.is_terminal() {
+            return Ok(file);
+        }
+
+        tokio::time::sleep(interval).await;
+    }
+}
@@ -0,0 +1,32 @@
+mod send;
+
+mod vector_stores;
+pub use vector_stores::{
+    create_vector_store,
+    retrieve_vector_store,
+    modify_vector_store,
+    list_vector_stores,
+    delete_vector_store,
+};
+
+mod files;
+pub use files::{
+    create_vector_store_file,
+    retrieve_vector_store_file,
+    update_vector_store_file_attributes,
+    list_vector_store_files,
+    delete_vector_store_file,
+    poll_vector_store_file,
+};
+
+mod file_batches;
+pub use file_batches::{
+    create_vector_store_file_batch,
+    retrieve_vector_store_file_batch,
+    cancel_vector_store_file_batch,
+    list_vector_store_file_batch_files,
+    poll_vector_store_file_batch,
+};
+
+mod search;
+pub use search::search_vector_store;
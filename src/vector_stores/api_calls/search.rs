@@ -0,0 +1,103 @@
+use crate::{ Result, OpenAIClient, utils::encode_path_segment };
+use super::{
+    super::{ VECTOR_STORES_API_PATH, SearchRequestBody, SearchResultsPage },
+    send::send_request,
+};
+
+/// Searches a vector store for the chunks relevant to a query.
+pub async fn search_vector_store<S: AsRef<str>>(
+    client: &OpenAIClient,
+    vector_store_id: S,
+    request_body: &SearchRequestBody
+) -> Result<SearchResultsPage> {
+    let vector_store_id = vector_store_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client
+                .post(
+                    client.endpoint(
+                        format!(
+                            "{}/{}/search",
+                            VECTOR_STORES_API_PATH,
+                            encode_path_segment(vector_store_id)
+                        )
+                    )
+                )
+                .json(request_body)
+        },
+        Some(vector_store_id),
+        "page of search results"
+    ).await
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use serde_json::json;
+    use crate::vector_stores::*;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_search_vector_store() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/vector_stores/vs_abc123/search")
+            .match_body(
+                Matcher::Json(
+                    json!({
+                        "query": "How do I reset my password?",
+                        "filters": { "type": "eq", "key": "team", "value": "support" },
+                        "max_num_results": 50,
+                        "ranking_options": { "ranker": "auto", "score_threshold": 0.5 }
+                    })
+                )
+            )
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "object": "vector_store.search_results.page",
+                    "search_query": ["reset password"],
+                    "data": [
+                        {
+                            "file_id": "file-abc123",
+                            "filename": "faq.md",
+                            "score": 0.82,
+                            "attributes": { "team": "support" },
+                            "content": [
+                                { "type": "text", "text": "Open the settings page." },
+                                { "type": "text", "text": "Click on reset password." }
+                            ]
+                        }
+                    ],
+                    "has_more": false,
+                    "next_page": null
+                }).to_string()
+            )
+            .create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // Search the vector store
+        let request_body = SearchRequestBody::builder("How do I reset my password?")
+            .filters(SearchFilter::eq("team", "support"))
+            .max_num_results(100)
+            .ranker(Ranker::Auto)
+            .score_threshold(0.5)
+            .build();
+        let page = search_vector_store(&client, "vs_abc123", &request_body).await?;
+
+        mock.assert_async().await;
+        assert_eq!(page.search_query, vec!["reset password"]);
+        assert_eq!(page.data[0].text(), "Open the settings page.\nClick on reset password.");
+        assert_eq!(
+            page.data[0].attributes.as_ref().unwrap()["team"],
+            AttributeValue::from("support")
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use reqwest::RequestBuilder;
+use serde::de::DeserializeOwned;
+use crate::{ Result, Error, VectorStoresApiError, OpenAIClient };
+
+/// Sends the request built by the closure to the vector stores API,
+/// and parses the response to the named object.
+///
+/// A 404 status means the object with the given ID is not found.
+pub(super) async fn send_request<T: DeserializeOwned, F>(
+    client: &OpenAIClient,
+    build: F,
+    id: Option<&str>,
+    object: &'static str
+) -> Result<T>
+    where F: FnOnce(&OpenAIClient) -> RequestBuilder
+{
+    // Send the request
+    let response = client.send(build).await;
+
+    let not_found = reqwest::StatusCode::NOT_FOUND;
+    if let (Err(Error::UnknownStatusCode { status_code, .. }), Some(id)) = (&response, id) {
+        if *status_code == not_found {
+            return Err(
+                Error::VectorStoresApi(VectorStoresApiError::ObjectNotFound(id.to_string()))
+            );
+        }
+    }
+    let response = response?;
+
+    // Parse the response
+    match response.json::<T>().await {
+        Ok(response) => Ok(response),
+        Err(error) => {
+            Err(
+                Error::VectorStoresApi(VectorStoresApiError::ParseResponse {
+                    object,
+                    source: error,
+                })
+            )
+        }
+    }
+}
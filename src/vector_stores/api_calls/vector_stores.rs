@@ -0,0 +1,183 @@
+use crate::{ Result, OpenAIClient, ListResponse, DeletedObject, utils::encode_path_segment };
+use super::{
+    super::{
+        VECTOR_STORES_API_PATH,
+        VectorStoreRequestBody,
+        VectorStoresListQuery,
+        VectorStore,
+    },
+    send::send_request,
+};
+
+/// Creates a vector store, optionally with files.
+pub async fn create_vector_store(
+    client: &OpenAIClient,
+    request_body: &VectorStoreRequestBody
+) -> Result<VectorStore> {
+    send_request(
+        client,
+        |client| client.post(client.endpoint(VECTOR_STORES_API_PATH)).json(request_body),
+        None,
+        "vector store"
+    ).await
+}
+
+/// Retrieves a vector store.
+pub async fn retrieve_vector_store<S: AsRef<str>>(
+    client: &OpenAIClient,
+    vector_store_id: S
+) -> Result<VectorStore> {
+    let vector_store_id = vector_store_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client.get(
+                client.endpoint(
+                    format!("{}/{}", VECTOR_STORES_API_PATH, encode_path_segment(vector_store_id))
+                )
+            )
+        },
+        Some(vector_store_id),
+        "vector store"
+    ).await
+}
+
+/// Modifies the name, expiration policy and metadata of a vector store.
+pub async fn modify_vector_store<S: AsRef<str>>(
+    client: &OpenAIClient,
+    vector_store_id: S,
+    request_body: &VectorStoreRequestBody
+) -> Result<VectorStore> {
+    let vector_store_id = vector_store_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client
+                .post(
+                    client.endpoint(
+                        format!(
+                            "{}/{}",
+                            VECTOR_STORES_API_PATH,
+                            encode_path_segment(vector_store_id)
+                        )
+                    )
+                )
+                .json(request_body)
+        },
+        Some(vector_store_id),
+        "vector store"
+    ).await
+}
+
+/// Lists a page of vector stores.
+pub async fn list_vector_stores(
+    client: &OpenAIClient,
+    query: &VectorStoresListQuery
+) -> Result<ListResponse<VectorStore>> {
+    send_request(
+        client,
+        |client| client.get(client.endpoint(VECTOR_STORES_API_PATH)).query(query),
+        None,
+        "list of vector stores"
+    ).await
+}
+
+/// Deletes a vector store, without deleting its files.
+pub async fn delete_vector_store<S: AsRef<str>>(
+    client: &OpenAIClient,
+    vector_store_id: S
+) -> Result<DeletedObject> {
+    let vector_store_id = vector_store_id.as_ref();
+
+    send_request(
+        client,
+        |client| {
+            client.delete(
+                client.endpoint(
+                    format!("{}/{}", VECTOR_STORES_API_PATH, encode_path_segment(vector_store_id))
+                )
+            )
+        },
+        Some(vector_store_id),
+        "deleted vector store"
+    ).await
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use serde_json::json;
+    use crate::{ Error, VectorStoresApiError, vector_stores::* };
+    use super::*;
+
+    #[tokio::test]
+    async fn test_create_vector_store() -> Result<()> {
+        // Start a mock server
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/vector_stores")
+            .match_header("authorization", "Bearer sk-test")
+            .match_body(
+                Matcher::Json(
+                    json!({
+                        "name": "Support FAQ",
+                        "file_ids": ["file-abc123"],
+                        "expires_after": { "anchor": "last_active_at", "days": 7 },
+                        "chunking_strategy": { "type": "auto" }
+                    })
+                )
+            )
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "id": "vs_abc123",
+                    "object": "vector_store",
+                    "created_at": 1699061776,
+                    "name": "Support FAQ",
+                    "usage_bytes": 0,
+                    "file_counts": {
+                        "in_progress": 1,
+                        "completed": 0,
+                        "failed": 0,
+                        "cancelled": 0,
+                        "total": 1
+                    },
+                    "status": "in_progress",
+                    "expires_after": { "anchor": "last_active_at", "days": 7 },
+                    "last_active_at": 1699061776,
+                    "metadata": {}
+                }).to_string()
+            )
+            .create_async().await;
+        server.mock("DELETE", "/vector_stores/missing").with_status(404).create_async().await;
+
+        // Create a client connected to the mock server
+        let client = OpenAIClient::builder().api_key("sk-test").base_url(server.url()).build()?;
+
+        // Create the vector store
+        let request_body = VectorStoreRequestBody::builder()
+            .name("Support FAQ")
+            .file_id("file-abc123")
+            .expires_after(ExpirationPolicy::after_last_active(7))
+            .chunking_strategy(ChunkingStrategy::Auto)
+            .build();
+        let vector_store = create_vector_store(&client, &request_body).await?;
+
+        mock.assert_async().await;
+        assert_eq!(vector_store.status, VectorStoreStatus::InProgress);
+        assert_eq!(vector_store.file_counts.total, 1);
+
+        // Delete a missing vector store
+        let result = delete_vector_store(&client, "missing").await;
+        match result {
+            Err(Error::VectorStoresApi(VectorStoresApiError::ObjectNotFound(id))) => {
+                assert_eq!(id, "missing");
+            }
+            other => panic!("expected a missing vector store, got {other:?}"),
+        }
+
+        Ok(())
+    }
+}
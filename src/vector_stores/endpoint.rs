@@ -0,0 +1,2 @@
+/// Path of the vector stores API under the base URL of the client.
+pub const VECTOR_STORES_API_PATH: &str = "vector_stores";
@@ -0,0 +1,93 @@
+use serde::{ Serialize, Deserialize };
+use log::warn;
+
+const MIN_MAX_CHUNK_SIZE_TOKENS: u32 = 100;
+const MAX_MAX_CHUNK_SIZE_TOKENS: u32 = 4096;
+
+/// The strategy used to chunk files added to a vector store.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChunkingStrategy {
+    /// Chunks of 800 tokens overlapping by 400 tokens.
+    Auto,
+
+    Static {
+        #[serde(rename = "static")]
+        static_chunking: StaticChunking,
+    },
+
+    /// The strategy of files whose chunking strategy is unknown,
+    /// which is only present in responses.
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct StaticChunking {
+    pub max_chunk_size_tokens: u32,
+    pub chunk_overlap_tokens: u32,
+}
+
+impl ChunkingStrategy {
+    /// Creates a static chunking strategy.
+    ///
+    /// The maximum chunk size will be clamped in between 100 and 4096,
+    /// and the overlap will be clamped to at most half of the maximum chunk size.
+    pub fn fixed(max_chunk_size_tokens: u32, chunk_overlap_tokens: u32) -> Self {
+        // Clamp the maximum chunk size to the valid range
+        let valid_range = MIN_MAX_CHUNK_SIZE_TOKENS..=MAX_MAX_CHUNK_SIZE_TOKENS;
+        let max_chunk_size_tokens = if !valid_range.contains(&max_chunk_size_tokens) {
+            // Clamp the value
+            let m = max_chunk_size_tokens.clamp(
+                MIN_MAX_CHUNK_SIZE_TOKENS,
+                MAX_MAX_CHUNK_SIZE_TOKENS
+            );
+
+            // Warn the user
+            warn!(
+                "input value of max_chunk_size_tokens is {max_chunk_size_tokens} which is out of range, it is now clamped to {m}"
+            );
+
+            m
+        } else {
+            max_chunk_size_tokens
+        };
+
+        // Clamp the overlap to half of the maximum chunk size
+        let max_overlap = max_chunk_size_tokens / 2;
+        let chunk_overlap_tokens = if chunk_overlap_tokens > max_overlap {
+            // Warn the user
+            warn!(
+                "input value of chunk_overlap_tokens is {chunk_overlap_tokens} which is out of range, it is now clamped to {max_overlap}"
+            );
+
+            max_overlap
+        } else {
+            chunk_overlap_tokens
+        };
+
+        Self::Static {
+            static_chunking: StaticChunking { max_chunk_size_tokens, chunk_overlap_tokens },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn serialize_chunking_strategy() {
+        assert_eq!(
+            serde_json::to_value(ChunkingStrategy::Auto).unwrap(),
+            json!({ "type": "auto" })
+        );
+        assert_eq!(
+            serde_json::to_value(ChunkingStrategy::fixed(5000, 3000)).unwrap(),
+            json!({
+                "type": "static",
+                "static": { "max_chunk_size_tokens": 4096, "chunk_overlap_tokens": 2048 }
+            })
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use serde::{ Serialize, Deserialize };
+use log::warn;
+
+const MIN_DAYS: u32 = 1;
+const MAX_DAYS: u32 = 365;
+
+/// The policy of when a vector store expires.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct ExpirationPolicy {
+    /// The timestamp after which the days are counted.
+    pub anchor: ExpirationAnchor,
+
+    pub days: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExpirationAnchor {
+    LastActiveAt,
+}
+
+impl ExpirationPolicy {
+    /// Creates a policy that expires the vector store after it is inactive for the days.
+    ///
+    /// The input value will be clamped in between 1 and 365.
+    pub fn after_last_active(days: u32) -> Self {
+        // Clamp the value to the valid range
+        let days = if !(MIN_DAYS..=MAX_DAYS).contains(&days) {
+            // Clamp the value
+            let d = days.clamp(MIN_DAYS, MAX_DAYS);
+
+            // Warn the user
+            warn!("input value of days is {days} which is out of range, it is now clamped to {d}");
+
+            d
+        } else {
+            days
+        };
+
+        Self { anchor: ExpirationAnchor::LastActiveAt, days }
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use serde::Serialize;
+use super::{ AttributeValue, ChunkingStrategy };
+
+/// Request body of attaching a file to a vector store.
+#[derive(Debug, Serialize)]
+pub struct VectorStoreFileRequestBody {
+    file_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<HashMap<String, AttributeValue>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chunking_strategy: Option<ChunkingStrategy>,
+}
+
+impl VectorStoreFileRequestBody {
+    pub fn builder<S: AsRef<str>>(file_id: S) -> VectorStoreFileRequestBodyBuilder {
+        VectorStoreFileRequestBodyBuilder::new(file_id)
+    }
+}
+
+pub struct VectorStoreFileRequestBodyBuilder {
+    file_id: String,
+    attributes: Option<HashMap<String, AttributeValue>>,
+    chunking_strategy: Option<ChunkingStrategy>,
+}
+
+impl VectorStoreFileRequestBodyBuilder {
+    /// Creates a new builder with `None` values for all optional fields.
+    pub fn new<S: AsRef<str>>(file_id: S) -> Self {
+        Self { file_id: file_id.as_ref().to_string(), attributes: None, chunking_strategy: None }
+    }
+
+    /// Builds the request body.
+    pub fn build(self) -> VectorStoreFileRequestBody {
+        VectorStoreFileRequestBody {
+            file_id: self.file_id,
+            attributes: self.attributes,
+            chunking_strategy: self.chunking_strategy,
+        }
+    }
+
+    /// Adds an attribute to the file, which can be used to filter search results.
+    pub fn attribute<K: AsRef<str>, V: Into<AttributeValue>>(mut self, key: K, value: V) -> Self {
+        self.attributes
+            .get_or_insert_with(HashMap::new)
+            .insert(key.as_ref().to_string(), value.into());
+        self
+    }
+
+    /// Sets the strategy used to chunk the file.
+    pub fn chunking_strategy(mut self, chunking_strategy: ChunkingStrategy) -> Self {
+        self.chunking_strategy = Some(chunking_strategy);
+        self
+    }
+}
+
+/// Request body of attaching a batch of files to a vector store.
+#[derive(Debug, Serialize)]
+pub struct FileBatchRequestBody {
+    file_ids: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<HashMap<String, AttributeValue>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chunking_strategy: Option<ChunkingStrategy>,
+}
+
+impl FileBatchRequestBody {
+    pub fn builder<S: AsRef<str>>(file_ids: &[S]) -> FileBatchRequestBodyBuilder {
+        FileBatchRequestBodyBuilder::new(file_ids)
+    }
+}
+
+pub struct FileBatchRequestBodyBuilder {
+    file_ids: Vec<String>,
+    attributes: Option<HashMap<String, AttributeValue>>,
+    chunking_strategy: Option<ChunkingStrategy>,
+}
+
+impl FileBatchRequestBodyBuilder {
+    /// Creates a new builder with `None` values for all optional fields.
+    pub fn new<S: AsRef<str>>(file_ids: &[S]) -> Self {
+        Self {
+            file_ids: file_ids
+                .iter()
+                .map(|file_id| file_id.as_ref().to_string())
+                .collect(),
+            attributes: None,
+            chunking_strategy: None,
+        }
+    }
+
+    /// Builds the request body.
+    pub fn build(self) -> FileBatchRequestBody {
+        FileBatchRequestBody {
+            file_ids: self.file_ids,
+            attributes: self.attributes,
+            chunking_strategy: self.chunking_strategy,
+        }
+    }
+
+    /// Adds an attribute to all the files, which can be used to filter search results.
+    pub fn attribute<K: AsRef<str>, V: Into<AttributeValue>>(mut self, key: K, value: V) -> Self {
+        self.attributes
+            .get_or_insert_with(HashMap::new)
+            .insert(key.as_ref().to_string(), value.into());
+        self
+    }
+
+    /// Sets the strategy used to chunk the files.
+    pub fn chunking_strategy(mut self, chunking_strategy: ChunkingStrategy) -> Self {
+        self.chunking_strategy = Some(chunking_strategy);
+        self
+    }
+}
+
+/// Request body of updating the attributes of a file in a vector store.
+#[derive(Debug, Serialize)]
+pub(crate) struct FileAttributesRequestBody<'a> {
+    pub attributes: &'a HashMap<String, AttributeValue>,
+}
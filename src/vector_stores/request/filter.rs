@@ -0,0 +1,125 @@
+use serde::Serialize;
+use super::AttributeValue;
+
+/// A filter of search results by the attributes of the files.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum SearchFilter {
+    /// Compares the attribute of the key with the value.
+    Comparison {
+        #[serde(rename = "type")]
+        operator: ComparisonOperator,
+        key: String,
+        value: AttributeValue,
+    },
+
+    /// Combines the filters with a logical operator.
+    Compound {
+        #[serde(rename = "type")]
+        operator: CompoundOperator,
+        filters: Vec<SearchFilter>,
+    },
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ComparisonOperator {
+    Eq,
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CompoundOperator {
+    And,
+    Or,
+}
+
+impl SearchFilter {
+    pub fn compare<S: AsRef<str>, V: Into<AttributeValue>>(
+        operator: ComparisonOperator,
+        key: S,
+        value: V
+    ) -> Self {
+        Self::Comparison { operator, key: key.as_ref().to_string(), value: value.into() }
+    }
+
+    /// Matches the files whose attribute equals the value.
+    pub fn eq<S: AsRef<str>, V: Into<AttributeValue>>(key: S, value: V) -> Self {
+        Self::compare(ComparisonOperator::Eq, key, value)
+    }
+
+    /// Matches the files whose attribute does not equal the value.
+    pub fn ne<S: AsRef<str>, V: Into<AttributeValue>>(key: S, value: V) -> Self {
+        Self::compare(ComparisonOperator::Ne, key, value)
+    }
+
+    /// Matches the files whose attribute is greater than the value.
+    pub fn gt<S: AsRef<str>, V: Into<AttributeValue>>(key: S, value: V) -> Self {
+        Self::compare(ComparisonOperator::Gt, key, value)
+    }
+
+    /// Matches the files whose attribute is greater than or equal to the value.
+    pub fn gte<S: AsRef<str>, V: Into<AttributeValue>>(key: S, value: V) -> Self {
+        Self::compare(ComparisonOperator::Gte, key, value)
+    }
+
+    /// Matches the files whose attribute is less than the value.
+    pub fn lt<S: AsRef<str>, V: Into<AttributeValue>>(key: S, value: V) -> Self {
+        Self::compare(ComparisonOperator::Lt, key, value)
+    }
+
+    /// Matches the files whose attribute is less than or equal to the value.
+    pub fn lte<S: AsRef<str>, V: Into<AttributeValue>>(key: S, value: V) -> Self {
+        Self::compare(ComparisonOperator::Lte, key, value)
+    }
+
+    /// Matches the files matched by all of the filters.
+    pub fn and(filters: Vec<SearchFilter>) -> Self {
+        Self::Compound { operator: CompoundOperator::And, filters }
+    }
+
+    /// Matches the files matched by any of the filters.
+    pub fn or(filters: Vec<SearchFilter>) -> Self {
+        Self::Compound { operator: CompoundOperator::Or, filters }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn serialize_search_filter() {
+        let filter = SearchFilter::and(
+            vec![
+                SearchFilter::eq("region", "us"),
+                SearchFilter::or(
+                    vec![SearchFilter::gte("year", 2023), SearchFilter::eq("draft", false)]
+                )
+            ]
+        );
+
+        assert_eq!(
+            serde_json::to_value(&filter).unwrap(),
+            json!({
+                "type": "and",
+                "filters": [
+                    { "type": "eq", "key": "region", "value": "us" },
+                    {
+                        "type": "or",
+                        "filters": [
+                            { "type": "gte", "key": "year", "value": 2023.0 },
+                            { "type": "eq", "key": "draft", "value": false }
+                        ]
+                    }
+                ]
+            })
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use serde::Serialize;
+use log::warn;
+use crate::ListOrder;
+use super::super::VectorStoreFileStatus;
+
+const MIN_LIMIT: u32 = 1;
+const MAX_LIMIT: u32 = 100;
+
+/// Query parameters of listing vector stores, or the files of a vector store or a file batch.
+#[derive(Debug, Serialize, Clone, Default)]
+pub struct VectorStoresListQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order: Option<ListOrder>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<VectorStoreFileStatus>,
+}
+
+impl VectorStoresListQuery {
+    pub fn builder() -> VectorStoresListQueryBuilder {
+        VectorStoresListQueryBuilder::new()
+    }
+}
+
+#[derive(Default)]
+pub struct VectorStoresListQueryBuilder {
+    limit: Option<u32>,
+    order: Option<ListOrder>,
+    after: Option<String>,
+    before: Option<String>,
+    filter: Option<VectorStoreFileStatus>,
+}
+
+impl VectorStoresListQueryBuilder {
+    /// Creates a new builder with `None` values for all fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds the query.
+    pub fn build(self) -> VectorStoresListQuery {
+        VectorStoresListQuery {
+            limit: self.limit,
+            order: self.order,
+            after: self.after,
+            before: self.before,
+            filter: self.filter,
+        }
+    }
+
+    /// Sets the number of objects in a page.
+    ///
+    /// The input value will be clamped in between 1 and 100.
+    pub fn limit(mut self, limit: u32) -> Self {
+        // Clamp the value to the valid range
+        let limit = if !(MIN_LIMIT..=MAX_LIMIT).contains(&limit) {
+            // Clamp the value
+            let l = limit.clamp(MIN_LIMIT, MAX_LIMIT);
+
+            // Warn the user
+            warn!(
+                "input value of limit is {limit} which is out of range, it is now clamped to {l}"
+            );
+
+            l
+        } else {
+            limit
+        };
+
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the sort order by the creation time of the objects.
+    pub fn order(mut self, order: ListOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the cursor to list the objects after the given object ID.
+    pub fn after<S: AsRef<str>>(mut self, after: S) -> Self {
+        self.after = Some(after.as_ref().to_string());
+        self
+    }
+
+    /// Sets the cursor to list the objects before the given object ID.
+    pub fn before<S: AsRef<str>>(mut self, before: S) -> Self {
+        self.before = Some(before.as_ref().to_string());
+        self
+    }
+
+    /// Lists only the files with the status, which only applies to listing files.
+    pub fn filter(mut self, filter: VectorStoreFileStatus) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+}
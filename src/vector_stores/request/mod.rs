@@ -0,0 +1,29 @@
+mod chunking;
+pub use chunking::{ ChunkingStrategy, StaticChunking };
+
+mod expiration;
+pub use expiration::{ ExpirationPolicy, ExpirationAnchor };
+
+mod attribute;
+pub use attribute::AttributeValue;
+
+mod filter;
+pub use filter::{ SearchFilter, ComparisonOperator, CompoundOperator };
+
+mod vector_store_request_body;
+pub use vector_store_request_body::{ VectorStoreRequestBody, VectorStoreRequestBodyBuilder };
+
+mod file_request_body;
+pub use file_request_body::{
+    VectorStoreFileRequestBody,
+    VectorStoreFileRequestBodyBuilder,
+    FileBatchRequestBody,
+    FileBatchRequestBodyBuilder,
+};
+pub(crate) use file_request_body::FileAttributesRequestBody;
+
+mod search;
+pub use search::{ SearchRequestBody, SearchRequestBodyBuilder, RankingOptions, Ranker };
+
+mod list;
+pub use list::{ VectorStoresListQuery, VectorStoresListQueryBuilder };
@@ -0,0 +1,154 @@
+use serde::Serialize;
+use log::warn;
+use super::SearchFilter;
+
+const MIN_MAX_NUM_RESULTS: u32 = 1;
+const MAX_MAX_NUM_RESULTS: u32 = 50;
+const MIN_SCORE_THRESHOLD: f64 = 0.0;
+const MAX_SCORE_THRESHOLD: f64 = 1.0;
+
+/// Request body of searching a vector store for the chunks relevant to a query.
+#[derive(Debug, Serialize)]
+pub struct SearchRequestBody {
+    query: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filters: Option<SearchFilter>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_num_results: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ranking_options: Option<RankingOptions>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rewrite_query: Option<bool>,
+}
+
+/// Options of ranking the search results.
+#[derive(Debug, Serialize, Clone, PartialEq, Default)]
+pub struct RankingOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ranker: Option<Ranker>,
+
+    /// The minimum score of the results, in between 0.0 and 1.0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score_threshold: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+pub enum Ranker {
+    #[serde(rename = "auto")]
+    Auto,
+
+    #[serde(rename = "default-2024-11-15")]
+    Default20241115,
+}
+
+impl SearchRequestBody {
+    pub fn builder<S: AsRef<str>>(query: S) -> SearchRequestBodyBuilder {
+        SearchRequestBodyBuilder::new(query)
+    }
+}
+
+pub struct SearchRequestBodyBuilder {
+    query: String,
+    filters: Option<SearchFilter>,
+    max_num_results: Option<u32>,
+    ranking_options: Option<RankingOptions>,
+    rewrite_query: Option<bool>,
+}
+
+impl SearchRequestBodyBuilder {
+    /// Creates a new builder with `None` values for all optional fields.
+    pub fn new<S: AsRef<str>>(query: S) -> Self {
+        Self {
+            query: query.as_ref().to_string(),
+            filters: None,
+            max_num_results: None,
+            ranking_options: None,
+            rewrite_query: None,
+        }
+    }
+
+    /// Builds the request body.
+    pub fn build(self) -> SearchRequestBody {
+        SearchRequestBody {
+            query: self.query,
+            filters: self.filters,
+            max_num_results: self.max_num_results,
+            ranking_options: self.ranking_options,
+            rewrite_query: self.rewrite_query,
+        }
+    }
+
+    /// Sets the filter of the results by the attributes of the files.
+    pub fn filters(mut self, filters: SearchFilter) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    /// Sets the maximum number of results.
+    ///
+    /// The input value will be clamped in between 1 and 50.
+    pub fn max_num_results(mut self, max_num_results: u32) -> Self {
+        // Clamp the value to the valid range
+        let max_num_results = if
+            !(MIN_MAX_NUM_RESULTS..=MAX_MAX_NUM_RESULTS).contains(&max_num_results)
+        {
+            // Clamp the value
+            let m = max_num_results.clamp(MIN_MAX_NUM_RESULTS, MAX_MAX_NUM_RESULTS);
+
+            // Warn the user
+            warn!(
+                "input value of max_num_results is {max_num_results} which is out of range, it is now clamped to {m}"
+            );
+
+            m
+        } else {
+            max_num_results
+        };
+
+        self.max_num_results = Some(max_num_results);
+        self
+    }
+
+    /// Sets the ranker of the results.
+    pub fn ranker(mut self, ranker: Ranker) -> Self {
+        self.ranking_options.get_or_insert_with(RankingOptions::default).ranker = Some(ranker);
+        self
+    }
+
+    /// Sets the minimum score of the results.
+    ///
+    /// The input value will be clamped in between 0.0 and 1.0.
+    pub fn score_threshold(mut self, score_threshold: f64) -> Self {
+        // Clamp the value to the valid range
+        let score_threshold = if
+            !(MIN_SCORE_THRESHOLD..=MAX_SCORE_THRESHOLD).contains(&score_threshold)
+        {
+            // Clamp the value
+            let s = score_threshold.clamp(MIN_SCORE_THRESHOLD, MAX_SCORE_THRESHOLD);
+
+            // Warn the user
+            warn!(
+                "input value of score_threshold is {score_threshold} which is out of range, it is now clamped to {s}"
+            );
+
+            s
+        } else {
+            score_threshold
+        };
+
+        self.ranking_options.get_or_insert_with(RankingOptions::default).score_threshold = Some(
+            score_threshold
+        );
+        self
+    }
+
+    /// Sets whether to rewrite the natural language query for vector search.
+    pub fn rewrite_query(mut self, rewrite_query: bool) -> Self {
+        self.rewrite_query = Some(rewrite_query);
+        self
+    }
+}
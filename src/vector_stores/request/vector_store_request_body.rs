@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+use serde::Serialize;
+use super::{ ChunkingStrategy, ExpirationPolicy };
+
+/// Request body of creating or modifying a vector store.
+///
+/// The files and the chunking strategy are only used when creating a vector store.
+#[derive(Debug, Serialize)]
+pub struct VectorStoreRequestBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file_ids: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires_after: Option<ExpirationPolicy>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chunking_strategy: Option<ChunkingStrategy>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<HashMap<String, String>>,
+}
+
+impl VectorStoreRequestBody {
+    pub fn builder() -> VectorStoreRequestBodyBuilder {
+        VectorStoreRequestBodyBuilder::new()
+    }
+}
+
+#[derive(Default)]
+pub struct VectorStoreRequestBodyBuilder {
+    name: Option<String>,
+    file_ids: Option<Vec<String>>,
+    expires_after: Option<ExpirationPolicy>,
+    chunking_strategy: Option<ChunkingStrategy>,
+    metadata: Option<HashMap<String, String>>,
+}
+
+impl VectorStoreRequestBodyBuilder {
+    /// Creates a new builder with `None` values for all fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds the request body.
+    pub fn build(self) -> VectorStoreRequestBody {
+        VectorStoreRequestBody {
+            name: self.name,
+            file_ids: self.file_ids,
+            expires_after: self.expires_after,
+            chunking_strategy: self.chunking_strategy,
+            metadata: self.metadata,
+        }
+    }
+
+    pub fn name<S: AsRef<str>>(mut self, name: S) -> Self {
+        self.name = Some(name.as_ref().to_string());
+        self
+    }
+
+    /// Adds a file to the vector store when it is created.
+    pub fn file_id<S: AsRef<str>>(mut self, file_id: S) -> Self {
+        self.file_ids.get_or_insert_with(Vec::new).push(file_id.as_ref().to_string());
+        self
+    }
+
+    /// Sets the policy of when the vector store expires.
+    pub fn expires_after(mut self, expires_after: ExpirationPolicy) -> Self {
+        self.expires_after = Some(expires_after);
+        self
+    }
+
+    /// Sets the strategy used to chunk the files added when the vector store is created.
+    pub fn chunking_strategy(mut self, chunking_strategy: ChunkingStrategy) -> Self {
+        self.chunking_strategy = Some(chunking_strategy);
+        self
+    }
+
+    /// Adds a key-value pair to the metadata of the vector store.
+    pub fn metadata<K: AsRef<str>, V: AsRef<str>>(mut self, key: K, value: V) -> Self {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+}
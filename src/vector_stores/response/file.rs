@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+use serde::{ Serialize, Deserialize };
+use super::{ super::{ ChunkingStrategy, AttributeValue }, FileCounts };
+
+/// A file attached to a vector store.
+#[derive(Debug, Deserialize, Clone)]
+pub struct VectorStoreFile {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+    pub vector_store_id: String,
+
+    /// The number of bytes used by the file in the vector store.
+    pub usage_bytes: u64,
+
+    pub status: VectorStoreFileStatus,
+    pub last_error: Option<VectorStoreFileError>,
+    pub chunking_strategy: Option<ChunkingStrategy>,
+    pub attributes: Option<HashMap<String, AttributeValue>>,
+}
+
+/// The ingestion status of a file or a batch of files.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum VectorStoreFileStatus {
+    InProgress,
+    Completed,
+    Cancelled,
+    Failed,
+}
+
+impl VectorStoreFileStatus {
+    /// Whether the ingestion is no longer in progress.
+    pub fn is_terminal(&self) -> bool {
+        !matches!(self, Self::InProgress)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct VectorStoreFileError {
+    pub code: String,
+    pub message: String,
+}
+
+/// A batch of files attached to a vector store together.
+#[derive(Debug, Deserialize, Clone)]
+pub struct VectorStoreFileBatch {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+    pub vector_store_id: String,
+    pub status: VectorStoreFileStatus,
+    pub file_counts: FileCounts,
+}
@@ -0,0 +1,13 @@
+mod vector_store;
+pub use vector_store::{ VectorStore, VectorStoreStatus, FileCounts };
+
+mod file;
+pub use file::{
+    VectorStoreFile,
+    VectorStoreFileStatus,
+    VectorStoreFileError,
+    VectorStoreFileBatch,
+};
+
+mod search;
+pub use search::{ SearchResultsPage, SearchResult, SearchResultContent };
@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+use super::super::AttributeValue;
+
+/// A page of the results of searching a vector store.
+#[derive(Debug, Deserialize, Clone)]
+pub struct SearchResultsPage {
+    pub object: String,
+
+    /// The queries used for the search, which may be rewritten.
+    pub search_query: Vec<String>,
+
+    pub data: Vec<SearchResult>,
+
+    #[serde(default)]
+    pub has_more: bool,
+
+    pub next_page: Option<String>,
+}
+
+/// A file matching the query, with its relevant chunks.
+#[derive(Debug, Deserialize, Clone)]
+pub struct SearchResult {
+    pub file_id: String,
+    pub filename: String,
+
+    /// The similarity score of the result.
+    pub score: f64,
+
+    pub attributes: Option<HashMap<String, AttributeValue>>,
+    pub content: Vec<SearchResultContent>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SearchResultContent {
+    Text {
+        text: String,
+    },
+}
+
+impl SearchResult {
+    /// Gets the text of the result by joining its chunks.
+    pub fn text(&self) -> String {
+        self.content
+            .iter()
+            .map(|content| {
+                match content {
+                    SearchResultContent::Text { text } => text.as_str(),
+                }
+            })
+            .collect::<Vec<&str>>()
+            .join("\n")
+    }
+}
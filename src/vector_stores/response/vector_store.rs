@@ -0,0 +1,42 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+use super::super::ExpirationPolicy;
+
+/// A store of chunked and embedded files, used by the file search tool.
+///
+/// The timestamps are Unix timestamps in seconds.
+#[derive(Debug, Deserialize, Clone)]
+pub struct VectorStore {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+    pub name: Option<String>,
+
+    /// The total number of bytes used by the files in the vector store.
+    pub usage_bytes: u64,
+
+    pub file_counts: FileCounts,
+    pub status: VectorStoreStatus,
+    pub expires_after: Option<ExpirationPolicy>,
+    pub expires_at: Option<u64>,
+    pub last_active_at: Option<u64>,
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum VectorStoreStatus {
+    Expired,
+    InProgress,
+    Completed,
+}
+
+/// Numbers of files by their ingestion status.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+pub struct FileCounts {
+    pub in_progress: u32,
+    pub completed: u32,
+    pub failed: u32,
+    pub cancelled: u32,
+    pub total: u32,
+}
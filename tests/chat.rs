@@ -3,7 +3,7 @@ use rustyopenai::prelude::*;
 #[tokio::test]
 async fn test_create_chat_completion() -> Result<()> {
     // Create a client
-    let client = OpenAIClient::new()?;
+    let client = OpenAIClient::from_env()?;
 
     // Build the request body
     let request_body = ChatRequestBody::builder(
@@ -5,14 +5,14 @@ fn test_openai_client() {
     // Initialize a logger
 
     // Create a client
-    let client = OpenAIClient::builder().build();
+    let client = OpenAIClient::builder().load_env().build();
     assert!(client.is_ok());
 }
 
 #[tokio::test]
 async fn test_list_models() {
     // Create a client
-    let client = OpenAIClient::builder().build().unwrap();
+    let client = OpenAIClient::builder().load_env().build().unwrap();
 
     // Get the list of models
     let response = list_models(&client).await;